@@ -0,0 +1,713 @@
+//! Build script to read the skinset table and champ lanes table at compile time and generate rust code that can be
+//! used in the web app.  
+
+use scraper::{Html, Selector};
+use std::{
+    collections::{HashMap, HashSet},
+    env,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+/// Name normalization shared with the library, so the lookup tables are keyed exactly as lookups normalize.
+#[path = "src/names.rs"]
+#[allow(dead_code)]
+mod names;
+
+/// Include the wiki sets table from https://leagueoflegends.fandom.com/wiki/Champion_skin/Skin_themes.
+#[allow(unused)]
+const WIKI_SETS_TABLE: &str = include_str!("assets/wiki-sets-table.html");
+
+/// Include the official sets table from https://leagueoflegends.fandom.com/wiki/Champion_skin/Skin_themes.
+#[allow(unused)]
+const OFFICIAL_SETS_TABLE: &str = include_str!("assets/official-sets-table.html");
+
+/// Select which table to use.
+const SKINSETS_HTML: &str = WIKI_SETS_TABLE;
+
+/// Lane table from https://leagueoflegends.fandom.com/wiki/List_of_champions_by_draft_position.
+const LANES_HTML: &str = include_str!("assets/champ-lanes-table.html");
+
+/// This build script generates a file that stores build data for reference in the web interface.
+fn main() -> anyhow::Result<()> {
+    // Re-run if this script changes.
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // We manually track the data acquired time for now.
+    let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR is defined");
+    // Put all generated code in `generated.rs`.
+    let dest_path = Path::new(&out_dir).join("generated.rs");
+    // Create the output file.
+    let out_file = File::create(dest_path)?;
+    // Create a buffered writer around the output file.
+    let mut writer = BufWriter::new(out_file);
+    // Write the necessary includes and structs to the output file.
+    writeln!(
+        &mut writer,
+        "{}",
+        unindent::unindent(
+            r#"
+        use serde::{Serialize, Deserialize};
+        use enumflags2::{BitFlags, bitflags, make_bitflags};
+        use derive_more::Display;
+
+        /// Bitflaggable Lane enumeration for the available lanes in league. 
+        #[bitflags]
+        #[repr(u8)]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Display, Serialize, Deserialize)]
+        pub enum Lane {
+            Top,
+            Jungle,
+            Mid,
+            Bot,
+            Support
+        }
+
+    "#
+        )
+    )?;
+
+    writeln!(
+        &mut writer,
+        "{}",
+        unindent::unindent(
+            r#"
+        /// Lane data parsed at compile time from the downloaded lane table HTML. 
+        pub const LANE_DATA: &[(&str, BitFlags<Lane>)] = &[
+    "#
+        )
+    )?;
+
+    
+    // Parse the lane data, sort it, and add to the generated file.
+    let mut lane_data = parse_lanes_file();
+    // Sort the lane data by champ name. 
+    lane_data.sort_by_key(|(champ_name, _)| champ_name.clone());
+    
+    // Compute a stable key for each champ from a hash of their name, to be used in share links. These are the full 32
+    // bits of the hash, since 16-bit keys would be likely to collide as champs are added.
+    let champ_keys: Vec<u32> = lane_data
+        .iter()
+        .map(|(champ_name, _)| fnv1a(champ_name.as_bytes()))
+        .collect();
+
+    // Make sure the keys are unique.
+    let unique_champ_keys: HashSet<u32> = champ_keys.iter().copied().collect();
+    assert_eq!(unique_champ_keys.len(), champ_keys.len(), "champ share keys collide");
+
+    // Start the data version hash with all the champ names. 
+    let mut data_version_input: Vec<u8> = lane_data
+        .iter()
+        .flat_map(|(champ_name, _)| champ_name.bytes().chain([0]))
+        .collect();
+
+    // Keep each champ's lanes for the per-skinset statistics, and build the champ name lookup table, before the lane
+    // data is consumed.
+    let champ_lanes: Vec<Vec<&'static str>> = lane_data.iter().map(|(_, lanes)| lanes.clone()).collect();
+
+    let champ_name_lookup: String = name_lookup(lane_data.iter().map(|(champ_name, _)| champ_name.as_str()));
+
+    // Convert to a const-string. 
+    let lane_data_const_string = lane_data
+        .into_iter()
+        // Convert to a const-evaluable string of rust code.
+        .map(|(champ_name, lanes)| {
+            // Connect all the champion's lanes with an 'or' operator.
+            let lane_string = lanes.join(" | ");
+            // Format into a string that is evaluable as a constant.
+            format!("(r#\"{champ_name}\"#, make_bitflags!(Lane::{{ {lane_string} }}))")
+        })
+        // Join into one big long string.
+        .collect::<Vec<String>>()
+        // Join with commas and indents.
+        .join(",\n\t");
+
+    // Write lane data
+    writeln!(&mut writer, "\t{lane_data_const_string}\n];")?;
+
+    // Write the champ name lookup table.
+    writeln!(
+        &mut writer,
+        "{}{champ_name_lookup};",
+        unindent::unindent(
+            r#"
+        /// Champ indices (into [LANE_DATA]) keyed by normalized name, for loose name lookups.
+        pub static CHAMP_NAME_LOOKUP: phf::Map<&'static str, usize> = "#
+        )
+    )?;
+
+    // Format the champ keys.
+    let champ_keys_data = champ_keys
+        .iter()
+        .map(|champ_key| champ_key.to_string())
+        .collect::<Vec<String>>()
+        .join(",\n\t");
+
+    // Write the champ keys. 
+    writeln!(
+        &mut writer,
+        "{}\t{champ_keys_data}\n];",
+        unindent::unindent(
+            r#"
+        /// Stable keys for each champ (in the same order as [LANE_DATA]), derived from a hash of their name. 
+        pub const CHAMP_KEYS: &[u32] = &[
+    "#
+        )
+    )?;
+
+    // Parse skinset data.
+    let (champs_to_skinsets, all_skinsets, champs_to_skins) = parse_skinsets_file();
+    // Make a sorted list of all the skinset names. 
+    let mut skinsets_sorted: Vec<String> = all_skinsets.into_iter().collect();
+    skinsets_sorted.sort();
+    
+    // Format the skinset list. 
+    let all_skinset_data = skinsets_sorted
+        .iter()
+        // Map skinsets into raw string literals.
+        .map(|skinset| format!("r##\"{skinset}\"##"))
+        // Collect to vec and join into string of lines.
+        .collect::<Vec<String>>()
+        // Join by comma and indent.
+        .join(",\n\t");
+    
+    // Finish the data version hash with all the skinset names and write it to the file, along with a hash of just the
+    // skinset names.
+    let skinset_names_input: Vec<u8> = skinsets_sorted.iter().flat_map(|skinset| skinset.bytes().chain([0])).collect();
+    data_version_input.extend_from_slice(&skinset_names_input);
+    let data_version: u32 = fnv1a(&data_version_input);
+    let skinset_version: u32 = fnv1a(&skinset_names_input);
+
+    writeln!(
+        &mut writer,
+        "{}",
+        unindent::unindent(&format!(
+            r#"
+        /// Hash of all the champ and skinset names, which changes whenever champs or skinsets are added or removed.
+        pub const DATA_VERSION: u32 = {data_version};
+
+        /// Hash of just the skinset names, which changes whenever skinsets are added or removed -- and so whenever
+        /// skinset indices shift.
+        pub const SKINSET_VERSION: u32 = {skinset_version};
+    "#
+        ))
+    )?;
+
+    // Add all skinset data to file.
+    writeln!(
+        &mut writer,
+        "{}\t{all_skinset_data}\n];",
+        unindent::unindent(
+            r#"
+        /// List of all skinsets parsed from skinset HTML table at compile time. 
+        pub const ALL_SKINSET_NAMES: &[&str] = &[
+    "#
+        )
+    )?;
+    
+    // Write the skinset name lookup table.
+    let skinset_name_lookup: String = name_lookup(skinsets_sorted.iter().map(String::as_str));
+
+    writeln!(
+        &mut writer,
+        "{}{skinset_name_lookup};",
+        unindent::unindent(
+            r#"
+        /// Skinset indices (into [ALL_SKINSET_NAMES]) keyed by normalized name, for loose name lookups.
+        pub static SKINSET_NAME_LOOKUP: phf::Map<&'static str, usize> = "#
+        )
+    )?;
+
+    // Parse the universe each skinset belongs to, and make a sorted list of all the universe names.
+    let skinsets_to_universes: HashMap<String, Vec<String>> = parse_universes_file();
+    let mut universes_sorted: Vec<String> = skinsets_to_universes.values().flatten().cloned().collect();
+    universes_sorted.sort();
+    universes_sorted.dedup();
+
+    // Format the universe list.
+    let all_universe_data = universes_sorted
+        .iter()
+        .map(|universe| format!("r##\"{universe}\"##"))
+        .collect::<Vec<String>>()
+        .join(",\n\t");
+
+    // Add all universe names to the file.
+    writeln!(
+        &mut writer,
+        "{}\t{all_universe_data}\n];",
+        unindent::unindent(
+            r#"
+        /// List of all skin universes parsed from skinset HTML table at compile time. 
+        pub const ALL_UNIVERSE_NAMES: &[&str] = &[
+    "#
+        )
+    )?;
+
+    // Format the universes of each skinset (in skinset name order) as lists of indices into the universe list.
+    let universe_map_data = skinsets_sorted
+        .iter()
+        .map(|skinset| {
+            let mut universe_indices: Vec<usize> = skinsets_to_universes[skinset]
+                .iter()
+                .map(|universe| universes_sorted.binary_search(universe).unwrap())
+                .collect();
+
+            universe_indices.sort();
+            universe_indices.dedup();
+            format!("&{universe_indices:?}")
+        })
+        .collect::<Vec<String>>()
+        .join(",\n\t");
+
+    // Write the universe map.
+    writeln!(
+        &mut writer,
+        "{}\t{universe_map_data}\n];",
+        unindent::unindent(
+            r#"
+        /// Map of all skinsets (in skinset name order) to the universes they're listed under. 
+        pub const SKINSETS_TO_UNIVERSES: &[&[usize]] = &[
+    "#
+        )
+    )?;
+
+    // Convert the champ->skinset map to a list of all the groups of skinsets for each champ (a list of lists of indices
+    // into the skinset list). 
+    let mut sorted_champs_skinsets_map: Vec<(String, HashSet<String>)> = champs_to_skinsets.into_iter().collect();
+    // Sort by champ name. 
+    sorted_champs_skinsets_map.sort_by_key(|(champ_name, _)| champ_name.clone());
+
+    // Iterate over this sorted list stripping out the champ name and replacing the list of skinsets with a list of indices.
+    let skinset_index_table: Vec<Vec<usize>> = sorted_champs_skinsets_map
+        .into_iter()
+        .map(|(_, skinsets)| {
+            skinsets.into_iter()
+                .map(|skinset| skinsets_sorted.binary_search(&skinset).unwrap())
+                .collect()
+        })
+        .collect();
+
+    // Invert the table to get the champs (in champ name order) with a skin in each skinset.
+    assert_eq!(skinset_index_table.len(), champ_lanes.len(), "lane and skinset tables have different champs");
+    let mut skinsets_to_champs: Vec<Vec<usize>> = vec![Vec::new(); skinsets_sorted.len()];
+
+    for (champ_index, skinsets) in skinset_index_table.iter().enumerate() {
+        for skinset_index in skinsets {
+            skinsets_to_champs[*skinset_index].push(champ_index);
+        }
+    }
+
+    // Format out the body of the skinset map data.
+    let skinset_map_data = skinset_index_table
+        .into_iter()
+        .map(|skinset_list| format!("&{skinset_list:?}"))
+        .collect::<Vec<String>>()
+        .join(",\n\t");
+
+    // Finally write the skinset map.
+    writeln!(
+        &mut writer,
+        "{}\t{skinset_map_data}\n];",
+        unindent::unindent(
+            r#"
+        /// Map of all champ names to skinsets. 
+        pub const CHAMPS_TO_SKINSETS: &[&[usize]] = &[
+    "#
+        )
+    )?;
+
+    // Format the reverse map, and the statistics derived from it.
+    let champ_map_data = skinsets_to_champs
+        .iter()
+        .map(|champ_list| format!("&{champ_list:?}"))
+        .collect::<Vec<String>>()
+        .join(",\n\t");
+
+    let lane_coverage_data = skinsets_to_champs
+        .iter()
+        .map(|champ_list| {
+            let mut lanes: Vec<&str> =
+                champ_list.iter().flat_map(|champ_index| champ_lanes[*champ_index].clone()).collect();
+            lanes.sort_by_key(|lane| LANES.iter().position(|iter_lane| iter_lane == lane));
+            lanes.dedup();
+
+            if lanes.is_empty() {
+                "BitFlags::EMPTY".to_owned()
+            } else {
+                format!("make_bitflags!(Lane::{{ {} }})", lanes.join(" | "))
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(",\n\t");
+
+    let full_team_data = skinsets_to_champs
+        .iter()
+        .map(|champ_list| {
+            let lanes: Vec<&[&str]> =
+                champ_list.iter().map(|champ_index| champ_lanes[*champ_index].as_slice()).collect();
+            has_full_team(&lanes).to_string()
+        })
+        .collect::<Vec<String>>()
+        .join(",\n\t");
+
+    writeln!(
+        &mut writer,
+        "{}\t{champ_map_data}\n];",
+        unindent::unindent(
+            r#"
+        /// Map of all skinsets (in skinset name order) to the champs (in champ name order) with a skin in them.
+        pub const SKINSETS_TO_CHAMPS: &[&[usize]] = &[
+    "#
+        )
+    )?;
+
+    writeln!(
+        &mut writer,
+        "{}\t{lane_coverage_data}\n];",
+        unindent::unindent(
+            r#"
+        /// The lanes covered by the default lanes of each skinset's champs, in skinset name order.
+        pub const SKINSET_LANE_COVERAGE: &[BitFlags<Lane>] = &[
+    "#
+        )
+    )?;
+
+    writeln!(
+        &mut writer,
+        "{}\t{full_team_data}\n];",
+        unindent::unindent(
+            r#"
+        /// Whether each skinset (in skinset name order) has five champs that can fill every lane between them, using
+        /// their default lanes.
+        pub const SKINSET_HAS_FULL_TEAM: &[bool] = &[
+    "#
+        )
+    )?;
+
+    // Make a sorted, de-duplicated list of all the skin names. 
+    let mut skins_sorted: Vec<String> = champs_to_skins
+        .values()
+        .flat_map(|skins| skins.iter().map(|(_, skin_name)| skin_name.clone()))
+        .collect();
+    skins_sorted.sort();
+    skins_sorted.dedup();
+
+    // Format the skin list. 
+    let all_skin_data = skins_sorted
+        .iter()
+        .map(|skin| format!("r##\"{skin}\"##"))
+        .collect::<Vec<String>>()
+        .join(",\n\t");
+
+    // Add all skin names to the file.
+    writeln!(
+        &mut writer,
+        "{}\t{all_skin_data}\n];",
+        unindent::unindent(
+            r#"
+        /// List of all skin names parsed from skinset HTML table at compile time. 
+        pub const ALL_SKIN_NAMES: &[&str] = &[
+    "#
+        )
+    )?;
+
+    // Hash the skin names too, since skin indices shift whenever skins are added or removed.
+    let skin_version: u32 = fnv1a(&skins_sorted.iter().flat_map(|skin| skin.bytes().chain([0])).collect::<Vec<u8>>());
+
+    writeln!(
+        &mut writer,
+        "{}",
+        unindent::unindent(&format!(
+            r#"
+        /// Hash of all the skin names, which changes whenever skins are added or removed -- and so whenever skin
+        /// indices shift.
+        pub const SKIN_VERSION: u32 = {skin_version};
+    "#
+        ))
+    )?;
+
+    // Convert the champ->skins map to a list (in champ name order) of (skinset index, skin index) pairs.
+    let mut sorted_champs_skins_map: Vec<(String, Vec<(String, String)>)> = champs_to_skins.into_iter().collect();
+    sorted_champs_skins_map.sort_by_key(|(champ_name, _)| champ_name.clone());
+
+    let skin_map_data = sorted_champs_skins_map
+        .into_iter()
+        .map(|(_, skins)| {
+            let mut skin_indices: Vec<(usize, usize)> = skins
+                .into_iter()
+                .map(|(skinset, skin_name)| {
+                    (
+                        skinsets_sorted.binary_search(&skinset).unwrap(),
+                        skins_sorted.binary_search(&skin_name).unwrap(),
+                    )
+                })
+                .collect();
+
+            // Sort and de-duplicate so that the generated code is stable. 
+            skin_indices.sort();
+            skin_indices.dedup();
+            format!("&{skin_indices:?}")
+        })
+        .collect::<Vec<String>>()
+        .join(",\n\t");
+
+    // Write the skin map.
+    writeln!(
+        &mut writer,
+        "{}\t{skin_map_data}\n];",
+        unindent::unindent(
+            r#"
+        /// Map of all champs (in champ name order) to the (skinset index, skin index) pairs of the skins they have. 
+        pub const CHAMPS_TO_SKINS: &[&[(usize, usize)]] = &[
+    "#
+        )
+    )?;
+
+    // Flush any unwritten content.
+    writer.flush()?;
+
+    // Build status exits OK.
+    Ok(())
+}
+
+/// Every lane, in the order they are declared in the generated [Lane] enum.
+const LANES: [&str; 5] = ["Top", "Jungle", "Mid", "Bot", "Support"];
+
+/// Check whether champs with the given lanes could fill every lane between them, each champ playing one lane. This is
+/// a bipartite matching of lanes to champs, found with augmenting paths.
+fn has_full_team(champ_lanes: &[&[&str]]) -> bool {
+    // The lane (index into [LANES]) each champ has been given, if any.
+    let mut champ_lane: Vec<Option<usize>> = vec![None; champ_lanes.len()];
+
+    (0..LANES.len()).all(|lane_index| {
+        assign_lane(champ_lanes, lane_index, &mut champ_lane, &mut vec![false; champ_lanes.len()])
+    })
+}
+
+/// Give a lane to a champ who can play it, moving champs that already have a lane to another one where needed.
+/// `visited` marks the champs already tried for this assignment.
+fn assign_lane(
+    champ_lanes: &[&[&str]],
+    lane_index: usize,
+    champ_lane: &mut [Option<usize>],
+    visited: &mut [bool],
+) -> bool {
+    for champ_index in 0..champ_lanes.len() {
+        if visited[champ_index] || !champ_lanes[champ_index].contains(&LANES[lane_index]) {
+            continue;
+        }
+
+        visited[champ_index] = true;
+
+        let reassigned: bool = match champ_lane[champ_index] {
+            None => true,
+            Some(other_lane_index) => assign_lane(champ_lanes, other_lane_index, champ_lane, visited),
+        };
+
+        if reassigned {
+            champ_lane[champ_index] = Some(lane_index);
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Build the code for a perfect hash map from normalized names to their index in the given order. Panics if two
+/// names normalize the same, since one of them could then never be looked up.
+fn name_lookup<'a>(names: impl Iterator<Item = &'a str>) -> String {
+    let mut map: phf_codegen::Map<String> = phf_codegen::Map::new();
+    let mut seen: HashSet<String> = HashSet::new();
+
+    for (index, name) in names.enumerate() {
+        let key: String = names::normalize_name(name);
+        assert!(seen.insert(key.clone()), "{name:?} is indistinguishable from another name once normalized");
+        map.entry(key, &index.to_string());
+    }
+
+    map.build().to_string()
+}
+
+/// 32-bit FNV-1a hash, used to derive stable keys and versions from the scraped data.
+fn fnv1a(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c9dc5, |hash, byte| (hash ^ *byte as u32).wrapping_mul(0x01000193))
+}
+
+/// Parse the skinsets file from html and return a map from champ name -> skinsets, a set of all the skinset names, and
+/// a map from champ name -> (skinset name, skin name) for every skin they have.
+///
+/// Adapted from original runtime version.
+#[allow(clippy::type_complexity)]
+fn parse_skinsets_file() -> (
+    HashMap<String, HashSet<String>>,
+    HashSet<String>,
+    HashMap<String, Vec<(String, String)>>,
+) {
+    // Parse the fragment we're useing.
+    let fragment = Html::parse_fragment(SKINSETS_HTML);
+    // Make a selector to get rows out of the table.
+    let rows_selector: Selector = Selector::parse("tr").expect("rows selector good");
+    // Make a selector to find champs from a row element.
+    let champs_selector: Selector = Selector::parse("li > span").expect("champ selector good");
+    // Make a selector to find the set name from a row ref.
+    let set_name_selector: Selector =
+        Selector::parse("th:last-of-type").expect("set name selector good");
+    // Make an iterator to go over all the rows of the skinset table, skipping the header row.
+    let row_iter = fragment.select(&rows_selector).skip(1);
+    // Make the champ-skinset map to populate
+    let mut champ_to_skinset_map: HashMap<String, HashSet<String>> = HashMap::new();
+    // Make set of all skinsets to store and pass out too.
+    let mut set_of_all_skinsets: HashSet<String> = HashSet::new();
+    // Make the champ-skins map to populate.
+    let mut champ_to_skins_map: HashMap<String, Vec<(String, String)>> = HashMap::new();
+
+    // Iterate over all the rows of the table.
+    for row_ref in row_iter {
+        // Get the set name.
+        let set_name: String = row_ref
+            .select(&set_name_selector)
+            .next()
+            .expect("finds set name")
+            .text()
+            .collect::<String>();
+
+        // Insert/upsert into the set of all skinsets.
+        set_of_all_skinsets.insert(set_name.clone());
+
+        // Get an iterator over all the champ names in this set, along with the name of the skin.
+        let champs_iter = row_ref.select(&champs_selector).map(|champ_el_ref| {
+            let champ_name: String = champ_el_ref
+                // Get the referenced element
+                .value()
+                // Read the 'data-champion' attribute
+                .attr("data-champion")
+                // Crash if it's not there.
+                .expect("champion name available")
+                // Convert to owned string
+                .to_owned();
+
+            // The skin name is the text label next to the skin icon.
+            let skin_name: String = champ_el_ref.text().collect::<String>().trim().to_owned();
+
+            (champ_name, skin_name)
+        });
+
+        // Add all of the champ-skinset and champ-skin mappings into the maps.
+        for (champ, skin_name) in champs_iter {
+            champ_to_skinset_map
+                // Get the map entry for this champ.
+                .entry(champ.clone())
+                // Make a new empty one if not recognized.
+                .or_default()
+                // Add the set name to this champ's list.
+                .insert(set_name.clone());
+
+            champ_to_skins_map
+                .entry(champ)
+                .or_default()
+                .push((set_name.clone(), skin_name));
+        }
+    }
+
+    (champ_to_skinset_map, set_of_all_skinsets, champ_to_skins_map)
+}
+
+/// Parse the skinsets file from html and return a map from skinset name -> the universes it's listed under. Most
+/// skinsets are under exactly one universe, but a few are listed under more than one.
+fn parse_universes_file() -> HashMap<String, Vec<String>> {
+    let fragment = Html::parse_fragment(SKINSETS_HTML);
+    let rows_selector: Selector = Selector::parse("tr").expect("rows selector good");
+    let headers_selector: Selector = Selector::parse("th").expect("headers selector good");
+    let mut skinset_to_universes_map: HashMap<String, Vec<String>> = HashMap::new();
+
+    // The universe heading spans several rows, so it only appears in the first row of each universe. Keep track of
+    // the last one seen.
+    let mut current_universe: Option<String> = None;
+
+    // Iterate over all the rows of the table, skipping the header row.
+    for row_ref in fragment.select(&rows_selector).skip(1) {
+        let headers: Vec<String> = row_ref
+            .select(&headers_selector)
+            .map(|header_ref| header_ref.text().collect::<String>().trim().to_owned())
+            .collect();
+
+        // Rows that start a universe have both the universe and set name headings.
+        if let [universe, _] = headers.as_slice() {
+            current_universe = Some(universe.clone());
+        }
+
+        // The set name is always the last heading. Use the same text as `parse_skinsets_file` so the names match.
+        let set_name: String = row_ref
+            .select(&headers_selector)
+            .last()
+            .expect("finds set name")
+            .text()
+            .collect::<String>();
+
+        skinset_to_universes_map
+            .entry(set_name)
+            .or_default()
+            .push(current_universe.clone().expect("first row starts a universe"));
+    }
+
+    skinset_to_universes_map
+}
+
+/// Parse the lanes table file from html and return a map from champ name -> lanes.
+///
+/// Adapted from original runtime version.
+fn parse_lanes_file() -> Vec<(String, Vec<&'static str>)> {
+    // Make map to populate.
+    let mut champ_to_lanes_map = Vec::new();
+    // Load the table fragment into a scrapable document.
+    let fragment: Html = Html::parse_fragment(LANES_HTML);
+    // Make a selector to get rows out of the table.
+    let rows_selector: Selector = Selector::parse("tbody > tr").expect("rows selector good");
+    // Make a selector to get columns out of a row element.
+    let cols_selector: Selector = Selector::parse("td").expect("cols selector good");
+
+    // Iterate over every row in the table.
+    for row_ref in fragment.select(&rows_selector) {
+        // Assume the children of the row are (in order):
+        // Champ name, top, jungle, mid, bot lane, support, unused.
+
+        // Make an iterator over column elements that takes the exact number we want.
+        let mut cols_iterator = row_ref
+            .select(&cols_selector)
+            // Ignore the unused column.
+            .take(6);
+
+        // Take the champ name from the iterator.
+        let champ_name: String = cols_iterator
+            .next()
+            .expect("Finds champ name element")
+            .value()
+            .attr("data-sort-value")
+            .expect("Finds champ name attribute")
+            .to_owned();
+
+        // Make a list of lanes to populate by iterating over the rest of the columns.
+        let mut lanes: Vec<&'static str> = Vec::with_capacity(5);
+        // Iterate over the remaining columns to populate bitflags.
+        for (index, col) in cols_iterator.enumerate() {
+            if col.value().attr("data-sort-value").is_some() {
+                match index {
+                    0 => lanes.push("Top"),
+                    1 => lanes.push("Jungle"),
+                    2 => lanes.push("Mid"),
+                    3 => lanes.push("Bot"),
+                    4 => lanes.push("Support"),
+                    // Unreachable because we limit the number of <td> tags in the iterator using take()
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        // Add the champ and their lanes to the map. We use insert rather than upsert here because we assume there
+        // are no duplicates in the table.
+        champ_to_lanes_map.push((champ_name, lanes));
+    }
+
+    champ_to_lanes_map
+}
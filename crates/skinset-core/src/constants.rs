@@ -0,0 +1,465 @@
+//! Staticly available information and information generated using the build script that scrapes the downloaded html 
+//! tables.
+
+use std::{collections::HashSet, fmt, iter::FusedIterator, str::FromStr};
+use enumflags2::BitFlags;
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+use crate::names::{self, normalize_name};
+use super::generated::{
+    LANE_DATA, ALL_SKINSET_NAMES, ALL_SKIN_NAMES, ALL_UNIVERSE_NAMES, CHAMPS_TO_SKINSETS, CHAMPS_TO_SKINS, CHAMP_KEYS,
+    CHAMP_NAME_LOOKUP, SKINSETS_TO_CHAMPS, SKINSETS_TO_UNIVERSES, SKINSET_HAS_FULL_TEAM, SKINSET_LANE_COVERAGE,
+    SKINSET_NAME_LOOKUP,
+};
+
+/// Re-export the lane enum.
+pub use crate::generated::Lane;
+
+/// How many suggestions an [UnknownNameError] carries.
+const ERROR_SUGGESTIONS: usize = 3;
+
+/// Error for a champ or skinset name that doesn't match anything, with the closest known names as suggestions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownNameError {
+    /// What was being looked up -- "champion" or "skinset".
+    pub kind: &'static str,
+
+    /// The name as it was given.
+    pub name: String,
+
+    /// The closest known names, closest first. May be empty.
+    pub suggestions: Vec<&'static str>,
+}
+
+impl fmt::Display for UnknownNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} {:?}", self.kind, self.name)?;
+
+        if !self.suggestions.is_empty() {
+            write!(f, " (did you mean {}?)", self.suggestions.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for UnknownNameError {}
+
+/// The ID used to refer to a league of legends champ.
+/// Under the hood this is just an index into the [LANE_DATA] constant, which shifts whenever a champ is added, so this
+/// is (de)serialized as the champ's name instead. 
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ChampId(usize);
+
+impl ChampId {
+    /// The highest valid champion ID.
+    pub const MAX: Self = ChampId(LANE_DATA.len() -  1);
+
+    /// Return an iterator over all the valid [ChampId]s. 
+    pub fn iter_all() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator + FusedIterator {
+        (0..LANE_DATA.len()).map(ChampId)
+    }
+
+    /// Get the name of the champion that this ID refers to. 
+    #[inline]
+    pub const fn champ_name(self) -> &'static str {
+        LANE_DATA[self.0].0
+    }
+
+    /// Find the champion with the given name (exact match), if there is one.
+    pub fn from_champ_name(champ_name: &str) -> Option<Self> {
+        // The lane data is sorted by champ name, so we can binary search it.
+        LANE_DATA
+            .binary_search_by(|(iter_champ_name, _)| (*iter_champ_name).cmp(champ_name))
+            .ok()
+            .map(ChampId)
+    }
+
+    /// Find the champion with the given name, ignoring case, spaces and punctuation (so "kaisa" finds Kai'Sa).
+    pub fn from_name(name: &str) -> Option<Self> {
+        CHAMP_NAME_LOOKUP.get(normalize_name(name).as_str()).map(|index: &usize| ChampId(*index))
+    }
+
+    /// Get up to `n` champions with names closest to the given one by edit distance, closest first. Used to suggest
+    /// corrections for typos.
+    pub fn suggest(name: &str, n: usize) -> Vec<Self> {
+        names::closest(name, Self::iter_all().map(|champ_id| (champ_id, champ_id.champ_name())), n)
+    }
+
+    /// Get the default lanes of the champion that this ID refers to.
+    #[inline]
+    pub const fn default_lanes(self) -> BitFlags<Lane> {
+        LANE_DATA[self.0].1
+    }
+
+    /// Get an iterator over all the skinsets available for the champ referred to by this ID.
+    pub fn skinsets(self) -> impl DoubleEndedIterator<Item = SkinsetId> + ExactSizeIterator + FusedIterator {
+        CHAMPS_TO_SKINSETS[self.0].iter().map(|index: &usize| SkinsetId(*index))
+    }
+
+    /// Get an iterator over all the skins available for the champ referred to by this ID, along with the skinset each
+    /// one belongs to. A skin that belongs to multiple skinsets appears once for each.
+    pub fn skins(self) -> impl DoubleEndedIterator<Item = (SkinsetId, SkinId)> + ExactSizeIterator + FusedIterator {
+        CHAMPS_TO_SKINS[self.0]
+            .iter()
+            .map(|(skinset_index, skin_index)| (SkinsetId(*skinset_index), SkinId(*skin_index)))
+    }
+
+    /// Get an iterator over the skins this champ has in a given skinset.
+    pub fn skins_in(self, skinset_id: SkinsetId) -> impl Iterator<Item = SkinId> {
+        self.skins()
+            .filter(move |(iter_skinset_id, _)| *iter_skinset_id == skinset_id)
+            .map(|(_, skin_id)| skin_id)
+    }
+
+    /// Get the stable key for this champ. Unlike the ID itself, this does not change when other champs are added. 
+    #[inline]
+    pub const fn share_key(self) -> u32 {
+        CHAMP_KEYS[self.0]
+    }
+
+    /// Find the champ with a given stable key, if there is one. See [ChampId::share_key].
+    pub fn from_share_key(key: u32) -> Option<Self> {
+        CHAMP_KEYS.iter().position(|champ_key| *champ_key == key).map(ChampId)
+    }
+}
+
+
+/// The ID used to refer to a league of legends skinset. 
+/// Under the hood this is just an index into the [ALL_SKINSET_NAMES] constant, which shifts whenever a skinset is
+/// added, so this is (de)serialized as the skinset's name instead. 
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SkinsetId(usize);
+
+impl SkinsetId {
+    /// The highest valid [SkinsetId].
+    pub const MAX: Self = SkinsetId(ALL_SKINSET_NAMES.len() - 1);
+
+    /// The "Legacy" skinset, which should be excluded by default. 
+    const LEGACY: Self = Self::id_of_skinset("Legacy");
+
+    /// The "N/A" skinset which shoudl be excluded by default. 
+    const NA: Self = Self::id_of_skinset("N/A");
+
+    /// The skinsets excluded by default because they are not visually cohesive. 
+    pub const DEFAULT_EXCLUDED_SKINSETS: &'static [SkinsetId] = &[Self::LEGACY, Self::NA];
+
+    /// Skinsets that group skins by how they were released (events, rewards, etc) rather than by a shared visual
+    /// theme. Used by the "Cohesive themes only" preset.
+    pub const NON_COHESIVE_SKINSETS: &'static [SkinsetId] = &[
+        Self::LEGACY,
+        Self::NA,
+        Self::id_of_skinset("Beta"),
+        Self::id_of_skinset("Challenger"),
+        Self::id_of_skinset("Collector's Edition"),
+        Self::id_of_skinset("Conqueror"),
+        Self::id_of_skinset("Day Job"),
+        Self::id_of_skinset("PAX"),
+        Self::id_of_skinset("Three Honors"),
+        Self::id_of_skinset("Triumphant"),
+        Self::id_of_skinset("Victorious"),
+        Self::id_of_skinset("World Championship"),
+    ];
+
+    /// Compile-time function to get the index of a skinset by name. 
+    const fn id_of_skinset(skinset_name: &'static str) -> Self {
+        let mut index: usize = 0;
+
+        while index < ALL_SKINSET_NAMES.len() {
+            // Work-around for string equality not being available in const contexts.
+            if const_str::equal!(ALL_SKINSET_NAMES[index], skinset_name) {
+                return SkinsetId(index);
+            }
+            index += 1;
+        }
+
+        // Panic here if we don't find it -- we cannot use nice format args to make a nice panic message so just do 
+        // this.
+        panic!("BAD SKINSET ID PASSED TO CONST FN");
+    }
+
+    /// Get an iterator over all the valid [SkinsetId]s. 
+    pub fn iter_all() -> impl DoubleEndedIterator<Item = Self> + FusedIterator + ExactSizeIterator {
+        (0..ALL_SKINSET_NAMES.len()).map(SkinsetId)
+    }
+
+    /// Get the name of the skinset this ID refers to. 
+    #[inline]
+    pub const fn skinset_name(self) -> &'static str {
+        ALL_SKINSET_NAMES[self.0]
+    }
+
+    /// Find the skinset with the given name (exact match), if there is one.
+    pub fn from_skinset_name(skinset_name: &str) -> Option<Self> {
+        // The skinset names are sorted, so we can binary search them.
+        ALL_SKINSET_NAMES.binary_search(&skinset_name).ok().map(SkinsetId)
+    }
+
+    /// Find the skinset with the given name, ignoring case, spaces and punctuation (so "kda" finds K/DA).
+    pub fn from_name(name: &str) -> Option<Self> {
+        SKINSET_NAME_LOOKUP.get(normalize_name(name).as_str()).map(|index: &usize| SkinsetId(*index))
+    }
+
+    /// Get up to `n` skinsets with names closest to the given one by edit distance, closest first. Used to suggest
+    /// corrections for typos.
+    pub fn suggest(name: &str, n: usize) -> Vec<Self> {
+        names::closest(name, Self::iter_all().map(|skinset_id| (skinset_id, skinset_id.skinset_name())), n)
+    }
+
+    /// Generate the default set of all included skinsets. 
+    pub fn generate_default_included_skinsets() -> HashSet<SkinsetId> {
+        SkinsetId::iter_all()
+            .filter(|skinset_id| !SkinsetId::DEFAULT_EXCLUDED_SKINSETS.contains(skinset_id))
+            .collect()
+    }
+
+    /// Get an iterator over the champs that have a skin in this skinset, in name order.
+    pub fn champs(self) -> impl DoubleEndedIterator<Item = ChampId> + ExactSizeIterator + FusedIterator {
+        SKINSETS_TO_CHAMPS[self.0].iter().map(|index: &usize| ChampId(*index))
+    }
+
+    /// Check whether a champ has a skin in this skinset.
+    pub fn has_champ(self, champ_id: ChampId) -> bool {
+        SKINSETS_TO_CHAMPS[self.0].binary_search(&champ_id.0).is_ok()
+    }
+
+    /// Count the champs that have a skin in this skinset.
+    #[inline]
+    pub const fn champ_count(self) -> usize {
+        SKINSETS_TO_CHAMPS[self.0].len()
+    }
+
+    /// Get every lane at least one of this skinset's champs plays by default.
+    #[inline]
+    pub const fn lane_coverage(self) -> BitFlags<Lane> {
+        SKINSET_LANE_COVERAGE[self.0]
+    }
+
+    /// Check whether five of this skinset's champs could fill every lane between them, using their default lanes. A
+    /// full Summoner's Rift team can still be possible with custom lanes when this is false.
+    #[inline]
+    pub const fn has_full_team(self) -> bool {
+        SKINSET_HAS_FULL_TEAM[self.0]
+    }
+
+    /// Get an iterator over the universes this skinset is listed under. Almost every skinset has exactly one.
+    pub fn universes(self) -> impl DoubleEndedIterator<Item = UniverseId> + ExactSizeIterator + FusedIterator {
+        SKINSETS_TO_UNIVERSES[self.0].iter().map(|index: &usize| UniverseId(*index))
+    }
+
+    /// Get the underlying [usize] for this [SkinsetId].
+    pub const fn inner(self) -> usize {
+        self.0
+    }
+
+    /// Get the [SkinsetId] with a given underlying [usize], if it's in range. See [SkinsetId::inner].
+    pub fn from_inner(inner: usize) -> Option<Self> {
+        (inner < ALL_SKINSET_NAMES.len()).then_some(SkinsetId(inner))
+    }
+}
+
+/// The ID used to refer to a skin universe -- a group of related skinsets (e.g. "Star Guardian").
+/// Under the hood this is just an index into the [ALL_UNIVERSE_NAMES] constant. Universes are only used to organize
+/// skinsets in the UI, so this is never stored.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UniverseId(usize);
+
+impl UniverseId {
+    /// Get an iterator over all the valid [UniverseId]s.
+    pub fn iter_all() -> impl DoubleEndedIterator<Item = Self> + FusedIterator + ExactSizeIterator {
+        (0..ALL_UNIVERSE_NAMES.len()).map(UniverseId)
+    }
+
+    /// Get the name of the universe this ID refers to.
+    #[inline]
+    pub const fn universe_name(self) -> &'static str {
+        ALL_UNIVERSE_NAMES[self.0]
+    }
+
+    /// Get an iterator over the skinsets listed under this universe, in name order.
+    pub fn skinsets(self) -> impl Iterator<Item = SkinsetId> {
+        SkinsetId::iter_all().filter(move |skinset_id| skinset_id.universes().any(|universe_id| universe_id == self))
+    }
+
+    /// Get the underlying [usize] for this [UniverseId].
+    pub const fn inner(self) -> usize {
+        self.0
+    }
+}
+
+/// The ID used to refer to a league of legends skin. 
+/// Under the hood this is just an index into the [ALL_SKIN_NAMES] constant, so like the other IDs this is 
+/// (de)serialized as the skin's name. 
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SkinId(usize);
+
+impl SkinId {
+    /// Get the name of the skin this ID refers to (e.g. "Academy Ahri").
+    #[inline]
+    pub const fn skin_name(self) -> &'static str {
+        ALL_SKIN_NAMES[self.0]
+    }
+
+    /// Find the skin with the given name (exact match), if there is one.
+    pub fn from_skin_name(skin_name: &str) -> Option<Self> {
+        // The skin names are sorted, so we can binary search them.
+        ALL_SKIN_NAMES.binary_search(&skin_name).ok().map(SkinId)
+    }
+
+    /// Get the underlying [usize] for this [SkinId].
+    pub const fn inner(self) -> usize {
+        self.0
+    }
+
+    /// Get the [SkinId] with a given underlying [usize], if it's in range. See [SkinId::inner].
+    pub fn from_inner(inner: usize) -> Option<Self> {
+        (inner < ALL_SKIN_NAMES.len()).then_some(SkinId(inner))
+    }
+}
+
+impl FromStr for ChampId {
+    type Err = UnknownNameError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        ChampId::from_name(name).ok_or_else(|| UnknownNameError {
+            kind: "champion",
+            name: name.to_owned(),
+            suggestions: ChampId::suggest(name, ERROR_SUGGESTIONS).into_iter().map(ChampId::champ_name).collect(),
+        })
+    }
+}
+
+impl FromStr for SkinsetId {
+    type Err = UnknownNameError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        SkinsetId::from_name(name).ok_or_else(|| UnknownNameError {
+            kind: "skinset",
+            name: name.to_owned(),
+            suggestions: SkinsetId::suggest(name, ERROR_SUGGESTIONS).into_iter().map(SkinsetId::skinset_name).collect(),
+        })
+    }
+}
+
+impl Serialize for ChampId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.champ_name())
+    }
+}
+
+impl<'de> Deserialize<'de> for ChampId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let champ_name: String = String::deserialize(deserializer)?;
+        ChampId::from_champ_name(&champ_name).ok_or_else(|| D::Error::custom(format!("unknown champion {champ_name:?}")))
+    }
+}
+
+impl Serialize for SkinsetId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.skinset_name())
+    }
+}
+
+impl<'de> Deserialize<'de> for SkinsetId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let skinset_name: String = String::deserialize(deserializer)?;
+        SkinsetId::from_skinset_name(&skinset_name)
+            .ok_or_else(|| D::Error::custom(format!("unknown skinset {skinset_name:?}")))
+    }
+}
+
+impl Serialize for SkinId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.skin_name())
+    }
+}
+
+impl<'de> Deserialize<'de> for SkinId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let skin_name: String = String::deserialize(deserializer)?;
+        SkinId::from_skin_name(&skin_name).ok_or_else(|| D::Error::custom(format!("unknown skin {skin_name:?}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChampId, SkinsetId, UniverseId};
+
+    #[test]
+    fn ids_round_trip_through_names() {
+        for champ_id in ChampId::iter_all() {
+            let json: String = serde_json::to_string(&champ_id).unwrap();
+            assert_eq!(json, format!("{:?}", champ_id.champ_name()));
+            assert_eq!(serde_json::from_str::<ChampId>(&json).unwrap(), champ_id);
+        }
+
+        for skinset_id in SkinsetId::iter_all() {
+            let json: String = serde_json::to_string(&skinset_id).unwrap();
+            assert_eq!(serde_json::from_str::<SkinsetId>(&json).unwrap(), skinset_id);
+        }
+    }
+
+    #[test]
+    fn unknown_names_are_rejected() {
+        assert!(serde_json::from_str::<ChampId>(r#""Not A Champion""#).is_err());
+        assert!(serde_json::from_str::<SkinsetId>(r#""Not A Skinset""#).is_err());
+        // Positional IDs from before IDs were serialized by name are rejected rather than silently misread.
+        assert!(serde_json::from_str::<ChampId>("3").is_err());
+    }
+
+    #[test]
+    fn names_are_looked_up_loosely() {
+        let kaisa = ChampId::from_champ_name("Kai'Sa").unwrap();
+        assert_eq!(ChampId::from_name("kaisa"), Some(kaisa));
+        assert_eq!("KAI SA".parse::<ChampId>(), Ok(kaisa));
+        assert_eq!(SkinsetId::from_name("kda"), SkinsetId::from_skinset_name("K/DA"));
+
+        for champ_id in ChampId::iter_all() {
+            assert_eq!(ChampId::from_name(champ_id.champ_name()), Some(champ_id));
+        }
+
+        for skinset_id in SkinsetId::iter_all() {
+            assert_eq!(skinset_id.skinset_name().parse::<SkinsetId>(), Ok(skinset_id));
+        }
+
+        let err = "Jnx".parse::<ChampId>().unwrap_err();
+        assert!(err.suggestions.contains(&"Jinx"), "{err}");
+        assert_eq!(SkinsetId::suggest("Leggacy", 1), [SkinsetId::from_skinset_name("Legacy").unwrap()]);
+    }
+
+    #[test]
+    fn skinset_champs_mirror_champ_skinsets() {
+        for skinset_id in SkinsetId::iter_all() {
+            let scanned: usize = ChampId::iter_all().filter(|c| c.skinsets().any(|id| id == skinset_id)).count();
+            assert_eq!(skinset_id.champ_count(), scanned);
+
+            for champ_id in skinset_id.champs() {
+                assert!(champ_id.skinsets().any(|iter_skinset_id| iter_skinset_id == skinset_id));
+                assert!(skinset_id.has_champ(champ_id));
+                assert!(skinset_id.lane_coverage().contains(champ_id.default_lanes()));
+            }
+
+            if skinset_id.has_full_team() {
+                assert!(skinset_id.champ_count() >= 5 && skinset_id.lane_coverage().is_all());
+            }
+        }
+
+        assert!(SkinsetId::from_skinset_name("Legacy").unwrap().has_full_team());
+    }
+
+    #[test]
+    fn every_skinset_is_in_a_universe() {
+        for skinset_id in SkinsetId::iter_all() {
+            assert!(skinset_id.universes().len() > 0, "{} has no universe", skinset_id.skinset_name());
+
+            for universe_id in skinset_id.universes() {
+                assert!(universe_id.skinsets().any(|iter_skinset_id| iter_skinset_id == skinset_id));
+            }
+        }
+
+        let star_guardian = UniverseId::iter_all()
+            .find(|universe_id| universe_id.universe_name() == "Star Guardian")
+            .unwrap();
+        let star_guardian_season_1 = SkinsetId::from_skinset_name("Star Guardian Season 1").unwrap();
+        assert!(star_guardian.skinsets().any(|skinset_id| skinset_id == star_guardian_season_1));
+    }
+}
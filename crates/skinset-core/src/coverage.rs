@@ -0,0 +1,134 @@
+//! How well each player's champ pool covers each skinset, for spotting themes held back by a single player.
+
+use crate::{
+    constants::{ChampId, Lane, SkinsetId},
+    model::PlayerRecord,
+};
+use enumflags2::BitFlags;
+use std::collections::HashSet;
+
+/// One player's coverage of one skinset.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoverageCell {
+    /// The player's champs in the skinset, in the order of the player's champs.
+    pub champs: Vec<ChampId>,
+
+    /// Every lane the player has those champs selected for.
+    pub lanes: BitFlags<Lane>,
+
+    /// Whether the player has excluded the skinset for themselves.
+    pub excluded: bool,
+}
+
+/// Every player's coverage of one skinset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoverageColumn {
+    /// The skinset.
+    pub skinset: SkinsetId,
+
+    /// The coverage for each player, in the same order as the players.
+    pub cells: Vec<CoverageCell>,
+}
+
+impl CoverageColumn {
+    /// The total number of champs across every player in this skinset.
+    pub fn total(&self) -> usize {
+        self.cells.iter().map(|cell| cell.champs.len()).sum()
+    }
+
+    /// The fewest champs any player has in this skinset -- the bottleneck.
+    pub fn min(&self) -> usize {
+        self.cells.iter().map(|cell| cell.champs.len()).min().unwrap_or_default()
+    }
+}
+
+/// How to order the coverage columns.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CoverageSort {
+    /// In skinset order.
+    #[default]
+    Skinset,
+
+    /// By column total, most covered first.
+    TotalDescending,
+
+    /// By column total, least covered first.
+    TotalAscending,
+}
+
+/// Get the coverage of every included skinset, ordered by `sort`. Columns with equal totals stay in skinset order.
+pub fn coverage(
+    players: &[PlayerRecord],
+    included_skinsets: &HashSet<SkinsetId>,
+    sort: CoverageSort,
+) -> Vec<CoverageColumn> {
+    let mut skinsets: Vec<SkinsetId> = included_skinsets.iter().copied().collect();
+    skinsets.sort();
+
+    let mut columns: Vec<CoverageColumn> = skinsets
+        .into_iter()
+        .map(|skinset_id| CoverageColumn {
+            skinset: skinset_id,
+            cells: players
+                .iter()
+                .map(|player| {
+                    let mut cell = CoverageCell {
+                        excluded: player.excluded_skinsets.contains(&skinset_id),
+                        ..CoverageCell::default()
+                    };
+
+                    for (champ_id, lanes) in player.champs.iter() {
+                        if champ_id.skinsets().any(|id| id == skinset_id) {
+                            cell.champs.push(*champ_id);
+                            cell.lanes |= *lanes;
+                        }
+                    }
+
+                    cell
+                })
+                .collect(),
+        })
+        .collect();
+
+    match sort {
+        CoverageSort::Skinset => {}
+        CoverageSort::TotalDescending => columns.sort_by_key(|column| std::cmp::Reverse(column.total())),
+        CoverageSort::TotalAscending => columns.sort_by_key(CoverageColumn::total),
+    }
+
+    columns
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{coverage, CoverageSort};
+    use crate::{
+        constants::{ChampId, Lane, SkinsetId},
+        model::PlayerRecord,
+    };
+    use enumflags2::BitFlags;
+    use std::collections::HashSet;
+
+    #[test]
+    fn counts_champs_per_player_and_sorts_by_total() {
+        let mut first = PlayerRecord::new();
+        first.champs.push((ChampId::from_champ_name("Ahri").unwrap(), BitFlags::from(Lane::Mid)));
+        first.champs.push((ChampId::from_champ_name("Akali").unwrap(), Lane::Top | Lane::Mid));
+        let mut second = PlayerRecord::new();
+        second.add_champ(ChampId::from_champ_name("Garen").unwrap());
+
+        let kda = SkinsetId::from_skinset_name("K/DA").unwrap();
+        let demacia = SkinsetId::from_skinset_name("Demacia Vice").unwrap();
+        let included: HashSet<SkinsetId> = HashSet::from([kda, demacia]);
+
+        let columns = coverage(&[first, second], &included, CoverageSort::TotalDescending);
+        assert_eq!(columns[0].skinset, kda);
+        assert_eq!(columns[0].cells[0].champs.len(), 2);
+        assert_eq!(columns[0].cells[0].lanes, Lane::Top | Lane::Mid);
+        assert_eq!(columns[0].min(), 0);
+        assert_eq!(columns[1].total(), 1);
+
+        let ascending = coverage(&[], &included, CoverageSort::TotalAscending);
+        assert!(ascending.iter().all(|column| column.total() == 0));
+    }
+}
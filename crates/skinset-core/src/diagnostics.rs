@@ -0,0 +1,310 @@
+//! Explanations for why the solver found no comps -- which players, lanes and exclusions are getting in the way.
+
+use crate::{
+    constants::{Lane, SkinsetId},
+    model::{GameMode, PlayerRecord, SolverSettings},
+    solver,
+};
+use enumflags2::BitFlags;
+use std::collections::HashSet;
+
+/// An included skinset that every player but one has a champ in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NearMiss {
+    /// The skinset.
+    pub skinset: SkinsetId,
+
+    /// The index of the player with no champ in it.
+    pub missing_player: usize,
+}
+
+/// Players who can't all be given distinct lanes, since between them they play fewer lanes than there are of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaneShortage {
+    /// The indices of the players, sorted.
+    pub players: Vec<usize>,
+
+    /// Every lane any of them can play.
+    pub lanes: BitFlags<Lane>,
+}
+
+/// What removed a skinset the players could otherwise queue a full comp in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Exclusion {
+    /// The skinset is included by default, but was deselected from the search.
+    NotIncluded,
+
+    /// The players at these indices excluded the skinset for themselves.
+    ExcludedByPlayers(Vec<usize>),
+}
+
+/// A skinset that would have had comps if not for an exclusion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExcludedSkinset {
+    /// The skinset.
+    pub skinset: SkinsetId,
+
+    /// What removed it.
+    pub exclusion: Exclusion,
+}
+
+/// Everything found to explain a lack of comps. Every list is sorted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Diagnostics {
+    /// The indices of players with no champs at all.
+    pub empty_pools: Vec<usize>,
+
+    /// The indices of players with champs, but none that can be played in an included skinset they accept.
+    pub no_usable_champs: Vec<usize>,
+
+    /// Players who can't all be given a lane of their own, found only on Summoner's Rift.
+    pub lane_shortage: Option<LaneShortage>,
+
+    /// Pairs of player indices whose usable champs share no included skinset.
+    pub disjoint_pairs: Vec<(usize, usize)>,
+
+    /// Included skinsets that every player but one has a usable champ in.
+    pub near_misses: Vec<NearMiss>,
+
+    /// Skinsets that only an exclusion is keeping out of the results.
+    pub excluded_skinsets: Vec<ExcludedSkinset>,
+}
+
+impl Diagnostics {
+    /// Check whether nothing was found.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// Get the included skinsets a player accepts for any champ they could be given (honouring their champ lock).
+fn usable_skinsets(player: &PlayerRecord, included: &HashSet<SkinsetId>, settings: SolverSettings) -> HashSet<SkinsetId> {
+    player
+        .champs
+        .iter()
+        .filter(|(champ_id, lanes)| {
+            player.lock.allows_champ(*champ_id)
+                && (settings.game_mode == GameMode::Aram || lanes.iter().any(|lane| player.lock.allows_lane(lane)))
+        })
+        .flat_map(|(champ_id, _)| {
+            champ_id
+                .skinsets()
+                .filter(|skinset_id| included.contains(skinset_id))
+                .filter(|skinset_id| player.accepts_skinset(*champ_id, *skinset_id, settings.owned_only))
+        })
+        .collect()
+}
+
+/// Get every lane a player could be given, honouring their lock-in.
+fn playable_lanes(player: &PlayerRecord) -> BitFlags<Lane> {
+    player
+        .champs
+        .iter()
+        .filter(|(champ_id, _)| player.lock.allows_champ(*champ_id))
+        .flat_map(|(_, lanes)| lanes.iter())
+        .filter(|lane| player.lock.allows_lane(*lane))
+        .collect()
+}
+
+/// Find players who can't all be given distinct lanes, if there are any. This is a bipartite matching of players to
+/// lanes, found with augmenting paths. When a player can't be given a lane, the players tried along the way only play
+/// lanes already held by each other, so they're the ones reported.
+fn lane_shortage(player_lanes: &[BitFlags<Lane>]) -> Option<LaneShortage> {
+    // The player given each lane, if any.
+    let mut lane_players: [Option<usize>; 5] = [None; 5];
+
+    for player_index in 0..player_lanes.len() {
+        let mut visited: Vec<bool> = vec![false; player_lanes.len()];
+
+        if !assign_lane(player_lanes, player_index, &mut lane_players, &mut visited) {
+            let players: Vec<usize> = (0..player_lanes.len()).filter(|index| visited[*index]).collect();
+            let lanes: BitFlags<Lane> = players.iter().map(|index| player_lanes[*index]).collect();
+            return Some(LaneShortage { players, lanes });
+        }
+    }
+
+    None
+}
+
+/// Give a player a lane they can play, moving players that already have one to another where needed. `visited` marks
+/// the players already tried for this assignment.
+fn assign_lane(
+    player_lanes: &[BitFlags<Lane>],
+    player_index: usize,
+    lane_players: &mut [Option<usize>; 5],
+    visited: &mut [bool],
+) -> bool {
+    visited[player_index] = true;
+
+    for lane in player_lanes[player_index].iter() {
+        // Lanes are single bits declared in lane order, so the bit position is the lane's index.
+        let lane_index: usize = (lane as u8).trailing_zeros() as usize;
+
+        let assigned: bool = match lane_players[lane_index] {
+            None => true,
+            Some(other) => !visited[other] && assign_lane(player_lanes, other, lane_players, visited),
+        };
+
+        if assigned {
+            lane_players[lane_index] = Some(player_index);
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Work out why the players can't queue any comps in the included skinsets. This uses the same inputs as
+/// [`solver::solve`], and can be run whatever the results -- it just finds more when there are none.
+pub fn diagnose(players: &[PlayerRecord], included_skinsets: &HashSet<SkinsetId>, settings: SolverSettings) -> Diagnostics {
+    let mut diagnostics = Diagnostics::default();
+
+    let usable: Vec<HashSet<SkinsetId>> = players
+        .iter()
+        .map(|player| usable_skinsets(player, included_skinsets, settings))
+        .collect();
+
+    for (player_index, player) in players.iter().enumerate() {
+        if player.champs.is_empty() {
+            diagnostics.empty_pools.push(player_index);
+        } else if usable[player_index].is_empty() {
+            diagnostics.no_usable_champs.push(player_index);
+        }
+    }
+
+    // Lanes only matter if there aren't enough of them to go around.
+    if settings.game_mode == GameMode::SummonersRift {
+        // Players with empty pools are already reported, so leave them out.
+        let with_champs: Vec<usize> = (0..players.len()).filter(|index| !players[*index].champs.is_empty()).collect();
+        let player_lanes: Vec<BitFlags<Lane>> =
+            with_champs.iter().map(|index| playable_lanes(&players[*index])).collect();
+
+        diagnostics.lane_shortage = lane_shortage(&player_lanes).map(|mut shortage| {
+            shortage.players.iter_mut().for_each(|index| *index = with_champs[*index]);
+            shortage
+        });
+    }
+
+    // Players with no usable champs are already reported, so leave them out of the pairs.
+    for first in 0..players.len() {
+        for second in first + 1..players.len() {
+            if !usable[first].is_empty() && !usable[second].is_empty() && usable[first].is_disjoint(&usable[second]) {
+                diagnostics.disjoint_pairs.push((first, second));
+            }
+        }
+    }
+
+    // Near misses only make sense with more than one player.
+    if players.len() > 1 {
+        let mut skinsets: Vec<SkinsetId> = included_skinsets.iter().copied().collect();
+        skinsets.sort();
+
+        for skinset_id in skinsets {
+            let missing: Vec<usize> = (0..players.len()).filter(|index| !usable[*index].contains(&skinset_id)).collect();
+
+            if let [missing_player] = missing[..] {
+                diagnostics.near_misses.push(NearMiss { skinset: skinset_id, missing_player });
+            }
+        }
+    }
+
+    // Skinsets that would work without the players' own exclusions, or if they were included.
+    let without_exclusions: Vec<PlayerRecord> = players
+        .iter()
+        .cloned()
+        .map(|mut player| {
+            player.excluded_skinsets.clear();
+            player
+        })
+        .collect();
+
+    for skinset_id in SkinsetId::iter_all() {
+        let excluded_by: Vec<usize> = (0..players.len())
+            .filter(|index| players[*index].excluded_skinsets.contains(&skinset_id))
+            .collect();
+
+        // Only skinsets that are included by default count as deselected -- the ones left out by default would flood
+        // the list, and are usually left out for a reason.
+        let exclusion: Exclusion = if !included_skinsets.contains(&skinset_id) {
+            if SkinsetId::DEFAULT_EXCLUDED_SKINSETS.contains(&skinset_id) {
+                continue;
+            }
+
+            Exclusion::NotIncluded
+        } else if !excluded_by.is_empty() {
+            Exclusion::ExcludedByPlayers(excluded_by)
+        } else {
+            continue;
+        };
+
+        // Only report it if the exclusion is the only thing in the way.
+        let achievable: bool = match exclusion {
+            Exclusion::NotIncluded => solver::is_achievable(players, skinset_id, settings),
+            Exclusion::ExcludedByPlayers(_) => solver::is_achievable(&without_exclusions, skinset_id, settings),
+        };
+
+        if achievable {
+            diagnostics.excluded_skinsets.push(ExcludedSkinset { skinset: skinset_id, exclusion });
+        }
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diagnose, Exclusion, ExcludedSkinset, LaneShortage, NearMiss};
+    use crate::{
+        constants::{Lane, SkinsetId},
+        model::SolverSettings,
+        test_fixtures::{player, skinset},
+    };
+    use enumflags2::BitFlags;
+    use std::collections::HashSet;
+
+    #[test]
+    fn finds_empty_pools_and_lane_shortages() {
+        let mut ahri = player(&["Ahri"]);
+        ahri.champs[0].1 = BitFlags::from(Lane::Mid);
+        let mut lux = player(&["Lux"]);
+        lux.champs[0].1 = BitFlags::from(Lane::Mid);
+        let mut garen = player(&["Garen"]);
+        garen.champs[0].1 = Lane::Top | Lane::Jungle;
+        let included: HashSet<SkinsetId> = SkinsetId::iter_all().collect();
+
+        let diagnostics = diagnose(&[ahri.clone(), lux.clone(), player(&[])], &included, SolverSettings::default());
+        assert_eq!(diagnostics.empty_pools, vec![2]);
+
+        // Three lanes are covered between the three of them, but Ahri and Lux both need mid.
+        let diagnostics = diagnose(&[ahri.clone(), garen, lux], &included, SolverSettings::default());
+        assert_eq!(diagnostics.lane_shortage, Some(LaneShortage { players: vec![0, 2], lanes: Lane::Mid.into() }));
+
+        lux = player(&["Lux"]);
+        lux.champs[0].1 = Lane::Mid | Lane::Support;
+        assert_eq!(diagnose(&[ahri, lux], &included, SolverSettings::default()).lane_shortage, None);
+    }
+
+    #[test]
+    fn finds_near_misses_and_exclusions() {
+        let mut ahri = player(&["Ahri"]);
+        let evelynn = player(&["Evelynn"]);
+        let garen = player(&["Garen"]);
+        let kda = skinset("K/DA");
+
+        // Garen isn't in K/DA, so it's a near miss for the three of them.
+        let included: HashSet<SkinsetId> = HashSet::from([kda]);
+        let diagnostics = diagnose(&[ahri.clone(), evelynn.clone(), garen], &included, SolverSettings::default());
+        assert_eq!(diagnostics.near_misses, vec![NearMiss { skinset: kda, missing_player: 2 }]);
+        assert!(diagnostics.disjoint_pairs.is_empty());
+
+        // Excluding K/DA for Ahri leaves the pair with nothing in common, and is reported.
+        ahri.excluded_skinsets.insert(kda);
+        let diagnostics = diagnose(&[ahri, evelynn], &included, SolverSettings::default());
+        assert_eq!(diagnostics.no_usable_champs, vec![0]);
+        assert!(diagnostics.excluded_skinsets.contains(&ExcludedSkinset {
+            skinset: kda,
+            exclusion: Exclusion::ExcludedByPlayers(vec![0]),
+        }));
+        assert!(diagnostics.excluded_skinsets.iter().any(|excluded| excluded.exclusion == Exclusion::NotIncluded));
+    }
+}
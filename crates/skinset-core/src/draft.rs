@@ -0,0 +1,137 @@
+//! Champ select drafts -- the champs banned or picked by the enemy team, which nobody on the roster can play.
+
+use crate::{constants::ChampId, model::PlayerRecord, solver::Comp};
+
+/// Why a champ is unavailable in the draft.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unavailable {
+    /// The champ was banned by either team.
+    Banned,
+
+    /// The champ was picked by the enemy team.
+    EnemyPick,
+}
+
+/// The champs taken out of the pool so far in champ select. The solver treats these as excluded for every player, on
+/// top of each [`PlayerRecord`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Draft {
+    /// Banned champs, in the order they were banned.
+    pub bans: Vec<ChampId>,
+
+    /// Champs picked by the enemy team, in the order they were picked.
+    pub enemy_picks: Vec<ChampId>,
+}
+
+impl Draft {
+    /// The most champs that can be banned -- five per team.
+    pub const MAX_BANS: usize = 10;
+
+    /// The most champs the enemy team can pick.
+    pub const MAX_ENEMY_PICKS: usize = 5;
+
+    /// Check whether nothing has been banned or picked yet.
+    pub fn is_empty(&self) -> bool {
+        self.bans.is_empty() && self.enemy_picks.is_empty()
+    }
+
+    /// Check why a champ is unavailable, if it is.
+    pub fn status(&self, champ_id: ChampId) -> Option<Unavailable> {
+        if self.bans.contains(&champ_id) {
+            Some(Unavailable::Banned)
+        } else if self.enemy_picks.contains(&champ_id) {
+            Some(Unavailable::EnemyPick)
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over every unavailable champ, bans first.
+    pub fn unavailable(&self) -> impl Iterator<Item = ChampId> + '_ {
+        self.bans.iter().chain(self.enemy_picks.iter()).copied()
+    }
+
+    /// Ban a champ. Returns `false` and does nothing if the champ is already unavailable or the bans are full.
+    pub fn ban(&mut self, champ_id: ChampId) -> bool {
+        let can_ban: bool = self.status(champ_id).is_none() && self.bans.len() < Self::MAX_BANS;
+
+        if can_ban {
+            self.bans.push(champ_id);
+        }
+
+        can_ban
+    }
+
+    /// Mark a champ as picked by the enemy team. Returns `false` and does nothing if the champ is already unavailable
+    /// or the enemy team has picked all their champs.
+    pub fn add_enemy_pick(&mut self, champ_id: ChampId) -> bool {
+        let can_pick: bool = self.status(champ_id).is_none() && self.enemy_picks.len() < Self::MAX_ENEMY_PICKS;
+
+        if can_pick {
+            self.enemy_picks.push(champ_id);
+        }
+
+        can_pick
+    }
+
+    /// Make a champ available again, whether it was banned or picked.
+    pub fn remove(&mut self, champ_id: ChampId) {
+        self.bans.retain(|banned| *banned != champ_id);
+        self.enemy_picks.retain(|picked| *picked != champ_id);
+    }
+
+    /// Copy the players with every unavailable champ (and any lock on one) removed, ready to be solved.
+    pub fn apply(&self, players: &[PlayerRecord]) -> Vec<PlayerRecord> {
+        let mut players: Vec<PlayerRecord> = players.to_vec();
+
+        for player in players.iter_mut() {
+            self.unavailable().for_each(|champ_id| player.remove_champ(champ_id));
+        }
+
+        players
+    }
+
+    /// The picks in a comp that are no longer possible, in pick order.
+    pub fn taken_in(&self, comp: &Comp) -> Vec<ChampId> {
+        comp.picks.iter().map(|pick| pick.champ).filter(|champ_id| self.status(*champ_id).is_some()).collect()
+    }
+
+    /// Check whether a comp is still possible -- i.e. none of its champs have been taken.
+    pub fn allows(&self, comp: &Comp) -> bool {
+        comp.picks.iter().all(|pick| self.status(pick.champ).is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Draft, Unavailable};
+    use crate::{
+        constants::SkinsetId,
+        model::{GameMode, SolverSettings},
+        solver,
+        test_fixtures::{champ, player, skinset},
+    };
+    use std::collections::HashSet;
+
+    #[test]
+    fn drafted_champs_are_excluded_from_every_player() {
+        let players = vec![player(&["Ahri", "Akali"]), player(&["Ahri", "Akali"])];
+        let skinsets: HashSet<SkinsetId> = HashSet::from([skinset("K/DA")]);
+        let settings = SolverSettings { game_mode: GameMode::Aram, ..SolverSettings::default() };
+
+        let mut draft = Draft::default();
+        assert!(draft.ban(champ("Ahri")));
+        assert!(!draft.add_enemy_pick(champ("Ahri")));
+        assert_eq!(draft.status(champ("Ahri")), Some(Unavailable::Banned));
+
+        // Before the ban both players can swap Ahri and Akali; after it neither can play Ahri.
+        let comps = solver::solve(&players, &skinsets, settings);
+        assert_eq!(comps.len(), 2);
+        assert!(comps.iter().all(|comp| !draft.allows(comp)));
+        assert_eq!(draft.taken_in(&comps[0]), vec![champ("Ahri")]);
+        assert!(solver::solve(&draft.apply(&players), &skinsets, settings).is_empty());
+
+        draft.remove(champ("Ahri"));
+        assert!(draft.is_empty());
+    }
+}
@@ -0,0 +1,277 @@
+//! Serialization of resolved comps into CSV, JSON and Markdown for pasting into chat or spreadsheets.
+//!
+//! This only depends on the data model and the solver's output, so it can be used outside of the web app.
+
+use crate::{
+    constants::{SkinId, SkinsetId},
+    model::PlayerRecord,
+    solver::{Comp, Pick},
+};
+use derive_more::Display;
+use serde::Serialize;
+
+/// The formats results can be exported in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+pub enum ExportFormat {
+    /// Comma separated values, with a header row.
+    #[display(fmt = "CSV")]
+    Csv,
+
+    /// A pretty-printed JSON array of comps.
+    #[display(fmt = "JSON")]
+    Json,
+
+    /// A Markdown table.
+    #[display(fmt = "Markdown")]
+    Markdown,
+}
+
+impl ExportFormat {
+    /// All the export formats, in the order they're listed in the UI.
+    pub const ALL: [Self; 3] = [Self::Csv, Self::Json, Self::Markdown];
+
+    /// The file extension for this format, without the dot.
+    pub const fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::Markdown => "md",
+        }
+    }
+
+    /// The MIME type for this format.
+    pub const fn mime_type(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Json => "application/json",
+            ExportFormat::Markdown => "text/markdown",
+        }
+    }
+}
+
+/// Options for exporting results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExportOptions {
+    /// Include the names of the skins each player would use from the shared skinsets.
+    pub include_skin_names: bool,
+
+    /// Only list the skins each player owns, matching [`SolverSettings::owned_only`](crate::model::SolverSettings).
+    pub owned_only: bool,
+}
+
+/// A pick in an exported comp.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedPick {
+    /// The display name of the player.
+    pub player: String,
+
+    /// The name of the champ picked.
+    pub champ: &'static str,
+
+    /// The lane played, if the game mode has lanes.
+    pub lane: Option<String>,
+
+    /// The skins the player could use from the shared skinsets, if skin names were asked for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skins: Option<Vec<&'static str>>,
+}
+
+/// A comp as it is exported.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedComp {
+    /// One pick per player, in the same order as the players.
+    pub picks: Vec<ExportedPick>,
+
+    /// The names of the skinsets every pick shares.
+    pub skinsets: Vec<&'static str>,
+}
+
+/// Get the display name of each player. See [`PlayerRecord::display_name`].
+fn player_names(players: &[PlayerRecord]) -> Vec<String> {
+    players.iter().enumerate().map(|(index, player)| player.display_name(index)).collect()
+}
+
+/// Get the names of the skins a player can use for a pick in any of the given skinsets, without duplicates.
+fn skin_names(player: &PlayerRecord, pick: &Pick, skinsets: &[SkinsetId], owned_only: bool) -> Vec<&'static str> {
+    let mut skins: Vec<SkinId> = skinsets
+        .iter()
+        .flat_map(|skinset_id| pick.champ.skins_in(*skinset_id))
+        .filter(|skin_id| player.can_use_skin(*skin_id, owned_only))
+        .collect();
+    skins.sort();
+    skins.dedup();
+    skins.into_iter().map(SkinId::skin_name).collect()
+}
+
+/// Convert the comps into their exported form.
+pub fn exported_comps(players: &[PlayerRecord], comps: &[Comp], options: ExportOptions) -> Vec<ExportedComp> {
+    let names: Vec<String> = player_names(players);
+
+    comps
+        .iter()
+        .map(|comp| ExportedComp {
+            picks: comp
+                .picks
+                .iter()
+                .zip(players.iter().zip(names.iter()))
+                .map(|(pick, (player, name))| ExportedPick {
+                    player: name.clone(),
+                    champ: pick.champ.champ_name(),
+                    lane: pick.lane.map(|lane| lane.to_string()),
+                    skins: options
+                        .include_skin_names
+                        .then(|| skin_names(player, pick, &comp.skinsets, options.owned_only)),
+                })
+                .collect(),
+            skinsets: comp.skinsets.iter().map(|skinset_id| skinset_id.skinset_name()).collect(),
+        })
+        .collect()
+}
+
+/// Get the header and rows of the export as a table of plain strings, shared by the CSV and Markdown formats.
+pub fn table(players: &[PlayerRecord], comps: &[Comp], options: ExportOptions) -> (Vec<String>, Vec<Vec<String>>) {
+    let mut header: Vec<String> = Vec::new();
+
+    for name in player_names(players) {
+        header.push(format!("{name} Champ"));
+        header.push(format!("{name} Lane"));
+
+        if options.include_skin_names {
+            header.push(format!("{name} Skins"));
+        }
+    }
+
+    header.push("Shared Skinsets".to_owned());
+
+    let rows: Vec<Vec<String>> = exported_comps(players, comps, options)
+        .into_iter()
+        .map(|comp| {
+            let mut row: Vec<String> = Vec::with_capacity(header.len());
+
+            for pick in comp.picks {
+                row.push(pick.champ.to_owned());
+                row.push(pick.lane.unwrap_or_default());
+
+                if let Some(skins) = pick.skins {
+                    row.push(skins.join("; "));
+                }
+            }
+
+            row.push(comp.skinsets.join("; "));
+            row
+        })
+        .collect();
+
+    (header, rows)
+}
+
+/// Quote a CSV field if it needs it.
+pub fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// Escape a Markdown table cell.
+fn markdown_cell(cell: &str) -> String {
+    cell.replace('|', "\\|").replace('\n', " ")
+}
+
+/// Export comps in the given format. The comps are exported in the order given, so any filtering and sorting should
+/// be done first.
+pub fn export_comps(players: &[PlayerRecord], comps: &[Comp], format: ExportFormat, options: ExportOptions) -> String {
+    match format {
+        ExportFormat::Json => serde_json::to_string_pretty(&exported_comps(players, comps, options))
+            .expect("exported comps always serialize"),
+
+        ExportFormat::Csv => {
+            let (header, rows) = table(players, comps, options);
+
+            std::iter::once(header)
+                .chain(rows)
+                .map(|row| row.iter().map(|field| csv_field(field)).collect::<Vec<String>>().join(","))
+                .map(|line| line + "\r\n")
+                .collect()
+        }
+
+        ExportFormat::Markdown => {
+            let (header, rows) = table(players, comps, options);
+            let separator: Vec<String> = vec!["---".to_owned(); header.len()];
+
+            std::iter::once(header)
+                .chain(std::iter::once(separator))
+                .chain(rows)
+                .map(|row| {
+                    let cells: Vec<String> = row.iter().map(|cell| markdown_cell(cell)).collect();
+                    format!("| {} |\n", cells.join(" | "))
+                })
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{export_comps, exported_comps, ExportFormat, ExportOptions};
+    use crate::{
+        constants::{ChampId, Lane, SkinId, SkinsetId},
+        model::PlayerRecord,
+        solver::{Comp, Pick},
+    };
+
+    fn fixture() -> (Vec<PlayerRecord>, Vec<Comp>) {
+        let mut emma = PlayerRecord::new();
+        emma.name = Some("Emma, the Great".to_owned());
+
+        let comps = vec![Comp {
+            picks: vec![
+                Pick { champ: ChampId::from_champ_name("Ahri").unwrap(), lane: Some(Lane::Mid) },
+                Pick { champ: ChampId::from_champ_name("Evelynn").unwrap(), lane: Some(Lane::Jungle) },
+            ],
+            skinsets: vec![SkinsetId::from_skinset_name("K/DA").unwrap()],
+        }];
+
+        (vec![emma, PlayerRecord::new()], comps)
+    }
+
+    #[test]
+    fn csv_quotes_fields_with_commas() {
+        let (players, comps) = fixture();
+        let csv: String = export_comps(&players, &comps, ExportFormat::Csv, ExportOptions::default());
+
+        assert_eq!(
+            csv,
+            "\"Emma, the Great Champ\",\"Emma, the Great Lane\",Player 2 Champ,Player 2 Lane,Shared Skinsets\r\n\
+             Ahri,Mid,Evelynn,Jungle,K/DA\r\n"
+        );
+    }
+
+    #[test]
+    fn markdown_and_json_include_skin_names_when_asked() {
+        let (players, comps) = fixture();
+        let options = ExportOptions { include_skin_names: true, owned_only: false };
+
+        let markdown: String = export_comps(&players, &comps, ExportFormat::Markdown, options);
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[1], "| --- | --- | --- | --- | --- | --- | --- |");
+        assert!(lines[2].starts_with("| Ahri | Mid | K/DA ALL OUT Ahri; K/DA Ahri; Prestige K/DA Ahri;"));
+
+        let json: serde_json::Value = serde_json::from_str(&export_comps(&players, &comps, ExportFormat::Json, options)).unwrap();
+        assert_eq!(json[0]["picks"][1]["player"], "Player 2");
+        assert_eq!(json[0]["picks"][1]["skins"][1], "K/DA Evelynn");
+        assert_eq!(json[0]["skinsets"][0], "K/DA");
+    }
+    #[test]
+    fn owned_only_lists_only_owned_skins() {
+        let (mut players, comps) = fixture();
+        players[0].owned_skins.insert(SkinId::from_skin_name("K/DA Ahri").unwrap());
+        let options = ExportOptions { include_skin_names: true, owned_only: true };
+
+        let exported = exported_comps(&players, &comps, options);
+        assert_eq!(exported[0].picks[0].skins, Some(vec!["K/DA Ahri"]));
+        assert_eq!(exported[0].picks[1].skins, Some(Vec::new()));
+    }
+}
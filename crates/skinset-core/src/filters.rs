@@ -0,0 +1,164 @@
+//! Filtering and sorting of resolved comps, applied on top of the solver output so that changing them never needs a
+//! re-solve.
+
+use crate::{
+    constants::{ChampId, Lane, SkinsetId},
+    solver::Comp,
+};
+use std::cmp::Ordering;
+
+/// Filters on the resolved comps. Every filter that is set must match for a comp to be kept.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResultFilter {
+    /// Only keep comps where someone plays this champ.
+    pub champ: Option<ChampId>,
+
+    /// Only keep comps where the player at this index plays this lane.
+    pub player_lane: Option<(usize, Lane)>,
+
+    /// Only keep comps that share this skinset.
+    pub skinset: Option<SkinsetId>,
+
+    /// Only keep comps that share at least this many skinsets.
+    pub min_skinsets: usize,
+}
+
+impl ResultFilter {
+    /// Check whether a comp passes this filter.
+    pub fn matches(&self, comp: &Comp) -> bool {
+        self.champ.is_none_or(|champ_id| comp.picks.iter().any(|pick| pick.champ == champ_id))
+            && self.player_lane.is_none_or(|(player_index, lane)| {
+                comp.picks.get(player_index).is_some_and(|pick| pick.lane == Some(lane))
+            })
+            && self.skinset.is_none_or(|skinset_id| comp.skinsets.binary_search(&skinset_id).is_ok())
+            && comp.skinsets.len() >= self.min_skinsets
+    }
+}
+
+/// What to sort the resolved comps by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    /// The name of the champ played by the player at this index.
+    PlayerChamp(usize),
+
+    /// The lane played by the player at this index, in lane order (top to support).
+    PlayerLane(usize),
+
+    /// The number of skinsets the comp shares.
+    SkinsetCount,
+}
+
+/// How to sort the resolved comps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResultSort {
+    /// What to sort by.
+    pub key: SortKey,
+
+    /// Sort largest first instead of smallest first.
+    pub descending: bool,
+}
+
+impl ResultSort {
+    /// Compare two comps by this sort.
+    fn compare(&self, a: &Comp, b: &Comp) -> Ordering {
+        let ordering: Ordering = match self.key {
+            SortKey::PlayerChamp(player_index) => {
+                let champ_name = |comp: &Comp| comp.picks.get(player_index).map(|pick| pick.champ.champ_name());
+                champ_name(a).cmp(&champ_name(b))
+            }
+
+            SortKey::PlayerLane(player_index) => {
+                // Lanes are bitflags declared in lane order, so their bits sort top to support.
+                let lane_bits = |comp: &Comp| {
+                    comp.picks.get(player_index).and_then(|pick| pick.lane).map(|lane| lane as u8)
+                };
+
+                lane_bits(a).cmp(&lane_bits(b))
+            }
+
+            SortKey::SkinsetCount => a.skinsets.len().cmp(&b.skinsets.len()),
+        };
+
+        if self.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+}
+
+/// Filter and sort comps. Comps that compare equal keep their solver order.
+pub fn filter_and_sort(comps: &[Comp], filter: &ResultFilter, sort: Option<ResultSort>) -> Vec<Comp> {
+    let mut results: Vec<Comp> = comps.iter().filter(|comp| filter.matches(comp)).cloned().collect();
+
+    if let Some(sort) = sort {
+        results.sort_by(|a, b| sort.compare(a, b));
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{filter_and_sort, ResultFilter, ResultSort, SortKey};
+    use crate::{
+        constants::{ChampId, Lane, SkinsetId},
+        solver::{Comp, Pick},
+    };
+
+    fn comp(picks: &[(&str, Lane)], skinsets: &[&str]) -> Comp {
+        let mut skinsets: Vec<SkinsetId> =
+            skinsets.iter().map(|name| SkinsetId::from_skinset_name(name).unwrap()).collect();
+        skinsets.sort();
+
+        Comp {
+            picks: picks
+                .iter()
+                .map(|(champ, lane)| Pick { champ: ChampId::from_champ_name(champ).unwrap(), lane: Some(*lane) })
+                .collect(),
+            skinsets,
+        }
+    }
+
+    #[test]
+    fn filters_combine() {
+        let comps = vec![
+            comp(&[("Ahri", Lane::Mid), ("Evelynn", Lane::Jungle)], &["K/DA"]),
+            comp(&[("Ahri", Lane::Bot), ("Evelynn", Lane::Jungle)], &["K/DA"]),
+            comp(&[("Lux", Lane::Mid), ("Evelynn", Lane::Jungle)], &["Elementalist", "K/DA"]),
+        ];
+
+        let ahri_mid = ResultFilter {
+            champ: ChampId::from_champ_name("Ahri"),
+            player_lane: Some((0, Lane::Mid)),
+            ..ResultFilter::default()
+        };
+        assert_eq!(filter_and_sort(&comps, &ahri_mid, None), vec![comps[0].clone()]);
+
+        let two_skinsets = ResultFilter { min_skinsets: 2, ..ResultFilter::default() };
+        assert_eq!(filter_and_sort(&comps, &two_skinsets, None), vec![comps[2].clone()]);
+
+        let kda = ResultFilter { skinset: SkinsetId::from_skinset_name("K/DA"), ..ResultFilter::default() };
+        assert_eq!(filter_and_sort(&comps, &kda, None).len(), 3);
+    }
+
+    #[test]
+    fn sorts_are_stable() {
+        let comps = vec![
+            comp(&[("Lux", Lane::Mid)], &["Elementalist"]),
+            comp(&[("Ahri", Lane::Support)], &["K/DA"]),
+            comp(&[("Ahri", Lane::Mid)], &["K/DA", "Spirit Blossom"]),
+        ];
+
+        let by_champ = ResultSort { key: SortKey::PlayerChamp(0), descending: false };
+        let sorted = filter_and_sort(&comps, &ResultFilter::default(), Some(by_champ));
+        assert_eq!(sorted, vec![comps[1].clone(), comps[2].clone(), comps[0].clone()]);
+
+        let by_lane = ResultSort { key: SortKey::PlayerLane(0), descending: true };
+        let sorted = filter_and_sort(&comps, &ResultFilter::default(), Some(by_lane));
+        assert_eq!(sorted, vec![comps[1].clone(), comps[0].clone(), comps[2].clone()]);
+
+        let by_count = ResultSort { key: SortKey::SkinsetCount, descending: true };
+        assert_eq!(filter_and_sort(&comps, &ResultFilter::default(), Some(by_count))[0], comps[2]);
+    }
+}
@@ -0,0 +1,106 @@
+//! Deserializers for state the apps store themselves (the web app's local storage and saved presets), which skip any
+//! champ, skinset or skin the current data doesn't know instead of failing.
+//!
+//! Champs get renamed and skinsets get reorganized in data updates. Failing the whole record on one unknown name would
+//! throw away everything else the user had stored, so unknown names are dropped with a warning instead. Names are
+//! matched loosely, so that small renames (e.g. punctuation) still resolve.
+
+use crate::constants::{ChampId, Lane, SkinId, SkinsetId};
+use enumflags2::BitFlags;
+use serde::{Deserialize, Deserializer};
+use std::collections::HashSet;
+
+/// Look up a name, warning if it's unknown.
+fn known<T>(kind: &str, name: &str, lookup: impl Fn(&str) -> Option<T>) -> Option<T> {
+    let id: Option<T> = lookup(name);
+
+    if id.is_none() {
+        log::warn!("Skipping unknown {kind} {name:?} in stored state");
+    }
+
+    id
+}
+
+/// Deserialize a list of champs and their lanes, skipping unknown champs. Since names are matched loosely, two names
+/// can resolve to the same champ, in which case they're merged into one entry with the lanes of both.
+pub fn champ_list<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<(ChampId, BitFlags<Lane>)>, D::Error> {
+    let mut champs: Vec<(ChampId, BitFlags<Lane>)> = Vec::new();
+
+    for (name, lanes) in Vec::<(String, BitFlags<Lane>)>::deserialize(deserializer)? {
+        let Some(champ_id) = known("champion", &name, ChampId::from_name) else {
+            continue;
+        };
+
+        match champs.iter_mut().find(|(existing, _)| *existing == champ_id) {
+            Some((_, existing_lanes)) => *existing_lanes |= lanes,
+            None => champs.push((champ_id, lanes)),
+        }
+    }
+
+    Ok(champs)
+}
+
+/// Deserialize an optional champ, treating an unknown champ as no champ.
+pub fn optional_champ<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<ChampId>, D::Error> {
+    Ok(Option::<String>::deserialize(deserializer)?.and_then(|name| known("champion", &name, ChampId::from_name)))
+}
+
+/// Deserialize a set of skinsets, skipping unknown skinsets.
+pub fn skinset_set<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HashSet<SkinsetId>, D::Error> {
+    Ok(Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .filter_map(|name| known("skinset", name, SkinsetId::from_name))
+        .collect())
+}
+
+/// Deserialize a set of skins, skipping unknown skins.
+pub fn skin_set<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HashSet<SkinId>, D::Error> {
+    Ok(Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .filter_map(|name| known("skin", name, SkinId::from_skin_name))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        constants::{ChampId, Lane, SkinsetId},
+        model::PlayerRecord,
+        presets::UserPreset,
+    };
+    use enumflags2::make_bitflags;
+
+    /// Player records as the web app stores them, including names the current data doesn't have.
+    const STORED_PLAYERS_FIXTURE: &str = r#"[
+        {
+            "name": "Emma",
+            "champs": [["Ahri", 4], ["Not A Champion", 8], ["Kai'Sa", 8], ["kaisa", 16]],
+            "excluded_skinsets": ["K/DA", "Not A Skinset"],
+            "owned_skins": ["Spirit Blossom Ahri", "Not A Skin"],
+            "lock": {"champ": "Not A Champion", "lane": "Mid"}
+        },
+        {"name": null, "champs": [["Vel'Koz", 20], ["Cho'Gath", 17]]}
+    ]"#;
+
+    #[test]
+    fn unknown_names_are_skipped() {
+        let players: Vec<PlayerRecord> = serde_json::from_str(STORED_PLAYERS_FIXTURE).unwrap();
+
+        assert_eq!(players.len(), 2);
+        assert_eq!(players[0].name.as_deref(), Some("Emma"));
+        let champ_names: Vec<&str> = players[0].champs.iter().map(|(champ_id, _)| champ_id.champ_name()).collect();
+        assert_eq!(champ_names, ["Ahri", "Kai'Sa"]);
+        assert_eq!(players[0].champs[0].1, make_bitflags!(Lane::{Mid}));
+        // Both spellings of Kai'Sa are the same champ, so their lanes are merged.
+        assert_eq!(players[0].champs[1].1, make_bitflags!(Lane::{Bot | Support}));
+        assert_eq!(players[0].excluded_skinsets.len(), 1);
+        assert_eq!(players[0].owned_skins.len(), 1);
+        assert_eq!(players[0].lock.champ, None);
+        assert_eq!(players[0].lock.lane, Some(Lane::Mid));
+        let velkoz = ChampId::from_champ_name("Vel'Koz").unwrap();
+        assert_eq!(players[1].champs[0], (velkoz, make_bitflags!(Lane::{Mid | Support})));
+
+        let preset: UserPreset = serde_json::from_str(r#"{"name": "Mine", "skinsets": ["K/DA", "Gone"]}"#).unwrap();
+        assert_eq!(preset.skinsets.into_iter().collect::<Vec<_>>(), [SkinsetId::from_skinset_name("K/DA").unwrap()]);
+    }
+}
@@ -0,0 +1,28 @@
+//! The champ, skin and skinset data scraped at build time, and everything that works with it without a browser --
+//! the data model, the solver and the analyses built on it, and the roster and export formats.
+//!
+//! This is shared by the web app and the native tools, so nothing in here may depend on wasm-only crates.
+
+pub mod constants;
+pub mod coverage;
+pub mod diagnostics;
+pub mod draft;
+pub mod export;
+pub mod filters;
+pub mod lenient;
+pub mod model;
+pub mod names;
+pub mod presets;
+pub mod query;
+pub mod recommend;
+pub mod roller;
+pub mod roster;
+pub mod solver;
+
+#[cfg(test)]
+mod test_fixtures;
+
+/// Content generated by the build script.
+pub mod generated {
+    include!(concat!(env!("OUT_DIR"), "/generated.rs"));
+}
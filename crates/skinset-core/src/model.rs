@@ -0,0 +1,196 @@
+//! Frontend models. 
+
+use serde::{Serialize, Deserialize};
+use crate::{constants::{ChampId, Lane, SkinId, SkinsetId}, generated::LANE_DATA, lenient};
+use derive_more::Display;
+use enumflags2::BitFlags;
+use std::collections::HashSet;
+
+/// The most players a comp can have.
+pub const MAX_PLAYERS: usize = 5;
+
+/// The game mode being played, which determines whether lanes matter when building comps.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Display, Serialize, Deserialize)]
+pub enum GameMode {
+    /// Summoner's Rift -- every player must be in a different lane.
+    #[default]
+    #[display(fmt = "Summoner's Rift")]
+    SummonersRift,
+
+    /// ARAM -- there are no lanes, so only the champs matter.
+    #[display(fmt = "ARAM")]
+    Aram,
+}
+
+impl GameMode {
+    /// All the game modes, in the order they're shown in the UI.
+    pub const ALL: &'static [GameMode] = &[GameMode::SummonersRift, GameMode::Aram];
+}
+
+/// State persisted for each player in the frontend.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PlayerRecord {
+    /// Player name (optional -- resolve with player number otherwise).
+    pub name: Option<String>,
+
+    /// List of champs and what lanes for them. This is in the order that they're in in the UI. 
+    #[serde(deserialize_with = "lenient::champ_list")]
+    pub champs: Vec<(ChampId, BitFlags<Lane>)>,
+
+    /// Skinsets this player doesn't want to play, on top of the skinsets excluded for everyone. 
+    #[serde(default, deserialize_with = "lenient::skinset_set")]
+    pub excluded_skinsets: HashSet<SkinsetId>,
+
+    /// Skins this player owns. Only used when searching with [`SolverSettings::owned_only`].
+    #[serde(default, deserialize_with = "lenient::skin_set")]
+    pub owned_skins: HashSet<SkinId>,
+
+    /// The champ and/or lane this player has locked in, if any.
+    #[serde(default)]
+    pub lock: LockIn,
+}
+
+/// A champ and/or lane a player has already decided on. The solver treats these as hard constraints -- the player is
+/// only ever given picks that match.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LockIn {
+    /// The champ this player has locked in. This should be one of the player's champs.
+    #[serde(default, deserialize_with = "lenient::optional_champ")]
+    pub champ: Option<ChampId>,
+
+    /// The lane this player has locked in. Ignored in game modes without lanes.
+    pub lane: Option<Lane>,
+}
+
+impl LockIn {
+    /// Check whether nothing is locked.
+    pub const fn is_empty(&self) -> bool {
+        self.champ.is_none() && self.lane.is_none()
+    }
+
+    /// Check whether a champ is allowed by this lock.
+    pub fn allows_champ(&self, champ_id: ChampId) -> bool {
+        self.champ.is_none_or(|locked| locked == champ_id)
+    }
+
+    /// Check whether a lane is allowed by this lock.
+    pub fn allows_lane(&self, lane: Lane) -> bool {
+        self.lane.is_none_or(|locked| locked == lane)
+    }
+}
+
+/// Settings that change how comps are resolved, beyond the players and included skinsets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SolverSettings {
+    /// The game mode being played.
+    pub game_mode: GameMode,
+
+    /// Only accept a skinset for a player's champ if that player owns a skin for the champ in that skinset.
+    pub owned_only: bool,
+}
+
+impl Default for PlayerRecord {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PlayerRecord {
+    /// Create a new player with no names, and an empty champ list. 
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            champs: Vec::with_capacity(LANE_DATA.len()),
+            excluded_skinsets: HashSet::new(),
+            owned_skins: HashSet::new(),
+            lock: LockIn::default(),
+        }
+    }
+
+    /// Add a champ to the end of this player's list with its default lanes. If the champ is already in the list, do
+    /// nothing.
+    pub fn add_champ(&mut self, champ_id: ChampId) {
+        if !self.has_champ(champ_id) {
+            self.champs.push((champ_id, champ_id.default_lanes()));
+        }
+    }
+
+    /// Replace a champ on this player with another, in the same position and with the new champ's default lanes.
+    /// A champ lock on the old champ moves to the new one. If the old champ is not on this player, or the new one
+    /// already is, do nothing. 
+    pub fn replace_champ(&mut self, old_champ_id: ChampId, new_champ_id: ChampId) {
+        if self.has_champ(new_champ_id) {
+            return;
+        }
+
+        if let Some(entry) = self.champs.iter_mut().find(|(iter_champ_id, _)| *iter_champ_id == old_champ_id) {
+            *entry = (new_champ_id, new_champ_id.default_lanes());
+
+            if self.lock.champ == Some(old_champ_id) {
+                self.lock.champ = Some(new_champ_id);
+            }
+        }
+    }
+
+    /// Get the name to show for this player -- their name if they have one, or "Player N" from their index otherwise.
+    pub fn display_name(&self, player_index: usize) -> String {
+        self.name.clone().unwrap_or_else(|| format!("Player {}", player_index + 1))
+    }
+
+    /// Check if a champ is in this player's list of champs.
+    pub fn has_champ(&self, champ_id: ChampId) -> bool {
+        self.champs.iter().any(|(iter_champ_id, _)| *iter_champ_id == champ_id)
+    }
+
+    /// Check whether this player will play a champ in a given skinset -- i.e. they have not excluded the skinset, and
+    /// if `owned_only` is set, they own one of the champ's skins in it. This does not check whether the champ is in
+    /// the skinset at all. 
+    pub fn accepts_skinset(&self, champ_id: ChampId, skinset_id: SkinsetId, owned_only: bool) -> bool {
+        !self.excluded_skinsets.contains(&skinset_id)
+            && (!owned_only || champ_id.skins_in(skinset_id).any(|skin_id| self.can_use_skin(skin_id, owned_only)))
+    }
+
+    /// Check whether this player can use a skin -- always, unless `owned_only` is set and they don't own it.
+    pub fn can_use_skin(&self, skin_id: SkinId, owned_only: bool) -> bool {
+        !owned_only || self.owned_skins.contains(&skin_id)
+    }
+
+    /// Update the lanes for a champ for this player if that champ is in this players list of champs.
+    /// Return `true` if the champ was updated and `false` if that champ is not selected on this player record.
+    pub fn update_champ(&mut self, champ_id: ChampId, lanes: BitFlags<Lane>) -> bool {
+        self.champs
+            .iter_mut()
+            .find(|(iter_champ_id, _)| *iter_champ_id == champ_id)
+            .map(|(_, lanes_mut)| *lanes_mut = lanes)
+            .is_some()
+    }
+
+    /// Lock this player into a pick, adding the champ (with just the picked lane) if they don't have it yet.
+    pub fn lock_pick(&mut self, champ_id: ChampId, lane: Option<Lane>) {
+        if !self.has_champ(champ_id) {
+            self.champs.push((champ_id, lane.map(BitFlags::from).unwrap_or_else(|| champ_id.default_lanes())));
+        }
+
+        self.lock = LockIn { champ: Some(champ_id), lane };
+    }
+
+    /// Remove a champ on this player, and any champ lock on it. If that champ is not in the list of
+    /// [`PlayerRecord::champs`], do nothing. 
+    pub fn remove_champ(&mut self, champ_id: ChampId) {
+        if self.lock.champ == Some(champ_id) {
+            self.lock.champ = None;
+        }
+
+        // Remove the champ if they already were in the list. 
+        let remove_index = self.champs
+            .iter()
+            .enumerate()
+            .find(|(_, (iter_champ_id, _))| *iter_champ_id == champ_id)
+            .map(|(index, _)| index);
+        
+        if let Some(index) = remove_index {
+            // Just use remove here to maintain order.
+            self.champs.remove(index);
+        }
+    }
+}
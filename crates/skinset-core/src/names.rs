@@ -0,0 +1,62 @@
+//! Loose name matching for champs and skinsets typed by people, who rarely get the case and punctuation right.
+//!
+//! The build script includes this file too (to key the generated lookup tables), so it may only use `std`.
+
+/// Normalize a name for lookups -- lower case, with everything but letters and digits removed. "Kai'Sa", "kaisa" and
+/// "KAI SA" all normalize to "kaisa".
+pub fn normalize_name(name: &str) -> String {
+    name.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
+
+/// The Levenshtein edit distance between two strings, counted in chars.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current: Vec<usize> = Vec::with_capacity(b.len() + 1);
+        current.push(i + 1);
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution: usize = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// The `n` candidates whose names are closest to `name` by edit distance between normalized names, closest first.
+/// Candidates too far off to be a likely typo (more than a third of the name's length away) are left out.
+pub fn closest<'a, T: Copy + Ord>(name: &str, candidates: impl Iterator<Item = (T, &'a str)>, n: usize) -> Vec<T> {
+    let name: String = normalize_name(name);
+    let max_distance: usize = name.chars().count() / 3 + 1;
+
+    let mut scored: Vec<(usize, T)> = candidates
+        .map(|(candidate, candidate_name)| (edit_distance(&name, &normalize_name(candidate_name)), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+
+    scored.sort();
+    scored.dedup();
+    scored.into_iter().take(n).map(|(_, candidate)| candidate).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{closest, edit_distance, normalize_name};
+
+    #[test]
+    fn names_are_compared_loosely() {
+        assert_eq!(normalize_name("Kai'Sa"), "kaisa");
+        assert_eq!(normalize_name("K/DA ALL OUT"), "kdaallout");
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+
+        let names = ["Jinx", "Jax", "Janna", "Zyra"];
+        assert_eq!(closest("jnx", names.iter().map(|name| (*name, *name)), 2), ["Jax", "Jinx"]);
+        assert!(closest("qqqqqq", names.iter().map(|name| (*name, *name)), 2).is_empty());
+    }
+}
@@ -0,0 +1,150 @@
+//! Named skinset selections ("presets") that can be applied to the skinset list, either replacing the current
+//! selection or combined with it.
+
+use crate::{constants::SkinsetId, lenient};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// The fewest champs a skinset needs for the [`BuiltinPreset::FivePlusChamps`] preset -- enough for a full team.
+const FULL_TEAM_SIZE: usize = 5;
+
+/// The presets that are always available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+pub enum BuiltinPreset {
+    /// Every skinset except the ones grouped by release rather than theme. See [`SkinsetId::NON_COHESIVE_SKINSETS`].
+    #[display(fmt = "Cohesive themes only")]
+    CohesiveThemesOnly,
+
+    /// Every skinset except the defaults excluded. See [`SkinsetId::DEFAULT_EXCLUDED_SKINSETS`].
+    #[display(fmt = "Exclude Legacy and N/A")]
+    ExcludeLegacyAndNa,
+
+    /// Only the skinsets with enough champs for a full team.
+    #[display(fmt = "Only sets with 5+ champs")]
+    FivePlusChamps,
+}
+
+impl BuiltinPreset {
+    /// All the built-in presets, in the order they're listed in the UI.
+    pub const ALL: [Self; 3] = [Self::CohesiveThemesOnly, Self::ExcludeLegacyAndNa, Self::FivePlusChamps];
+
+    /// Get the skinsets selected by this preset.
+    pub fn skinsets(self) -> HashSet<SkinsetId> {
+        match self {
+            BuiltinPreset::CohesiveThemesOnly => SkinsetId::iter_all()
+                .filter(|skinset_id| !SkinsetId::NON_COHESIVE_SKINSETS.contains(skinset_id))
+                .collect(),
+
+            BuiltinPreset::ExcludeLegacyAndNa => SkinsetId::generate_default_included_skinsets(),
+
+            BuiltinPreset::FivePlusChamps => SkinsetId::iter_all()
+                .filter(|skinset_id| skinset_id.champ_count() >= FULL_TEAM_SIZE)
+                .collect(),
+        }
+    }
+}
+
+/// A preset saved by the user.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserPreset {
+    /// The name the user gave this preset.
+    pub name: String,
+
+    /// The skinsets selected by this preset.
+    #[serde(deserialize_with = "lenient::skinset_set")]
+    pub skinsets: HashSet<SkinsetId>,
+}
+
+/// How to combine a preset with the current skinset selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+pub enum PresetCombine {
+    /// Select exactly the preset's skinsets.
+    #[display(fmt = "Apply")]
+    Replace,
+
+    /// Also select the preset's skinsets.
+    #[display(fmt = "Union")]
+    Union,
+
+    /// Only keep the selected skinsets that are also in the preset.
+    #[display(fmt = "Intersect")]
+    Intersect,
+}
+
+impl PresetCombine {
+    /// All the ways to combine presets, in the order they're listed in the UI.
+    pub const ALL: [Self; 3] = [Self::Replace, Self::Union, Self::Intersect];
+
+    /// Combine the current selection with a preset's skinsets.
+    pub fn apply(self, current: &HashSet<SkinsetId>, preset: &HashSet<SkinsetId>) -> HashSet<SkinsetId> {
+        match self {
+            PresetCombine::Replace => preset.clone(),
+            PresetCombine::Union => current.union(preset).copied().collect(),
+            PresetCombine::Intersect => current.intersection(preset).copied().collect(),
+        }
+    }
+}
+
+/// Save the current selection as a user preset, replacing any preset with the same name (ignoring surrounding
+/// whitespace). Returns `false` without saving if the name is blank.
+pub fn save_user_preset(presets: &mut Vec<UserPreset>, name: &str, skinsets: HashSet<SkinsetId>) -> bool {
+    let name: &str = name.trim();
+
+    if name.is_empty() {
+        return false;
+    }
+
+    match presets.iter_mut().find(|preset| preset.name == name) {
+        Some(preset) => preset.skinsets = skinsets,
+        None => presets.push(UserPreset { name: name.to_owned(), skinsets }),
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{save_user_preset, BuiltinPreset, PresetCombine, UserPreset};
+    use crate::constants::SkinsetId;
+    use std::collections::HashSet;
+
+    fn skinsets(names: &[&str]) -> HashSet<SkinsetId> {
+        names.iter().map(|name| SkinsetId::from_skinset_name(name).unwrap()).collect()
+    }
+
+    #[test]
+    fn builtin_presets_select_the_expected_skinsets() {
+        let cohesive = BuiltinPreset::CohesiveThemesOnly.skinsets();
+        assert!(cohesive.is_superset(&skinsets(&["K/DA", "Spirit Blossom"])));
+        assert!(cohesive.is_disjoint(&skinsets(&["Legacy", "N/A", "Victorious"])));
+
+        let five_plus = BuiltinPreset::FivePlusChamps.skinsets();
+        assert!(five_plus.contains(&SkinsetId::from_skinset_name("K/DA").unwrap()));
+        assert!(!five_plus.contains(&SkinsetId::from_skinset_name("Beta").unwrap()));
+    }
+
+    #[test]
+    fn presets_combine_with_the_current_selection() {
+        let current = skinsets(&["K/DA", "Spirit Blossom"]);
+        let preset = skinsets(&["K/DA", "Star Guardian Season 1"]);
+
+        assert_eq!(PresetCombine::Replace.apply(&current, &preset), preset);
+        assert_eq!(
+            PresetCombine::Union.apply(&current, &preset),
+            skinsets(&["K/DA", "Spirit Blossom", "Star Guardian Season 1"])
+        );
+        assert_eq!(PresetCombine::Intersect.apply(&current, &preset), skinsets(&["K/DA"]));
+    }
+
+    #[test]
+    fn saving_a_preset_overwrites_one_with_the_same_name() {
+        let mut presets: Vec<UserPreset> = Vec::new();
+
+        assert!(!save_user_preset(&mut presets, "  ", skinsets(&["K/DA"])));
+        assert!(save_user_preset(&mut presets, "Strict", skinsets(&["K/DA"])));
+        assert!(save_user_preset(&mut presets, " Strict ", skinsets(&["Spirit Blossom"])));
+
+        assert_eq!(presets, vec![UserPreset { name: "Strict".to_owned(), skinsets: skinsets(&["Spirit Blossom"]) }]);
+    }
+}
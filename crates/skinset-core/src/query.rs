@@ -0,0 +1,409 @@
+//! A small text query language for comp searches, parsed into constraints on top of the roster.
+//!
+//! A query is a list of whitespace separated `key:value` terms. Values with spaces are quoted.
+//!
+//! ```text
+//! skinset:"Star Guardian" Venus:support champ:Lux -skinset:Legacy lanes:any
+//! ```
+//!
+//! - `skinset:NAME` -- only search this skinset, or every skinset in this universe. Can be given more than once.
+//! - `-skinset:NAME` -- never search this skinset (or universe).
+//! - `champ:NAME` -- only keep comps where someone plays this champ.
+//! - `-champ:NAME` -- never pick this champ.
+//! - `lanes:any` / `lanes:unique` -- ignore lanes (as in ARAM), or give every player a different lane.
+//! - `PLAYER:LANE` / `PLAYER:CHAMP` -- lock a player (by name, e.g. `Venus` or `"Player 2"`) into a lane or champ.
+//!
+//! Names are matched ignoring case, spaces and punctuation. Unknown names are reported with the span of the offending
+//! text and the closest known names.
+
+use crate::{
+    constants::{ChampId, Lane, SkinsetId, UniverseId},
+    model::{GameMode, LockIn, PlayerRecord, SolverSettings},
+    names,
+    solver::{self, Comp},
+};
+use derive_more::Display;
+use enumflags2::BitFlags;
+use std::{
+    collections::{BTreeMap, HashSet},
+    ops::Range,
+};
+
+/// The most suggestions given for an unknown name.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// The keys that aren't player names.
+const KEYWORDS: &[&str] = &["skinset", "champ", "lanes"];
+
+/// An error in a query, with the byte range of the text it is about.
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+#[display(fmt = "{} (at {}..{})", message, "span.start", "span.end")]
+pub struct QueryError {
+    /// The byte range in the query of the text with the error.
+    pub span: Range<usize>,
+
+    /// A description of the error.
+    pub message: String,
+
+    /// The closest known names, if the error is about an unknown name.
+    pub suggestions: Vec<String>,
+}
+
+impl QueryError {
+    /// Create an error without suggestions.
+    fn new(span: Range<usize>, message: impl Into<String>) -> Self {
+        Self { span, message: message.into(), suggestions: Vec::new() }
+    }
+
+    /// Add suggestions to this error.
+    fn with_suggestions(mut self, suggestions: Vec<String>) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
+
+    /// Render a line of carets under the span of this error, for printing under the query in a terminal.
+    pub fn caret_line(&self, query: &str) -> String {
+        let start: usize = query[..self.span.start].chars().count();
+        let width: usize = query[self.span.clone()].chars().count().max(1);
+        format!("{}{}", " ".repeat(start), "^".repeat(width))
+    }
+}
+
+/// A parsed query.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Query {
+    /// The only skinsets to search. Empty means the skinsets are left as they are.
+    pub include_skinsets: HashSet<SkinsetId>,
+
+    /// Skinsets never to search.
+    pub exclude_skinsets: HashSet<SkinsetId>,
+
+    /// Champs someone must play in every comp.
+    pub required_champs: Vec<ChampId>,
+
+    /// Champs never to pick.
+    pub excluded_champs: HashSet<ChampId>,
+
+    /// Locks by player index. These take the place of the matching parts of the players' own locks.
+    pub locks: BTreeMap<usize, LockIn>,
+
+    /// The game mode to search in, if the query sets one.
+    pub game_mode: Option<GameMode>,
+}
+
+/// A `key:value` term of a query, before its names are resolved.
+#[derive(Debug)]
+struct Term {
+    /// Whether the term started with a `-`.
+    negated: bool,
+
+    /// The key, unquoted.
+    key: String,
+
+    /// The span of the key.
+    key_span: Range<usize>,
+
+    /// The value, unquoted, and its span. [`None`] if there was no `:`.
+    value: Option<(String, Range<usize>)>,
+}
+
+/// Split a query into terms, respecting quotes.
+fn tokenize(input: &str) -> Result<Vec<Term>, QueryError> {
+    let mut terms: Vec<Term> = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let negated: bool = c == '-';
+        if negated {
+            chars.next();
+        }
+
+        let key_start: usize = if negated { start + 1 } else { start };
+        let mut key = String::new();
+        let mut key_end: usize = key_start;
+        let mut value: Option<(String, usize)> = None;
+        let mut quote_start: Option<usize> = None;
+        let mut end: usize = key_start;
+
+        while let Some(&(index, c)) = chars.peek() {
+            if quote_start.is_none() && c.is_whitespace() {
+                break;
+            }
+
+            chars.next();
+            end = index + c.len_utf8();
+
+            match c {
+                '"' if quote_start.is_some() => quote_start = None,
+                '"' => quote_start = Some(index),
+                ':' if quote_start.is_none() && value.is_none() => {
+                    key_end = index;
+                    value = Some((String::new(), end));
+                }
+                c => match &mut value {
+                    Some((value, _)) => value.push(c),
+                    None => key.push(c),
+                },
+            }
+        }
+
+        if let Some(quote_start) = quote_start {
+            return Err(QueryError::new(quote_start..input.len(), "unterminated quote"));
+        }
+
+        terms.push(match value {
+            Some((value, value_start)) => {
+                Term { negated, key, key_span: key_start..key_end, value: Some((value, value_start..end)) }
+            }
+            None => Term { negated, key, key_span: key_start..end, value: None },
+        });
+    }
+
+    Ok(terms)
+}
+
+/// The candidates closest to a name, for suggestions in errors.
+fn closest<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Vec<String> {
+    names::closest(name, candidates.map(|candidate| (candidate, candidate)), MAX_SUGGESTIONS)
+        .into_iter()
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Find a lane by name, ignoring case.
+fn find_lane(name: &str) -> Option<Lane> {
+    BitFlags::<Lane>::all().iter().find(|lane| lane.to_string().eq_ignore_ascii_case(name))
+}
+
+/// Find the skinsets a name refers to -- a single skinset, or every skinset in a universe.
+fn find_skinsets(name: &str) -> Option<Vec<SkinsetId>> {
+    if let Some(skinset_id) = SkinsetId::from_name(name) {
+        return Some(vec![skinset_id]);
+    }
+
+    let name: String = names::normalize_name(name);
+    UniverseId::iter_all()
+        .find(|universe_id| names::normalize_name(universe_id.universe_name()) == name)
+        .map(|universe_id| universe_id.skinsets().collect())
+}
+
+impl Query {
+    /// Parse a query. Player names are resolved against the given players.
+    pub fn parse(input: &str, players: &[PlayerRecord]) -> Result<Self, QueryError> {
+        let mut query = Self::default();
+        let player_names: Vec<String> =
+            players.iter().enumerate().map(|(index, player)| player.display_name(index)).collect();
+
+        for term in tokenize(input)? {
+            let Some((value, value_span)) = term.value else {
+                return Err(QueryError::new(term.key_span, "expected a `key:value` term, like `champ:Lux`"));
+            };
+
+            if value.is_empty() {
+                return Err(QueryError::new(value_span, format!("missing a value after `{}:`", term.key)));
+            }
+
+            match term.key.to_lowercase().as_str() {
+                "skinset" => {
+                    let skinsets: Vec<SkinsetId> = find_skinsets(&value).ok_or_else(|| {
+                        let names = SkinsetId::iter_all()
+                            .map(SkinsetId::skinset_name)
+                            .chain(UniverseId::iter_all().map(UniverseId::universe_name));
+                        QueryError::new(value_span.clone(), format!("unknown skinset {value:?}"))
+                            .with_suggestions(closest(&value, names))
+                    })?;
+
+                    let target = if term.negated { &mut query.exclude_skinsets } else { &mut query.include_skinsets };
+                    target.extend(skinsets);
+                }
+
+                "champ" => {
+                    let champ_id: ChampId = ChampId::from_name(&value).ok_or_else(|| {
+                        let suggestions = ChampId::suggest(&value, MAX_SUGGESTIONS).into_iter();
+                        let suggestions = suggestions.map(|champ_id| champ_id.champ_name().to_owned());
+                        QueryError::new(value_span.clone(), format!("unknown champ {value:?}"))
+                            .with_suggestions(suggestions.collect())
+                    })?;
+
+                    if term.negated {
+                        query.excluded_champs.insert(champ_id);
+                    } else {
+                        query.required_champs.push(champ_id);
+                    }
+                }
+
+                "lanes" if term.negated => return Err(QueryError::new(term.key_span, "`lanes:` can't be negated")),
+
+                "lanes" => {
+                    query.game_mode = Some(match value.to_lowercase().as_str() {
+                        "any" => GameMode::Aram,
+                        "unique" => GameMode::SummonersRift,
+                        _ => {
+                            return Err(QueryError::new(value_span, "expected `lanes:any` or `lanes:unique`")
+                                .with_suggestions(closest(&value, ["any", "unique"].into_iter())))
+                        }
+                    });
+                }
+
+                _ => {
+                    let player_index: usize = player_names
+                        .iter()
+                        .position(|name| name.eq_ignore_ascii_case(&term.key))
+                        .ok_or_else(|| {
+                            let names = player_names.iter().map(String::as_str).chain(KEYWORDS.iter().copied());
+                            QueryError::new(term.key_span.clone(), format!("unknown player or key {:?}", term.key))
+                                .with_suggestions(closest(&term.key, names))
+                        })?;
+
+                    if term.negated {
+                        return Err(QueryError::new(term.key_span, "player locks can't be negated"));
+                    }
+
+                    let lock: &mut LockIn = query.locks.entry(player_index).or_default();
+                    let player: &PlayerRecord = &players[player_index];
+
+                    if let Some(lane) = find_lane(&value) {
+                        lock.lane = Some(lane);
+                    } else if let Some(champ_id) = ChampId::from_name(&value).filter(|id| player.has_champ(*id)) {
+                        lock.champ = Some(champ_id);
+                    } else {
+                        let pool_names = player.champs.iter().map(|(champ_id, _)| champ_id.champ_name());
+                        let lane_names: Vec<String> = BitFlags::<Lane>::all().iter().map(|l| l.to_string()).collect();
+                        let message: String = match ChampId::from_name(&value) {
+                            Some(champ_id) => {
+                                format!("{} doesn't play {}", player_names[player_index], champ_id.champ_name())
+                            }
+                            None => format!("unknown lane or champ {value:?}"),
+                        };
+
+                        let names = pool_names.chain(lane_names.iter().map(String::as_str));
+                        return Err(QueryError::new(value_span, message).with_suggestions(closest(&value, names)));
+                    }
+                }
+            }
+        }
+
+        Ok(query)
+    }
+
+    /// Check whether this query has no terms.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Apply the query's constraints to the solver's inputs, returning the players, skinsets and settings to solve
+    /// with. Required champs can only be checked on the comps themselves -- see [`Query::matches`].
+    pub fn apply(
+        &self,
+        players: &[PlayerRecord],
+        included_skinsets: &HashSet<SkinsetId>,
+        settings: SolverSettings,
+    ) -> (Vec<PlayerRecord>, HashSet<SkinsetId>, SolverSettings) {
+        let mut players: Vec<PlayerRecord> = players.to_vec();
+
+        for player in players.iter_mut() {
+            self.excluded_champs.iter().for_each(|champ_id| player.remove_champ(*champ_id));
+        }
+
+        for (player_index, lock) in &self.locks {
+            if let Some(player) = players.get_mut(*player_index) {
+                player.lock.champ = lock.champ.or(player.lock.champ);
+                player.lock.lane = lock.lane.or(player.lock.lane);
+            }
+        }
+
+        let mut skinsets: HashSet<SkinsetId> =
+            if self.include_skinsets.is_empty() { included_skinsets.clone() } else { self.include_skinsets.clone() };
+        skinsets.retain(|skinset_id| !self.exclude_skinsets.contains(skinset_id));
+
+        let settings = SolverSettings { game_mode: self.game_mode.unwrap_or(settings.game_mode), ..settings };
+
+        (players, skinsets, settings)
+    }
+
+    /// Check whether a comp has every required champ.
+    pub fn matches(&self, comp: &Comp) -> bool {
+        self.required_champs.iter().all(|champ_id| comp.picks.iter().any(|pick| pick.champ == *champ_id))
+    }
+
+    /// Resolve the comps matching this query.
+    pub fn solve(
+        &self,
+        players: &[PlayerRecord],
+        included_skinsets: &HashSet<SkinsetId>,
+        settings: SolverSettings,
+    ) -> Vec<Comp> {
+        self.solve_limited(players, included_skinsets, settings, None)
+    }
+
+    /// Resolve the comps matching this query, stopping as soon as `limit` have been found. See
+    /// [`solver::solve_limited`].
+    pub fn solve_limited(
+        &self,
+        players: &[PlayerRecord],
+        included_skinsets: &HashSet<SkinsetId>,
+        settings: SolverSettings,
+        limit: Option<usize>,
+    ) -> Vec<Comp> {
+        let (players, skinsets, settings) = self.apply(players, included_skinsets, settings);
+        solver::solve_limited(&players, &skinsets, settings, limit, |comp| self.matches(comp))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Query;
+    use crate::{
+        constants::{ChampId, Lane, SkinsetId},
+        model::{GameMode, PlayerRecord},
+    };
+
+    fn players() -> Vec<PlayerRecord> {
+        let mut venus = PlayerRecord::new();
+        venus.name = Some("Venus".to_owned());
+        venus.add_champ(ChampId::from_champ_name("Lux").unwrap());
+        venus.add_champ(ChampId::from_champ_name("Janna").unwrap());
+
+        let mut other = PlayerRecord::new();
+        other.add_champ(ChampId::from_champ_name("Ahri").unwrap());
+        vec![venus, other]
+    }
+
+    #[test]
+    fn parses_the_example_query() {
+        let input = r#"skinset:"Star Guardian" Venus:support champ:Lux -skinset:Legacy lanes:any "player 2":mid"#;
+        let query = Query::parse(input, &players()).unwrap();
+
+        assert!(query.include_skinsets.contains(&SkinsetId::from_skinset_name("Star Guardian Season 1").unwrap()));
+        assert!(query.exclude_skinsets.contains(&SkinsetId::from_skinset_name("Legacy").unwrap()));
+        assert_eq!(query.required_champs, vec![ChampId::from_champ_name("Lux").unwrap()]);
+        assert_eq!(query.locks[&0].lane, Some(Lane::Support));
+        assert_eq!(query.locks[&1].lane, Some(Lane::Mid));
+        assert_eq!(query.game_mode, Some(GameMode::Aram));
+        assert!(Query::parse("  ", &players()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn errors_point_at_the_bad_text() {
+        let input = "champ:Lux skinset:Leggacy";
+        let err = Query::parse(input, &players()).unwrap_err();
+        assert_eq!(&input[err.span.clone()], "Leggacy");
+        assert_eq!(err.suggestions.first().map(String::as_str), Some("Legacy"));
+        assert_eq!(err.caret_line(input), "                  ^^^^^^^");
+
+        let err = Query::parse("Venus:Ahri", &players()).unwrap_err();
+        assert_eq!(err.span, 6..10);
+        assert_eq!(err.message, "Venus doesn't play Ahri");
+
+        let err = Query::parse("Venis:mid", &players()).unwrap_err();
+        assert_eq!(err.span, 0..5);
+        assert_eq!(err.suggestions.first().map(String::as_str), Some("Venus"));
+
+        assert_eq!(Query::parse(r#"champ:"Lux"#, &players()).unwrap_err().span, 6..10);
+    }
+}
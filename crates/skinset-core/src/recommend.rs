@@ -0,0 +1,210 @@
+//! Recommendations for which champ a player should add to their pool next, ranked by how much the group gains.
+
+use crate::{
+    constants::{ChampId, Lane, SkinsetId},
+    model::{PlayerRecord, SolverSettings},
+    solver::{self, Comp},
+};
+use derive_more::Display;
+use enumflags2::BitFlags;
+use std::{cmp::Reverse, collections::HashSet};
+
+/// The most recommendations listed for a player.
+pub const MAX_RECOMMENDATIONS: usize = 10;
+
+/// The most comps of the other players that are resolved to build recommendations on. Large rosters can have far
+/// more, and resolving them all would take too long to do on every edit.
+pub const MAX_OTHER_COMPS: usize = 10_000;
+
+/// What recommendations are ranked by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Display)]
+pub enum RecommendBy {
+    /// The number of included skinsets that become achievable.
+    #[default]
+    #[display(fmt = "Most skinsets")]
+    Skinsets,
+
+    /// The number of new comps.
+    #[display(fmt = "Most comps")]
+    Comps,
+}
+
+impl RecommendBy {
+    /// All the rankings, in the order they're listed in the UI.
+    pub const ALL: [Self; 2] = [Self::Skinsets, Self::Comps];
+}
+
+/// A champ a player could add to their pool, and what the group would gain from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recommendation {
+    /// The champ to add.
+    pub champ: ChampId,
+
+    /// The lanes the champ would be added with -- its default lanes.
+    pub lanes: BitFlags<Lane>,
+
+    /// The included skinsets that would become achievable, sorted.
+    pub unlocked_skinsets: Vec<SkinsetId>,
+
+    /// The number of comps that would be added.
+    pub new_comps: usize,
+}
+
+/// The recommendations for a player, best first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RankedRecommendations {
+    /// The recommendations, at most [`MAX_RECOMMENDATIONS`].
+    pub recommendations: Vec<Recommendation>,
+
+    /// Whether the other players had more than [`MAX_OTHER_COMPS`] comps, so only some were considered. The gains
+    /// are then lower bounds, and a skinset listed as unlocked may already have been achievable.
+    pub approximate: bool,
+}
+
+/// Rank the champs a player could add to their pool by what the group would gain, best first, up to
+/// [`MAX_RECOMMENDATIONS`]. Champs that would add nothing are left out.
+///
+/// Only included skinsets are considered, and the player's exclusions, owned skins and lane lock-in still apply. A
+/// champ lock-in doesn't, since learning a new champ means playing it instead.
+pub fn recommend_champs(
+    players: &[PlayerRecord],
+    player_index: usize,
+    included_skinsets: &HashSet<SkinsetId>,
+    settings: SolverSettings,
+    rank_by: RecommendBy,
+) -> RankedRecommendations {
+    let Some(player) = players.get(player_index) else {
+        return RankedRecommendations::default();
+    };
+
+    // A champ can only help in a skinset where every other player already has a champ.
+    let others: Vec<PlayerRecord> = players
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| *index != player_index)
+        .map(|(_, other)| other.clone())
+        .collect();
+
+    let candidate_skinsets: HashSet<SkinsetId> = included_skinsets
+        .iter()
+        .copied()
+        .filter(|skinset_id| solver::players_with_champ_in(&others, *skinset_id) == others.len())
+        .collect();
+
+    // Solve the other players once. Every comp with the player is one of these plus a pick for the player, so this is
+    // all that's needed to check both what the player's pool achieves now and what each champ would add. With nobody
+    // else, there's just the one empty comp. Solve for one more than the cap, just to tell whether there were more.
+    let mut others_comps: Vec<Comp> = if others.is_empty() {
+        let mut skinsets: Vec<SkinsetId> = candidate_skinsets.iter().copied().collect();
+        skinsets.sort();
+        vec![Comp { picks: Vec::new(), skinsets }]
+    } else {
+        solver::solve_limited(&others, &candidate_skinsets, settings, Some(MAX_OTHER_COMPS + 1), |_| true)
+    };
+
+    let approximate: bool = others_comps.len() > MAX_OTHER_COMPS;
+    others_comps.truncate(MAX_OTHER_COMPS);
+
+    let mut achievable: HashSet<SkinsetId> = HashSet::new();
+    solver::join_comps(&others_comps, player, &candidate_skinsets, settings, |shared| achievable.extend(shared));
+
+    let mut recommendations: Vec<Recommendation> = ChampId::iter_all()
+        .filter(|champ_id| !player.has_champ(*champ_id))
+        .filter(|champ_id| champ_id.skinsets().any(|skinset_id| candidate_skinsets.contains(&skinset_id)))
+        .filter_map(|champ_id| {
+            let lanes: BitFlags<Lane> = champ_id.default_lanes();
+
+            // The player with only the new champ, since every new comp has them on it. Any champ they're locked into
+            // is dropped, or they could never be given the new one.
+            let mut on_champ: PlayerRecord = player.clone();
+            on_champ.champs = vec![(champ_id, lanes)];
+            on_champ.lock.champ = None;
+
+            let mut unlocked_skinsets: Vec<SkinsetId> = Vec::new();
+            let mut new_comps: usize = 0;
+
+            solver::join_comps(&others_comps, &on_champ, &candidate_skinsets, settings, |shared| {
+                new_comps += 1;
+                unlocked_skinsets.extend(shared.iter().filter(|skinset_id| !achievable.contains(skinset_id)));
+            });
+
+            unlocked_skinsets.sort();
+            unlocked_skinsets.dedup();
+
+            (!unlocked_skinsets.is_empty() || new_comps > 0).then_some(Recommendation {
+                champ: champ_id,
+                lanes,
+                unlocked_skinsets,
+                new_comps,
+            })
+        })
+        .collect();
+
+    // Rank by the chosen gain, then the other, then by name so the order is stable.
+    recommendations.sort_by_key(|recommendation| {
+        let skinsets: usize = recommendation.unlocked_skinsets.len();
+        let gains = match rank_by {
+            RecommendBy::Skinsets => (skinsets, recommendation.new_comps),
+            RecommendBy::Comps => (recommendation.new_comps, skinsets),
+        };
+
+        (Reverse(gains), recommendation.champ.champ_name())
+    });
+
+    recommendations.truncate(MAX_RECOMMENDATIONS);
+    RankedRecommendations { recommendations, approximate }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{recommend_champs, RecommendBy, MAX_RECOMMENDATIONS};
+    use crate::{
+        constants::SkinsetId,
+        model::SolverSettings,
+        solver,
+        test_fixtures::{champ, player, skinset},
+    };
+    use std::collections::HashSet;
+
+    #[test]
+    fn recommendations_unlock_skinsets_with_the_group() {
+        let players = [player(&["Ahri"]), player(&["Garen"])];
+        let included: HashSet<SkinsetId> = SkinsetId::iter_all().collect();
+        let kda = skinset("K/DA");
+
+        let settings = SolverSettings::default();
+        let ranked = recommend_champs(&players, 1, &included, settings, RecommendBy::Skinsets);
+        assert!(!ranked.approximate);
+        let recommendations = ranked.recommendations;
+        assert!(!recommendations.is_empty() && recommendations.len() <= MAX_RECOMMENDATIONS);
+        assert!(recommendations.iter().all(|recommendation| !players[1].has_champ(recommendation.champ)));
+
+        // Ranked best first.
+        let gains: Vec<usize> = recommendations.iter().map(|r| r.unlocked_skinsets.len()).collect();
+        assert!(gains.windows(2).all(|pair| pair[0] >= pair[1]));
+
+        // With only K/DA included, every recommendation is a K/DA champ that can share it with Ahri.
+        let evelynn = champ("Evelynn");
+        let by_comps = recommend_champs(&players, 1, &HashSet::from([kda]), settings, RecommendBy::Comps);
+        let by_comps = by_comps.recommendations;
+        assert!(by_comps.iter().all(|recommendation| recommendation.unlocked_skinsets == vec![kda]));
+        assert!(by_comps.iter().any(|recommendation| recommendation.champ == evelynn));
+
+        // The new comps are exactly the ones with the player on the new champ.
+        for recommendation in &by_comps {
+            let mut on_champ = players.to_vec();
+            on_champ[1].champs = vec![(recommendation.champ, recommendation.lanes)];
+            assert_eq!(solver::comp_count(&on_champ, &HashSet::from([kda]), settings), recommendation.new_comps);
+        }
+    }
+    #[test]
+    fn champ_locks_dont_block_recommendations() {
+        let mut players = [player(&["Ahri"]), player(&["Garen"])];
+        players[1].lock.champ = Some(champ("Garen"));
+        let kda = skinset("K/DA");
+
+        let settings = SolverSettings::default();
+        let ranked = recommend_champs(&players, 1, &HashSet::from([kda]), settings, RecommendBy::Skinsets);
+        assert!(ranked.recommendations.iter().any(|recommendation| recommendation.champ == champ("Evelynn")));
+    }
+}
@@ -0,0 +1,170 @@
+//! Random selection of a comp from the solver's results, for when the players want the app to decide for them.
+
+use crate::{
+    constants::{SkinId, SkinsetId},
+    solver::{Comp, Pick},
+};
+use derive_more::Display;
+
+/// How to choose a random comp.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Display)]
+pub enum RollMode {
+    /// Choose a skinset first, then a comp in it -- every skinset is equally likely, however many comps it has.
+    #[default]
+    #[display(fmt = "Any skinset")]
+    BySkinset,
+
+    /// Choose from every comp -- skinsets with more comps come up more often.
+    #[display(fmt = "Any comp")]
+    ByComp,
+}
+
+impl RollMode {
+    /// All the roll modes, in the order they're listed in the UI.
+    pub const ALL: [Self; 2] = [Self::BySkinset, Self::ByComp];
+}
+
+/// A randomly chosen comp and the skinset it was chosen for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Roll {
+    /// The picks for each player, in the same order as the players.
+    pub picks: Vec<Pick>,
+
+    /// The skinset the players will use. This is always one of the skinsets the picks share.
+    pub skinset: SkinsetId,
+}
+
+impl Roll {
+    /// Get the skins each player has in the rolled skinset, in the same order as the players.
+    pub fn skins(&self) -> Vec<Vec<SkinId>> {
+        self.picks.iter().map(|pick| pick.champ.skins_in(self.skinset).collect()).collect()
+    }
+}
+
+/// Get a uniformly random index below `len` from the system's random source. `len` must not be zero.
+pub fn random_index(len: usize) -> Result<usize, getrandom::Error> {
+    let mut bytes = [0u8; 8];
+    getrandom::getrandom(&mut bytes)?;
+
+    // The modulo bias is negligible for the number of comps we deal with.
+    Ok((u64::from_le_bytes(bytes) % len as u64) as usize)
+}
+
+/// Make a roll (e.g. with [`roll`] or [`reroll_player`]) using the system's random source. If the random source
+/// fails, its error is returned instead of a roll made from indices that aren't random.
+pub fn with_system_random<T>(roll: impl FnOnce(&mut dyn FnMut(usize) -> usize) -> T) -> Result<T, getrandom::Error> {
+    let mut error: Option<getrandom::Error> = None;
+
+    let rolled: T = roll(&mut |len| {
+        random_index(len).unwrap_or_else(|err| {
+            error.get_or_insert(err);
+            0
+        })
+    });
+
+    error.map_or(Ok(rolled), Err)
+}
+
+/// Choose a random comp. `random_index` is called with a non-zero length and must return an index below it. Returns
+/// [`None`] if there are no comps.
+pub fn roll(comps: &[Comp], mode: RollMode, mut random_index: impl FnMut(usize) -> usize) -> Option<Roll> {
+    match mode {
+        RollMode::ByComp => {
+            if comps.is_empty() {
+                return None;
+            }
+
+            let comp: &Comp = &comps[random_index(comps.len())];
+            let skinset: SkinsetId = comp.skinsets[random_index(comp.skinsets.len())];
+            Some(Roll { picks: comp.picks.clone(), skinset })
+        }
+
+        RollMode::BySkinset => {
+            let mut skinsets: Vec<SkinsetId> = comps.iter().flat_map(|comp| comp.skinsets.iter().copied()).collect();
+            skinsets.sort();
+            skinsets.dedup();
+
+            if skinsets.is_empty() {
+                return None;
+            }
+
+            let skinset: SkinsetId = skinsets[random_index(skinsets.len())];
+            let in_skinset: Vec<&Comp> = comps.iter().filter(|comp| comp.skinsets.contains(&skinset)).collect();
+            let comp: &Comp = in_skinset[random_index(in_skinset.len())];
+            Some(Roll { picks: comp.picks.clone(), skinset })
+        }
+    }
+}
+
+/// Choose a new random pick for one player, keeping every other player's pick and the skinset. Returns [`None`] if
+/// there is no other pick that player could make.
+pub fn reroll_player(
+    comps: &[Comp],
+    current: &Roll,
+    player_index: usize,
+    mut random_index: impl FnMut(usize) -> usize,
+) -> Option<Roll> {
+    let candidates: Vec<&Comp> = comps
+        .iter()
+        .filter(|comp| comp.skinsets.contains(&current.skinset))
+        .filter(|comp| comp.picks.get(player_index) != current.picks.get(player_index))
+        .filter(|comp| {
+            comp.picks
+                .iter()
+                .zip(current.picks.iter())
+                .enumerate()
+                .all(|(index, (pick, current_pick))| index == player_index || pick == current_pick)
+        })
+        .collect();
+
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let comp: &Comp = candidates[random_index(candidates.len())];
+    Some(Roll { picks: comp.picks.clone(), skinset: current.skinset })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{reroll_player, roll, RollMode};
+    use crate::{
+        constants::SkinsetId,
+        model::{GameMode, SolverSettings},
+        solver::{self, Comp},
+        test_fixtures::{player, skinset},
+    };
+    use std::collections::HashSet;
+
+    fn comps() -> Vec<Comp> {
+        let players = [player(&["Ahri", "Lux"]), player(&["Evelynn", "Akali", "Kai'Sa"])];
+        let included: HashSet<SkinsetId> = HashSet::from([skinset("K/DA")]);
+        solver::solve(&players, &included, SolverSettings { game_mode: GameMode::Aram, owned_only: false })
+    }
+
+    #[test]
+    fn rolls_pick_from_the_results() {
+        let comps = comps();
+        assert!(roll(&[], RollMode::BySkinset, |_| 0).is_none());
+
+        for mode in RollMode::ALL {
+            let rolled = roll(&comps, mode, |len| len - 1).unwrap();
+            assert!(comps.iter().any(|comp| comp.picks == rolled.picks && comp.skinsets.contains(&rolled.skinset)));
+            assert!(rolled.skins().iter().all(|skins| !skins.is_empty()));
+        }
+    }
+
+    #[test]
+    fn rerolling_keeps_the_other_players() {
+        let comps = comps();
+        let rolled = roll(&comps, RollMode::ByComp, |_| 0).unwrap();
+
+        let rerolled = reroll_player(&comps, &rolled, 1, |_| 0).unwrap();
+        assert_eq!(rerolled.picks[0], rolled.picks[0]);
+        assert_ne!(rerolled.picks[1], rolled.picks[1]);
+        assert_eq!(rerolled.skinset, rolled.skinset);
+
+        // Lux isn't in K/DA, so Ahri is the only option for the first player.
+        assert!(reroll_player(&comps, &rolled, 0, |_| 0).is_none());
+    }
+}
@@ -0,0 +1,420 @@
+//! Versioned JSON roster documents, used to import and export the whole session as a file.
+//!
+//! The document format is defined by [`RosterDocument`]. Champs, lanes and skinsets are referred to by name so that
+//! roster files can be written by hand or generated by scripts. An example document:
+//!
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "game_mode": "SummonersRift",
+//!   "players": [
+//!     { "name": "Emma", "champs": [{ "champ": "Ahri", "lanes": ["Mid"] }, { "champ": "Jinx", "lanes": ["Bot"] }] },
+//!     {
+//!       "name": null,
+//!       "champs": [{ "champ": "Vel'Koz", "lanes": ["Mid", "Support"] }],
+//!       "excluded_skinsets": ["K/DA"],
+//!       "owned_skins": ["Arclight Vel'Koz"],
+//!       "lock": { "champ": "Vel'Koz", "lane": "Support" }
+//!     }
+//!   ],
+//!   "owned_only": false,
+//!   "included_skinsets": ["K/DA", "Spirit Blossom", "Star Guardian Season 1"]
+//! }
+//! ```
+
+use crate::{
+    constants::{ChampId, Lane, SkinId, SkinsetId},
+    model::{GameMode, LockIn, PlayerRecord, MAX_PLAYERS},
+};
+use derive_more::Display;
+use enumflags2::BitFlags;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// The current version of the roster document schema. Bump this whenever [`RosterDocument`] changes.
+pub const ROSTER_SCHEMA_VERSION: u32 = 1;
+
+/// A roster document, as written to and read from JSON files.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RosterDocument {
+    /// The version of the schema this document was written with. Must be [`ROSTER_SCHEMA_VERSION`].
+    pub schema_version: u32,
+
+    /// The game mode being played.
+    #[serde(default)]
+    pub game_mode: GameMode,
+
+    /// The players in the roster, in order.
+    pub players: Vec<RosterPlayer>,
+
+    /// The names of the skinsets included in the search.
+    pub included_skinsets: Vec<String>,
+
+    /// Whether the search only uses skins the players own.
+    #[serde(default)]
+    pub owned_only: bool,
+}
+
+/// A player in a [`RosterDocument`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RosterPlayer {
+    /// The player's name, if they have one.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// The champs this player is willing to play, in order.
+    pub champs: Vec<RosterChamp>,
+
+    /// The names of skinsets this player won't play, on top of the ones excluded for everyone.
+    #[serde(default)]
+    pub excluded_skinsets: Vec<String>,
+
+    /// The names of skins this player owns.
+    #[serde(default)]
+    pub owned_skins: Vec<String>,
+
+    /// The champ and/or lane this player has locked in, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lock: Option<RosterLock>,
+}
+
+/// A [`RosterPlayer`]'s lock-in. See [`LockIn`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RosterLock {
+    /// The name of the locked champ, which must be one of the player's champs.
+    #[serde(default)]
+    pub champ: Option<String>,
+
+    /// The name of the locked lane.
+    #[serde(default)]
+    pub lane: Option<String>,
+}
+
+/// A champ in a [`RosterPlayer`]'s pool.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RosterChamp {
+    /// The champ's name, as it appears in the lane data (e.g. `"Kai'Sa"`). Case, spaces and punctuation are ignored
+    /// when importing, so `"kaisa"` works too.
+    pub champ: String,
+
+    /// The names of the lanes the player will play this champ in (`"Top"`, `"Jungle"`, `"Mid"`, `"Bot"` or
+    /// `"Support"`). When omitted, the champ's default lanes are used.
+    #[serde(default)]
+    pub lanes: Option<Vec<String>>,
+}
+
+/// A roster after it has been validated against the current data.
+#[derive(Debug, Clone)]
+pub struct ValidatedRoster {
+    /// The game mode being played.
+    pub game_mode: GameMode,
+
+    /// Whether the search only uses skins the players own.
+    pub owned_only: bool,
+
+    /// The player records.
+    pub players: Vec<PlayerRecord>,
+
+    /// The set of skinsets included in the search.
+    pub skinsets: HashSet<SkinsetId>,
+}
+
+/// An error found in a specific field of a [`RosterDocument`].
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+#[display(fmt = "{}: {}", field, message)]
+pub struct RosterFieldError {
+    /// The path to the field with the error (e.g. `players[1].champs[0].champ`).
+    pub field: String,
+
+    /// A description of what is wrong with the field.
+    pub message: String,
+}
+
+impl RosterFieldError {
+    /// Create a new field error.
+    fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self { field: field.into(), message: message.into() }
+    }
+}
+
+impl RosterDocument {
+    /// Make a roster document from the current session state.
+    pub fn from_session(
+        game_mode: GameMode,
+        owned_only: bool,
+        players: &[PlayerRecord],
+        skinsets: &HashSet<SkinsetId>,
+    ) -> Self {
+        Self {
+            schema_version: ROSTER_SCHEMA_VERSION,
+            game_mode,
+            players: players
+                .iter()
+                .map(|player| RosterPlayer {
+                    name: player.name.clone(),
+                    champs: player.champs
+                        .iter()
+                        .map(|(champ_id, lanes)| RosterChamp {
+                            champ: champ_id.champ_name().to_owned(),
+                            lanes: Some(lanes.iter().map(|lane| lane.to_string()).collect()),
+                        })
+                        .collect(),
+                    excluded_skinsets: sorted_names(&player.excluded_skinsets, |skinset_id| skinset_id.skinset_name()),
+                    owned_skins: sorted_names(&player.owned_skins, |skin_id| skin_id.skin_name()),
+                    lock: (!player.lock.is_empty()).then(|| RosterLock {
+                        champ: player.lock.champ.map(|champ_id| champ_id.champ_name().to_owned()),
+                        lane: player.lock.lane.map(|lane| lane.to_string()),
+                    }),
+                })
+                .collect(),
+            included_skinsets: sorted_names(skinsets, |skinset_id| skinset_id.skinset_name()),
+            owned_only,
+        }
+    }
+
+    /// Parse a roster document from JSON and validate it. See [`RosterDocument::validate`].
+    pub fn parse(json: &str) -> Result<ValidatedRoster, Vec<RosterFieldError>> {
+        serde_json::from_str::<RosterDocument>(json)
+            .map_err(|err| vec![RosterFieldError::new("document", format!("invalid roster JSON: {err}"))])?
+            .validate()
+    }
+
+    /// Validate this document against the current data, collecting an error for every invalid field.
+    pub fn validate(&self) -> Result<ValidatedRoster, Vec<RosterFieldError>> {
+        let mut errors: Vec<RosterFieldError> = Vec::new();
+
+        if self.schema_version != ROSTER_SCHEMA_VERSION {
+            errors.push(RosterFieldError::new(
+                "schema_version",
+                format!("unsupported schema version {} (expected {ROSTER_SCHEMA_VERSION})", self.schema_version),
+            ));
+        }
+
+        if self.players.is_empty() {
+            errors.push(RosterFieldError::new("players", "roster must have at least one player"));
+        } else if self.players.len() > MAX_PLAYERS {
+            errors.push(RosterFieldError::new(
+                "players",
+                format!("roster has {} players, but a team has at most {MAX_PLAYERS}", self.players.len()),
+            ));
+        }
+
+        let mut players: Vec<PlayerRecord> = Vec::with_capacity(self.players.len());
+
+        for (player_index, roster_player) in self.players.iter().enumerate() {
+            let mut player = PlayerRecord::new();
+            player.name = roster_player.name.clone().filter(|name| !name.is_empty());
+
+            for (champ_index, roster_champ) in roster_player.champs.iter().enumerate() {
+                let field: String = format!("players[{player_index}].champs[{champ_index}]");
+
+                let champ_id: ChampId = match roster_champ.champ.parse() {
+                    Ok(champ_id) => champ_id,
+                    Err(err) => {
+                        errors.push(RosterFieldError::new(format!("{field}.champ"), err.to_string()));
+                        continue;
+                    }
+                };
+
+                if player.champs.iter().any(|(existing, _)| *existing == champ_id) {
+                    errors.push(RosterFieldError::new(
+                        format!("{field}.champ"),
+                        format!("{} is listed more than once for this player", champ_id.champ_name()),
+                    ));
+                    continue;
+                }
+
+                // Resolve the lanes, defaulting to the champ's usual lanes.
+                let mut lanes: BitFlags<Lane> = BitFlags::empty();
+
+                match &roster_champ.lanes {
+                    None => lanes = champ_id.default_lanes(),
+
+                    Some(lane_names) => {
+                        for (lane_index, lane_name) in lane_names.iter().enumerate() {
+                            match parse_lane(lane_name) {
+                                Some(lane) => lanes |= lane,
+                                None => errors.push(RosterFieldError::new(
+                                    format!("{field}.lanes[{lane_index}]"),
+                                    format!("invalid lane {lane_name:?}"),
+                                )),
+                            }
+                        }
+                    }
+                }
+
+                player.champs.push((champ_id, lanes));
+            }
+
+            for (skinset_index, skinset_name) in roster_player.excluded_skinsets.iter().enumerate() {
+                match skinset_name.parse::<SkinsetId>() {
+                    Ok(skinset_id) => {
+                        player.excluded_skinsets.insert(skinset_id);
+                    }
+
+                    Err(err) => errors.push(RosterFieldError::new(
+                        format!("players[{player_index}].excluded_skinsets[{skinset_index}]"),
+                        err.to_string(),
+                    )),
+                }
+            }
+
+            for (skin_index, skin_name) in roster_player.owned_skins.iter().enumerate() {
+                match SkinId::from_skin_name(skin_name) {
+                    Some(skin_id) => {
+                        player.owned_skins.insert(skin_id);
+                    }
+
+                    None => errors.push(RosterFieldError::new(
+                        format!("players[{player_index}].owned_skins[{skin_index}]"),
+                        format!("unknown skin {skin_name:?}"),
+                    )),
+                }
+            }
+
+            if let Some(roster_lock) = &roster_player.lock {
+                let field: String = format!("players[{player_index}].lock");
+                player.lock = validate_lock(roster_lock, &player, &field, &mut errors);
+            }
+
+            players.push(player);
+        }
+
+        let mut skinsets: HashSet<SkinsetId> = HashSet::with_capacity(self.included_skinsets.len());
+
+        for (skinset_index, skinset_name) in self.included_skinsets.iter().enumerate() {
+            match skinset_name.parse::<SkinsetId>() {
+                Ok(skinset_id) => {
+                    skinsets.insert(skinset_id);
+                }
+
+                Err(err) => {
+                    errors.push(RosterFieldError::new(format!("included_skinsets[{skinset_index}]"), err.to_string()))
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(ValidatedRoster { game_mode: self.game_mode, owned_only: self.owned_only, players, skinsets })
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Serialize this document as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("roster documents always serialize")
+    }
+}
+
+/// Validate a player's lock-in, after their champs have been validated. Invalid parts are reported and left unlocked.
+fn validate_lock(
+    roster_lock: &RosterLock,
+    player: &PlayerRecord,
+    field: &str,
+    errors: &mut Vec<RosterFieldError>,
+) -> LockIn {
+    let mut lock = LockIn::default();
+
+    if let Some(champ_name) = &roster_lock.champ {
+        match champ_name.parse::<ChampId>() {
+            Ok(champ_id) if player.has_champ(champ_id) => lock.champ = Some(champ_id),
+
+            Ok(champ_id) => errors.push(RosterFieldError::new(
+                format!("{field}.champ"),
+                format!("{} is locked in but isn't one of this player's champs", champ_id.champ_name()),
+            )),
+
+            Err(err) => errors.push(RosterFieldError::new(format!("{field}.champ"), err.to_string())),
+        }
+    }
+
+    if let Some(lane_name) = &roster_lock.lane {
+        match parse_lane(lane_name) {
+            Some(lane) => lock.lane = Some(lane),
+            None => errors.push(RosterFieldError::new(format!("{field}.lane"), format!("invalid lane {lane_name:?}"))),
+        }
+    }
+
+    lock
+}
+
+/// Get the names of a set of IDs, sorted so that exported files are stable.
+fn sorted_names<T: Copy + Ord>(ids: &HashSet<T>, name: impl Fn(T) -> &'static str) -> Vec<String> {
+    let mut ids: Vec<T> = ids.iter().copied().collect();
+    ids.sort();
+    ids.into_iter().map(|id| name(id).to_owned()).collect()
+}
+
+/// Parse a lane from its name, ignoring case.
+fn parse_lane(lane_name: &str) -> Option<Lane> {
+    BitFlags::<Lane>::all()
+        .iter()
+        .find(|lane| lane.to_string().eq_ignore_ascii_case(lane_name.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RosterDocument, RosterFieldError};
+    use crate::{
+        constants::{ChampId, Lane, SkinId, SkinsetId},
+        model::{GameMode, LockIn, PlayerRecord, MAX_PLAYERS},
+    };
+    use enumflags2::BitFlags;
+
+    #[test]
+    fn export_then_import_round_trips() {
+        let mut player = PlayerRecord::new();
+        player.name = Some("Emma".to_owned());
+        player.champs.push((ChampId::from_champ_name("Ahri").unwrap(), BitFlags::all()));
+        player.excluded_skinsets.insert(SkinsetId::from_skinset_name("K/DA").unwrap());
+        player.owned_skins.insert(SkinId::from_skin_name("Spirit Blossom Ahri").unwrap());
+        player.lock = LockIn { champ: ChampId::from_champ_name("Ahri"), lane: Some(Lane::Mid) };
+
+        let skinsets = SkinsetId::generate_default_included_skinsets();
+        let json: String = RosterDocument::from_session(GameMode::Aram, true, &[player.clone()], &skinsets).to_json();
+        let roster = RosterDocument::parse(&json).unwrap();
+
+        assert_eq!(roster.game_mode, GameMode::Aram);
+        assert!(roster.owned_only);
+        assert_eq!(roster.players, vec![player]);
+        assert_eq!(roster.skinsets, skinsets);
+    }
+
+    #[test]
+    fn invalid_fields_are_reported_individually() {
+        let json = r#"{
+            "schema_version": 1,
+            "players": [{ "name": "Toni", "champs": [
+                { "champ": "Velkozz", "lanes": ["Mid"] },
+                { "champ": "Cho'Gath", "lanes": ["Top", "Feeder"] },
+                { "champ": "Briar" }
+            ], "owned_skins": ["Academy Briar"], "lock": { "champ": "Jinx", "lane": "Middle" } }],
+            "included_skinsets": ["Not A Skinset"]
+        }"#;
+
+        let errors: Vec<String> = RosterDocument::parse(json)
+            .unwrap_err()
+            .iter()
+            .map(RosterFieldError::to_string)
+            .collect();
+
+        assert_eq!(errors, [
+            r#"players[0].champs[0].champ: unknown champion "Velkozz" (did you mean Vel'Koz?)"#,
+            r#"players[0].champs[1].lanes[1]: invalid lane "Feeder""#,
+            r#"players[0].owned_skins[0]: unknown skin "Academy Briar""#,
+            r#"players[0].lock.champ: Jinx is locked in but isn't one of this player's champs"#,
+            r#"players[0].lock.lane: invalid lane "Middle""#,
+            r#"included_skinsets[0]: unknown skinset "Not A Skinset""#,
+        ]);
+    }
+
+    #[test]
+    fn oversized_rosters_are_rejected() {
+        let players: Vec<PlayerRecord> = vec![PlayerRecord::new(); MAX_PLAYERS + 1];
+        let document = RosterDocument::from_session(GameMode::SummonersRift, false, &players, &Default::default());
+
+        let errors: Vec<String> = document.validate().unwrap_err().iter().map(RosterFieldError::to_string).collect();
+        assert_eq!(errors, ["players: roster has 6 players, but a team has at most 5"]);
+    }
+}
@@ -0,0 +1,588 @@
+//! Resolution of every team comp the players could queue where all the champs share at least one skinset.
+
+use crate::{
+    constants::{ChampId, Lane, SkinsetId},
+    model::{GameMode, PlayerRecord, SolverSettings},
+};
+use enumflags2::BitFlags;
+use std::{collections::HashSet, ops::ControlFlow};
+
+/// A champ picked by one player in a comp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pick {
+    /// The champ being played.
+    pub champ: ChampId,
+
+    /// The lane the champ is played in, or [`None`] in game modes without lanes.
+    pub lane: Option<Lane>,
+}
+
+/// A team comp -- one pick for each player, in the same order as the players -- and the skinsets every pick shares.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comp {
+    /// The pick for each player.
+    pub picks: Vec<Pick>,
+
+    /// The skinsets shared by every pick, sorted. Never empty.
+    pub skinsets: Vec<SkinsetId>,
+}
+
+/// The options every player has in one skinset -- an alternative view of the results for picking a theme first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkinsetOptions {
+    /// The skinset.
+    pub skinset: SkinsetId,
+
+    /// For each player (in the same order as the players), the champs they could play in this skinset and the lanes
+    /// they could play them in. Lanes are always empty in game modes without lanes. Only champs and lanes that are
+    /// part of at least one full comp are listed.
+    pub players: Vec<Vec<(ChampId, BitFlags<Lane>)>>,
+
+    /// The number of distinct comps in this skinset.
+    pub comp_count: usize,
+}
+
+/// A skinset that some of the players have champs in -- a looser view of the results for finding sets that are nearly
+/// there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialMatch {
+    /// The skinset.
+    pub skinset: SkinsetId,
+
+    /// For each player (in the same order as the players), the champs they could play in this skinset. Empty for
+    /// players with none.
+    pub players: Vec<Vec<ChampId>>,
+}
+
+impl Pick {
+    /// Check whether two picks can't be in the same comp -- they're the same champ, or the same lane.
+    fn conflicts_with(&self, other: &Pick) -> bool {
+        self.champ == other.champ || (self.lane.is_some() && self.lane == other.lane)
+    }
+}
+
+impl PartialMatch {
+    /// The number of players with at least one champ in this skinset.
+    pub fn matched_players(&self) -> usize {
+        self.players.iter().filter(|champs| !champs.is_empty()).count()
+    }
+}
+
+/// A pick a player could make, and the skinsets they would accept for it (sorted).
+#[derive(Debug, Clone)]
+struct PickOption {
+    pick: Pick,
+    skinsets: Vec<SkinsetId>,
+}
+
+/// Resolve every comp the players could queue where all the picks share at least one included skinset.
+///
+/// A skinset only counts for a pick if it is in `included_skinsets`, the champ is in it, and the player accepts it (see
+/// [`PlayerRecord::accepts_skinset`]). No champ can be picked twice, and on Summoner's Rift no lane can be used twice.
+/// Picks always match each player's [`PlayerRecord::lock`].
+pub fn solve(players: &[PlayerRecord], included_skinsets: &HashSet<SkinsetId>, settings: SolverSettings) -> Vec<Comp> {
+    solve_limited(players, included_skinsets, settings, None, |_| true)
+}
+
+/// Resolve comps like [`solve`], but only keep the ones `keep` accepts, and stop searching as soon as `limit` have
+/// been found. The comps found are the first `limit` that [`solve`] would return (after filtering), in the same order.
+pub fn solve_limited(
+    players: &[PlayerRecord],
+    included_skinsets: &HashSet<SkinsetId>,
+    settings: SolverSettings,
+    limit: Option<usize>,
+    mut keep: impl FnMut(&Comp) -> bool,
+) -> Vec<Comp> {
+    let limit: usize = limit.unwrap_or(usize::MAX);
+
+    if players.is_empty() || limit == 0 {
+        return Vec::new();
+    }
+
+    // Only skinsets with enough champs for everyone can have a comp.
+    let included_skinsets: HashSet<SkinsetId> = skinsets_with_room_for(included_skinsets, players.len());
+    let mut results: Vec<Comp> = Vec::new();
+
+    search(&all_pick_options(players, &included_skinsets, settings), &mut |found: Found| {
+        let comp = Comp { picks: found.picks.to_vec(), skinsets: found.skinsets.to_vec() };
+
+        if keep(&comp) {
+            results.push(comp);
+        }
+
+        if results.len() < limit {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    });
+
+    results
+}
+
+/// Find every skinset the players could queue a full comp in, ignoring which skinsets are currently included -- i.e.
+/// the skinsets where every player has an accepted champ and those champs can be given distinct lanes.
+pub fn achievable_skinsets(players: &[PlayerRecord], settings: SolverSettings) -> HashSet<SkinsetId> {
+    if players.is_empty() {
+        return HashSet::new();
+    }
+
+    SkinsetId::iter_all().filter(|skinset_id| is_achievable(players, *skinset_id, settings)).collect()
+}
+
+/// Check whether the players could queue a full comp in a skinset, ignoring which skinsets are currently included.
+pub fn is_achievable(players: &[PlayerRecord], skinset_id: SkinsetId, settings: SolverSettings) -> bool {
+    // Only skinsets with enough champs and a champ from every player could possibly be achievable.
+    if players.is_empty()
+        || skinset_id.champ_count() < players.len()
+        || players_with_champ_in(players, skinset_id) < players.len()
+    {
+        return false;
+    }
+
+    let options: Vec<Vec<PickOption>> = all_pick_options(players, &HashSet::from([skinset_id]), settings);
+    let mut found: bool = false;
+
+    search(&options, &mut |_| {
+        found = true;
+        ControlFlow::Break(())
+    });
+
+    found
+}
+
+/// Count the comps [`solve`] would resolve, without collecting them.
+pub fn comp_count(players: &[PlayerRecord], included_skinsets: &HashSet<SkinsetId>, settings: SolverSettings) -> usize {
+    if players.is_empty() {
+        return 0;
+    }
+
+    let included_skinsets: HashSet<SkinsetId> = skinsets_with_room_for(included_skinsets, players.len());
+    let mut count: usize = 0;
+
+    search(&all_pick_options(players, &included_skinsets, settings), &mut |_| {
+        count += 1;
+        ControlFlow::Continue(())
+    });
+
+    count
+}
+
+/// Resolve the options each player has in every included skinset the players could queue a full comp in, in skinset
+/// order.
+pub fn solve_by_skinset(
+    players: &[PlayerRecord],
+    included_skinsets: &HashSet<SkinsetId>,
+    settings: SolverSettings,
+) -> Vec<SkinsetOptions> {
+    if players.is_empty() {
+        return Vec::new();
+    }
+
+    let mut skinsets: Vec<SkinsetId> = included_skinsets
+        .iter()
+        .copied()
+        .filter(|skinset_id| skinset_id.champ_count() >= players.len())
+        .filter(|skinset_id| players_with_champ_in(players, *skinset_id) == players.len())
+        .collect();
+    skinsets.sort();
+
+    skinsets
+        .into_iter()
+        .filter_map(|skinset_id| {
+            let options: Vec<Vec<PickOption>> = all_pick_options(players, &HashSet::from([skinset_id]), settings);
+
+            // Count the comps, and track which options are part of at least one.
+            let mut used: Vec<Vec<bool>> = options.iter().map(|player_options| vec![false; player_options.len()]).collect();
+            let mut comp_count: usize = 0;
+
+            search(&options, &mut |found: Found| {
+                comp_count += 1;
+                found.chosen.iter().zip(used.iter_mut()).for_each(|(index, player_used)| player_used[*index] = true);
+                ControlFlow::Continue(())
+            });
+
+            if comp_count == 0 {
+                return None;
+            }
+
+            // Group each player's used options by champ, in the order of the player's champs.
+            let players: Vec<Vec<(ChampId, BitFlags<Lane>)>> = options
+                .iter()
+                .zip(used)
+                .map(|(player_options, player_used)| {
+                    let mut champs: Vec<(ChampId, BitFlags<Lane>)> = Vec::new();
+
+                    for (option, _) in player_options.iter().zip(player_used).filter(|(_, used)| *used) {
+                        let lanes: BitFlags<Lane> = option.pick.lane.map(BitFlags::from).unwrap_or_default();
+
+                        match champs.iter_mut().find(|(champ_id, _)| *champ_id == option.pick.champ) {
+                            Some((_, champ_lanes)) => *champ_lanes |= lanes,
+                            None => champs.push((option.pick.champ, lanes)),
+                        }
+                    }
+
+                    champs
+                })
+                .collect();
+
+            Some(SkinsetOptions { skinset: skinset_id, players, comp_count })
+        })
+        .collect()
+}
+
+/// Find every included skinset that at least `min_players` of the players have a champ in, with the most matched
+/// players first and then in skinset order.
+///
+/// Unlike [`solve`] this ignores lanes, since the players without a champ in the set would have to pick something else
+/// anyway. Exclusions, owned skins and champ locks still apply.
+pub fn partial_matches(
+    players: &[PlayerRecord],
+    included_skinsets: &HashSet<SkinsetId>,
+    settings: SolverSettings,
+    min_players: usize,
+) -> Vec<PartialMatch> {
+    let mut skinsets: Vec<SkinsetId> = included_skinsets.iter().copied().collect();
+    skinsets.sort();
+
+    let mut matches: Vec<PartialMatch> = skinsets
+        .into_iter()
+        .map(|skinset_id| PartialMatch {
+            skinset: skinset_id,
+            players: players
+                .iter()
+                .map(|player| {
+                    player
+                        .champs
+                        .iter()
+                        .map(|(champ_id, _)| *champ_id)
+                        .filter(|champ_id| player.lock.allows_champ(*champ_id))
+                        .filter(|champ_id| skinset_id.has_champ(*champ_id))
+                        .filter(|champ_id| player.accepts_skinset(*champ_id, skinset_id, settings.owned_only))
+                        .collect()
+                })
+                .collect(),
+        })
+        .filter(|partial| partial.matched_players() > 0 && partial.matched_players() >= min_players)
+        .collect();
+
+    matches.sort_by_key(|partial| std::cmp::Reverse(partial.matched_players()));
+    matches
+}
+
+/// Count the players with at least one champ in a skinset, regardless of lanes or exclusions.
+pub fn players_with_champ_in(players: &[PlayerRecord], skinset_id: SkinsetId) -> usize {
+    players
+        .iter()
+        .filter(|player| player.champs.iter().any(|(champ_id, _)| skinset_id.has_champ(*champ_id)))
+        .count()
+}
+
+/// Keep only the skinsets with at least as many champs as there are players -- since no champ can be picked twice, no
+/// other skinset can have a comp.
+fn skinsets_with_room_for(included_skinsets: &HashSet<SkinsetId>, player_count: usize) -> HashSet<SkinsetId> {
+    included_skinsets.iter().copied().filter(|skinset_id| skinset_id.champ_count() >= player_count).collect()
+}
+
+/// Get all the picks a player could make, with the skinsets they'd accept for each, dropping any pick with no
+/// skinsets at all or that doesn't match the player's lock-in.
+fn pick_options(player: &PlayerRecord, included_skinsets: &HashSet<SkinsetId>, settings: SolverSettings) -> Vec<PickOption> {
+    let mut options: Vec<PickOption> = Vec::new();
+
+    for (champ_id, lanes) in player.champs.iter() {
+        if !player.lock.allows_champ(*champ_id) {
+            continue;
+        }
+
+        let mut skinsets: Vec<SkinsetId> = champ_id
+            .skinsets()
+            .filter(|skinset_id| included_skinsets.contains(skinset_id))
+            .filter(|skinset_id| player.accepts_skinset(*champ_id, *skinset_id, settings.owned_only))
+            .collect();
+
+        if skinsets.is_empty() {
+            continue;
+        }
+
+        skinsets.sort();
+
+        match settings.game_mode {
+            GameMode::SummonersRift => {
+                for lane in lanes.iter().filter(|lane| player.lock.allows_lane(*lane)) {
+                    options.push(PickOption {
+                        pick: Pick { champ: *champ_id, lane: Some(lane) },
+                        skinsets: skinsets.clone(),
+                    });
+                }
+            }
+
+            GameMode::Aram => options.push(PickOption { pick: Pick { champ: *champ_id, lane: None }, skinsets }),
+        }
+    }
+
+    options
+}
+
+/// Get the pick options for every player, in the same order as the players.
+fn all_pick_options(
+    players: &[PlayerRecord],
+    included_skinsets: &HashSet<SkinsetId>,
+    settings: SolverSettings,
+) -> Vec<Vec<PickOption>> {
+    players.iter().map(|player| pick_options(player, included_skinsets, settings)).collect()
+}
+
+/// Add one more player to comps of the other players, calling `visit` with the skinsets shared by each comp that
+/// makes. The new player's picks are resolved as in [`solve`], and never reuse a champ or lane from the comp.
+pub(crate) fn join_comps(
+    comps: &[Comp],
+    player: &PlayerRecord,
+    included_skinsets: &HashSet<SkinsetId>,
+    settings: SolverSettings,
+    mut visit: impl FnMut(&[SkinsetId]),
+) {
+    let options: Vec<PickOption> = pick_options(player, included_skinsets, settings);
+
+    for comp in comps {
+        for option in options.iter().filter(|option| !comp.picks.iter().any(|pick| pick.conflicts_with(&option.pick))) {
+            let shared: Vec<SkinsetId> = intersect_sorted(&comp.skinsets, &option.skinsets);
+
+            if !shared.is_empty() {
+                visit(&shared);
+            }
+        }
+    }
+}
+
+/// A full comp found by [`search`].
+struct Found<'a> {
+    /// The pick for each player.
+    picks: &'a [Pick],
+
+    /// The index of each player's pick in their options.
+    chosen: &'a [usize],
+
+    /// The skinsets shared by every pick, sorted. Never empty.
+    skinsets: &'a [SkinsetId],
+}
+
+/// Depth-first search for every comp from the players' options, calling `visit` with each one found. No champ is
+/// picked twice, no lane is used twice, and branches are pruned as soon as the picks share no skinset. The search stops
+/// as soon as `visit` breaks.
+fn search(options: &[Vec<PickOption>], visit: &mut impl FnMut(Found) -> ControlFlow<()>) {
+    let mut picks: Vec<Pick> = Vec::with_capacity(options.len());
+    let mut chosen: Vec<usize> = Vec::with_capacity(options.len());
+    let _ = search_from(options, &mut picks, &mut chosen, None, visit);
+}
+
+/// The recursive part of [`search`], over the remaining players' options given the picks so far and the skinsets they
+/// share (`None` before the first pick).
+fn search_from(
+    options: &[Vec<PickOption>],
+    picks: &mut Vec<Pick>,
+    chosen: &mut Vec<usize>,
+    shared: Option<&[SkinsetId]>,
+    visit: &mut impl FnMut(Found) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    let Some((player_options, remaining)) = options.split_first() else {
+        // Every player has a pick.
+        return visit(Found { picks, chosen, skinsets: shared.unwrap_or_default() });
+    };
+
+    for (option_index, option) in player_options.iter().enumerate() {
+        if picks.iter().any(|pick| pick.conflicts_with(&option.pick)) {
+            continue;
+        }
+
+        let new_shared: Vec<SkinsetId> = match shared {
+            None => option.skinsets.clone(),
+            Some(shared) => intersect_sorted(shared, &option.skinsets),
+        };
+
+        if new_shared.is_empty() {
+            continue;
+        }
+
+        picks.push(option.pick);
+        chosen.push(option_index);
+        let flow: ControlFlow<()> = search_from(remaining, picks, chosen, Some(&new_shared), visit);
+        picks.pop();
+        chosen.pop();
+        flow?;
+    }
+
+    ControlFlow::Continue(())
+}
+
+/// Intersect two sorted slices of skinsets.
+fn intersect_sorted(a: &[SkinsetId], b: &[SkinsetId]) -> Vec<SkinsetId> {
+    let mut result: Vec<SkinsetId> = Vec::with_capacity(a.len().min(b.len()));
+    let (mut a_index, mut b_index) = (0, 0);
+
+    while a_index < a.len() && b_index < b.len() {
+        match a[a_index].cmp(&b[b_index]) {
+            std::cmp::Ordering::Less => a_index += 1,
+            std::cmp::Ordering::Greater => b_index += 1,
+            std::cmp::Ordering::Equal => {
+                result.push(a[a_index]);
+                a_index += 1;
+                b_index += 1;
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        achievable_skinsets, comp_count, partial_matches, players_with_champ_in, solve, solve_by_skinset, solve_limited,
+    };
+    use crate::{
+        constants::{ChampId, Lane, SkinId, SkinsetId},
+        model::{GameMode, LockIn, PlayerRecord, SolverSettings},
+        solver::Comp,
+        test_fixtures::{player_in_any_lane as player, skinset},
+    };
+    use enumflags2::BitFlags;
+    use std::collections::HashSet;
+
+    #[test]
+    fn comps_share_a_skinset_and_never_repeat_lanes() {
+        let players = [player(&["Ahri"]), player(&["Evelynn"])];
+        let included: HashSet<SkinsetId> = SkinsetId::iter_all().collect();
+        let comps = solve(&players, &included, SolverSettings::default());
+
+        // Ahri and Evelynn share K/DA, in any pair of distinct lanes.
+        assert_eq!(comps.len(), 5 * 4);
+        assert_eq!(comp_count(&players, &included, SolverSettings::default()), comps.len());
+        assert!(comps.iter().all(|comp| comp.skinsets.contains(&skinset("K/DA"))));
+        assert!(comps.iter().all(|comp| comp.picks[0].lane != comp.picks[1].lane));
+
+        // A limited solve stops at the first comps a full solve finds, after filtering.
+        let mid_ahri = |comp: &Comp| comp.picks[0].lane == Some(Lane::Mid);
+        let limited = solve_limited(&players, &included, SolverSettings::default(), Some(3), mid_ahri);
+        assert_eq!(limited, comps.into_iter().filter(mid_ahri).take(3).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn aram_ignores_lanes() {
+        let players = [player(&["Ahri"]), player(&["Evelynn"])];
+        let included: HashSet<SkinsetId> = SkinsetId::iter_all().collect();
+        let settings = SolverSettings { game_mode: GameMode::Aram, owned_only: false };
+        let comps = solve(&players, &included, settings);
+
+        assert_eq!(comps.len(), 1);
+        assert!(comps[0].picks.iter().all(|pick| pick.lane.is_none()));
+    }
+
+    #[test]
+    fn player_exclusions_only_apply_to_that_player() {
+        let mut ahri = player(&["Ahri"]);
+        ahri.champs[0].1 = BitFlags::from(Lane::Mid);
+        let mut evelynn = player(&["Evelynn"]);
+        evelynn.champs[0].1 = BitFlags::from(Lane::Jungle);
+        let included: HashSet<SkinsetId> = SkinsetId::iter_all().collect();
+
+        let before = solve(&[ahri.clone(), evelynn.clone()], &included, SolverSettings::default());
+        assert!(before[0].skinsets.contains(&skinset("K/DA")));
+
+        evelynn.excluded_skinsets.insert(skinset("K/DA"));
+        let after = solve(&[ahri, evelynn], &included, SolverSettings::default());
+        assert!(after.iter().all(|comp| !comp.skinsets.contains(&skinset("K/DA"))));
+    }
+
+    #[test]
+    fn owned_only_requires_an_owned_skin_in_the_set() {
+        let mut ahri = player(&["Ahri"]);
+        let included: HashSet<SkinsetId> = SkinsetId::iter_all().collect();
+        let settings = SolverSettings { game_mode: GameMode::Aram, owned_only: true };
+
+        assert!(solve(&[ahri.clone()], &included, settings).is_empty());
+
+        ahri.owned_skins.insert(SkinId::from_skin_name("Spirit Blossom Ahri").unwrap());
+        let comps = solve(&[ahri], &included, settings);
+        assert_eq!(comps.len(), 1);
+        assert_eq!(comps[0].skinsets, vec![skinset("Spirit Blossom")]);
+    }
+
+    #[test]
+    fn achievable_skinsets_need_distinct_lanes() {
+        let mut ahri = player(&["Ahri"]);
+        ahri.champs[0].1 = BitFlags::from(Lane::Mid);
+        let mut evelynn = player(&["Evelynn"]);
+        evelynn.champs[0].1 = BitFlags::from(Lane::Jungle);
+        let kda = skinset("K/DA");
+
+        assert_eq!(players_with_champ_in(&[ahri.clone(), evelynn.clone()], kda), 2);
+        assert!(achievable_skinsets(&[ahri.clone(), evelynn.clone()], SolverSettings::default()).contains(&kda));
+
+        // Both in mid can't be queued on Summoner's Rift, but can in ARAM.
+        evelynn.champs[0].1 = BitFlags::from(Lane::Mid);
+        assert!(achievable_skinsets(&[ahri.clone(), evelynn.clone()], SolverSettings::default()).is_empty());
+
+        let aram = SolverSettings { game_mode: GameMode::Aram, owned_only: false };
+        assert!(achievable_skinsets(&[ahri, evelynn], aram).contains(&kda));
+    }
+
+    #[test]
+    fn by_skinset_lists_only_usable_options() {
+        let mut ahri = player(&["Ahri"]);
+        ahri.champs[0].1 = BitFlags::from(Lane::Mid);
+        let mut evelynn = player(&["Evelynn"]);
+        evelynn.champs[0].1 = Lane::Jungle | Lane::Mid;
+        let players = [ahri, evelynn];
+        let included: HashSet<SkinsetId> = HashSet::from([skinset("K/DA")]);
+
+        let by_skinset = solve_by_skinset(&players, &included, SolverSettings::default());
+        assert_eq!(by_skinset.len(), 1);
+        assert_eq!(by_skinset[0].comp_count, 1);
+
+        // Evelynn can't be played mid since Ahri has to be.
+        let evelynn_id = ChampId::from_champ_name("Evelynn").unwrap();
+        assert_eq!(by_skinset[0].players[1], vec![(evelynn_id, BitFlags::from(Lane::Jungle))]);
+
+        // The count matches the full comp list.
+        assert_eq!(solve(&players, &included, SolverSettings::default()).len(), by_skinset[0].comp_count);
+    }
+
+    #[test]
+    fn locks_are_hard_constraints() {
+        let mut jinx = player(&["Jinx", "Ahri"]);
+        let players = |jinx: &PlayerRecord| [jinx.clone(), player(&["Evelynn", "Lux"])];
+        let included: HashSet<SkinsetId> = SkinsetId::iter_all().collect();
+        let jinx_id = ChampId::from_champ_name("Jinx").unwrap();
+
+        jinx.lock = LockIn { champ: Some(jinx_id), lane: Some(Lane::Bot) };
+        let comps = solve(&players(&jinx), &included, SolverSettings::default());
+        assert!(!comps.is_empty());
+        assert!(comps.iter().all(|comp| comp.picks[0].champ == jinx_id && comp.picks[0].lane == Some(Lane::Bot)));
+
+        // A lane lock is ignored in ARAM, but the champ lock still applies.
+        let aram = SolverSettings { game_mode: GameMode::Aram, owned_only: false };
+        let comps = solve(&players(&jinx), &included, aram);
+        assert!(!comps.is_empty());
+        assert!(comps.iter().all(|comp| comp.picks[0].champ == jinx_id));
+
+        // Removing the locked champ drops the champ lock.
+        jinx.remove_champ(jinx_id);
+        assert_eq!(jinx.lock, LockIn { champ: None, lane: Some(Lane::Bot) });
+    }
+
+    #[test]
+    fn partial_matches_meet_the_threshold() {
+        let players = [player(&["Ahri"]), player(&["Evelynn"]), player(&["Garen"])];
+        let included: HashSet<SkinsetId> = HashSet::from([skinset("K/DA"), skinset("Demacia Vice")]);
+
+        let partial = partial_matches(&players, &included, SolverSettings::default(), 2);
+        assert_eq!(partial.len(), 1);
+        assert_eq!(partial[0].skinset, skinset("K/DA"));
+        assert_eq!(partial[0].matched_players(), 2);
+        assert!(partial[0].players[2].is_empty());
+
+        // Demacia Vice only has Garen, and comes after K/DA.
+        let partial = partial_matches(&players, &included, SolverSettings::default(), 1);
+        assert_eq!(partial.iter().map(|p| p.skinset).collect::<Vec<_>>(), vec![skinset("K/DA"), skinset("Demacia Vice")]);
+    }
+}
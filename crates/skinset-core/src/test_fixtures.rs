@@ -0,0 +1,31 @@
+//! Helpers for building test inputs by name, shared by the unit tests.
+
+use crate::{
+    constants::{ChampId, SkinsetId},
+    model::PlayerRecord,
+};
+use enumflags2::BitFlags;
+
+/// Look up a champ by its exact name.
+pub fn champ(name: &str) -> ChampId {
+    ChampId::from_champ_name(name).unwrap()
+}
+
+/// Look up a skinset by its exact name.
+pub fn skinset(name: &str) -> SkinsetId {
+    SkinsetId::from_skinset_name(name).unwrap()
+}
+
+/// Make a player with the given champs, each in its default lanes -- the way they'd be added in the app.
+pub fn player(champs: &[&str]) -> PlayerRecord {
+    let mut player = PlayerRecord::new();
+    champs.iter().for_each(|name| player.add_champ(champ(name)));
+    player
+}
+
+/// Make a player with the given champs, each playable in every lane, for tests that shouldn't depend on the lane data.
+pub fn player_in_any_lane(champs: &[&str]) -> PlayerRecord {
+    let mut player = PlayerRecord::new();
+    player.champs = champs.iter().map(|name| (champ(name), BitFlags::all())).collect();
+    player
+}
@@ -0,0 +1,312 @@
+//! Command-line skinset finder. Reads a roster file (the same format the web app imports and exports), resolves the
+//! comps and prints them as a table, JSON or CSV.
+//!
+//! ```text
+//! skinset-finder roster.json --game-mode aram --exclude Legacy --format csv --limit 20
+//! skinset-finder roster.json --query 'skinset:"Star Guardian" Venus:support champ:Lux lanes:any'
+//! ```
+
+use clap::{Parser, ValueEnum};
+use skinset_core::{
+    constants::SkinsetId,
+    export::ExportOptions,
+    model::{GameMode, SolverSettings},
+    query::{Query, QueryError},
+    roster::{RosterDocument, RosterFieldError, ValidatedRoster},
+    solver::{self, Comp, PartialMatch},
+};
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+mod output;
+
+/// The game modes that can be chosen on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum GameModeArg {
+    /// Summoner's Rift -- every player must be in a different lane.
+    Sr,
+
+    /// ARAM -- there are no lanes, so only the champs matter.
+    Aram,
+}
+
+impl From<GameModeArg> for GameMode {
+    fn from(game_mode: GameModeArg) -> Self {
+        match game_mode {
+            GameModeArg::Sr => GameMode::SummonersRift,
+            GameModeArg::Aram => GameMode::Aram,
+        }
+    }
+}
+
+/// The output formats.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// An aligned plain-text table.
+    #[default]
+    Table,
+
+    /// A pretty-printed JSON array.
+    Json,
+
+    /// Comma separated values, with a header row.
+    Csv,
+}
+
+/// Find League of Legends team comps that share skins from the same skinset.
+#[derive(Debug, Parser)]
+#[command(name = "skinset-finder", version)]
+struct Args {
+    /// The roster file to read, in the web app's JSON roster format.
+    roster: PathBuf,
+
+    /// The game mode to resolve comps for, overriding the roster's.
+    #[arg(short = 'm', long)]
+    game_mode: Option<GameModeArg>,
+
+    /// Start from every skinset instead of the roster's included skinsets.
+    #[arg(long)]
+    all_skinsets: bool,
+
+    /// A skinset to include in the search, on top of the roster's. Can be given more than once.
+    #[arg(short, long = "include", value_name = "SKINSET")]
+    include: Vec<String>,
+
+    /// A skinset to exclude from the search. Can be given more than once, and wins over --include.
+    #[arg(short = 'x', long = "exclude", value_name = "SKINSET")]
+    exclude: Vec<String>,
+
+    /// A search query, e.g. `skinset:"Star Guardian" Venus:support -champ:Teemo`. Applied on top of the other options.
+    #[arg(short, long)]
+    query: Option<String>,
+
+    /// List the skinsets at least this many players have a champ in, instead of full comps.
+    #[arg(short = 'p', long, value_name = "PLAYERS")]
+    min_players: Option<usize>,
+
+    /// Include the names of the skins each player would use.
+    #[arg(short, long)]
+    skins: bool,
+
+    /// The output format.
+    #[arg(short, long, value_enum, default_value_t)]
+    format: OutputFormat,
+
+    /// The most results to print.
+    #[arg(short = 'n', long)]
+    limit: Option<usize>,
+}
+
+/// Resolve skinset names from the command line, collecting every unknown name.
+fn parse_skinsets(names: &[String], flag: &str, errors: &mut Vec<String>) -> HashSet<SkinsetId> {
+    names
+        .iter()
+        .filter_map(|name| match name.parse::<SkinsetId>() {
+            Ok(skinset_id) => Some(skinset_id),
+            Err(err) => {
+                errors.push(format!("{flag}: {err}"));
+                None
+            }
+        })
+        .collect()
+}
+
+/// Format a query error with the query and carets under the offending text.
+fn query_error_message(input: &str, err: &QueryError) -> String {
+    let mut message: String =
+        format!("error: invalid query: {}\n  {input}\n  {}\n", err.message, err.caret_line(input));
+
+    if !err.suggestions.is_empty() {
+        message += &format!("  did you mean {}?\n", err.suggestions.join(", "));
+    }
+
+    message
+}
+
+/// Format the errors in a roster document, one per line under a heading naming the file.
+fn roster_error_message(path: &Path, errors: &[RosterFieldError]) -> String {
+    std::iter::once(format!("error: invalid roster {}:\n", path.display()))
+        .chain(errors.iter().map(|err| format!("  {err}\n")))
+        .collect()
+}
+
+/// Resolve and format the results for a roster document. On failure, the error is returned as the message to print.
+fn run(args: &Args, json: &str) -> Result<String, String> {
+    let roster: ValidatedRoster =
+        RosterDocument::parse(json).map_err(|errors| roster_error_message(&args.roster, &errors))?;
+
+    // Work out the skinsets to search, reporting every unknown name at once.
+    let mut errors: Vec<String> = Vec::new();
+    let include: HashSet<SkinsetId> = parse_skinsets(&args.include, "--include", &mut errors);
+    let exclude: HashSet<SkinsetId> = parse_skinsets(&args.exclude, "--exclude", &mut errors);
+
+    if !errors.is_empty() {
+        return Err(errors.iter().map(|err| format!("error: {err}\n")).collect());
+    }
+
+    let mut skinsets: HashSet<SkinsetId> =
+        if args.all_skinsets { SkinsetId::iter_all().collect() } else { roster.skinsets.clone() };
+    skinsets.extend(include);
+    skinsets.retain(|skinset_id| !exclude.contains(skinset_id));
+
+    let settings = SolverSettings {
+        game_mode: args.game_mode.map(GameMode::from).unwrap_or(roster.game_mode),
+        owned_only: roster.owned_only,
+    };
+
+    let query: Query = match args.query.as_deref().map(|input| (input, Query::parse(input, &roster.players))) {
+        None => Query::default(),
+        Some((_, Ok(query))) => query,
+        Some((input, Err(err))) => return Err(query_error_message(input, &err)),
+    };
+
+    let (players, skinsets, settings) = query.apply(&roster.players, &skinsets, settings);
+
+    Ok(match args.min_players {
+        Some(min_players) => {
+            let mut matches: Vec<PartialMatch> =
+                solver::partial_matches(&players, &skinsets, settings, min_players);
+            matches.truncate(args.limit.unwrap_or(usize::MAX));
+            output::partial_matches(&players, &matches, args.format)
+        }
+
+        // Stop solving once there are enough comps, rather than resolving them all to print a few.
+        None => {
+            let comps: Vec<Comp> =
+                solver::solve_limited(&players, &skinsets, settings, args.limit, |comp| query.matches(comp));
+            let options = ExportOptions { include_skin_names: args.skins, owned_only: settings.owned_only };
+            output::comps(&players, &comps, args.format, options)
+        }
+    })
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let json: String = match fs::read_to_string(&args.roster) {
+        Ok(json) => json,
+        Err(err) => {
+            eprintln!("error: could not read {}: {err}", args.roster.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(&args, &json) {
+        Ok(printed) => {
+            print!("{printed}");
+            ExitCode::SUCCESS
+        }
+
+        Err(message) => {
+            eprint!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run, Args};
+    use clap::Parser;
+
+    /// A roster with two players who can both play K/DA and Spirit Blossom champs, in more than one comp.
+    const ROSTER: &str = r#"{
+        "schema_version": 1,
+        "game_mode": "SummonersRift",
+        "players": [
+            {
+                "name": "Emma",
+                "champs": [{ "champ": "Ahri", "lanes": ["Mid"] }, { "champ": "Akali", "lanes": ["Mid"] }]
+            },
+            { "name": "Sam", "champs": [{ "champ": "Evelynn", "lanes": ["Jungle"] }] }
+        ],
+        "owned_only": false,
+        "included_skinsets": ["K/DA", "Spirit Blossom"]
+    }"#;
+
+    /// Run the CLI on [`ROSTER`] with the given options, as CSV so that each comp is one line.
+    fn run_with(options: &[&str]) -> Result<String, String> {
+        let args = ["skinset-finder", "roster.json", "--format", "csv"].iter().chain(options);
+        run(&Args::try_parse_from(args).unwrap(), ROSTER)
+    }
+
+    /// Get the comp rows of CSV output, without the header.
+    fn rows(printed: &str) -> Vec<&str> {
+        printed.lines().skip(1).collect()
+    }
+
+    #[test]
+    fn roster_skinsets_are_searched() {
+        let printed: String = run_with(&[]).unwrap();
+        assert!(printed.contains("K/DA"));
+        assert!(printed.contains("Spirit Blossom"));
+    }
+
+    #[test]
+    fn include_and_exclude_change_the_searched_skinsets() {
+        let printed: String = run_with(&["--exclude", "K/DA"]).unwrap();
+        assert!(!printed.contains("K/DA"));
+        assert!(printed.contains("Spirit Blossom"));
+
+        // Exclusion wins over inclusion.
+        let printed: String = run_with(&["--include", "K/DA", "--exclude", "K/DA"]).unwrap();
+        assert!(!printed.contains("K/DA"));
+
+        let printed: String = run_with(&["--exclude", "K/DA", "--exclude", "Spirit Blossom"]).unwrap();
+        assert!(rows(&printed).is_empty());
+    }
+
+    #[test]
+    fn unknown_skinsets_are_all_reported() {
+        let err: String = run_with(&["--include", "Nope", "--exclude", "Also Nope"]).unwrap_err();
+        assert_eq!(err.lines().count(), 2);
+        assert!(err.lines().next().unwrap().starts_with("error: --include: "));
+        assert!(err.lines().nth(1).unwrap().starts_with("error: --exclude: "));
+    }
+
+    #[test]
+    fn limit_caps_the_comps() {
+        assert!(rows(&run_with(&[]).unwrap()).len() > 1);
+        assert_eq!(rows(&run_with(&["--limit", "1"]).unwrap()).len(), 1);
+        assert!(rows(&run_with(&["--limit", "0"]).unwrap()).is_empty());
+    }
+
+    #[test]
+    fn query_filters_the_comps() {
+        let printed: String = run_with(&["--query", r#"skinset:"Spirit Blossom""#]).unwrap();
+        assert!(!rows(&printed).is_empty());
+        assert!(rows(&printed).iter().all(|row| row.contains("Spirit Blossom") && !row.contains("K/DA")));
+    }
+
+    #[test]
+    fn invalid_queries_point_at_the_error() {
+        let query = "skinset:Nope";
+        let err: String = run_with(&["--query", query]).unwrap_err();
+        let lines: Vec<&str> = err.lines().collect();
+
+        assert!(lines[0].starts_with("error: invalid query: "));
+        assert_eq!(lines[1], format!("  {query}"));
+        assert_eq!(lines[2].trim_end(), format!("  {}^^^^", " ".repeat("skinset:".len())));
+    }
+
+    #[test]
+    fn invalid_rosters_report_each_field() {
+        let roster = r#"{
+            "schema_version": 1,
+            "players": [{ "name": "Emma", "champs": [{ "champ": "Nobody", "lanes": ["Mid"] }] }],
+            "included_skinsets": ["Nope"]
+        }"#;
+
+        let args = Args::try_parse_from(["skinset-finder", "roster.json"]).unwrap();
+        let err: String = run(&args, roster).unwrap_err();
+        let lines: Vec<&str> = err.lines().collect();
+
+        assert_eq!(lines[0], "error: invalid roster roster.json:");
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1..].iter().all(|line| line.starts_with("  ")));
+    }
+}
@@ -0,0 +1,179 @@
+//! Formatting of results for the terminal.
+
+use crate::OutputFormat;
+use serde::Serialize;
+use skinset_core::{
+    export::{self, ExportFormat, ExportOptions},
+    model::PlayerRecord,
+    solver::{Comp, PartialMatch},
+};
+
+/// A partial match as it is printed in JSON.
+#[derive(Debug, Serialize)]
+struct PrintedPartialMatch {
+    skinset: &'static str,
+    matched_players: usize,
+    players: Vec<PrintedPlayerChamps>,
+}
+
+/// One player's champs in a partial match, as printed in JSON.
+#[derive(Debug, Serialize)]
+struct PrintedPlayerChamps {
+    player: String,
+    champs: Vec<&'static str>,
+}
+
+/// Get the display name of each player.
+fn player_names(players: &[PlayerRecord]) -> Vec<String> {
+    players.iter().enumerate().map(|(index, player)| player.display_name(index)).collect()
+}
+
+/// Render a header and rows as a plain-text table with padded columns.
+fn aligned_table(header: &[String], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|cell| cell.chars().count()).collect();
+
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |row: &[String]| -> String {
+        let cells: Vec<String> = row.iter().zip(&widths).map(|(cell, width)| format!("{cell:<width$}")).collect();
+        cells.join("  ").trim_end().to_owned() + "\n"
+    };
+
+    let rule: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+
+    std::iter::once(line(header))
+        .chain(std::iter::once(line(&rule)))
+        .chain(rows.iter().map(|row| line(row)))
+        .collect()
+}
+
+/// Render a header and rows as CSV.
+fn csv(header: &[String], rows: &[Vec<String>]) -> String {
+    std::iter::once(header)
+        .chain(rows.iter().map(Vec::as_slice))
+        .map(|row| row.iter().map(|field| export::csv_field(field)).collect::<Vec<String>>().join(",") + "\r\n")
+        .collect()
+}
+
+/// Format resolved comps.
+pub fn comps(players: &[PlayerRecord], comps: &[Comp], format: OutputFormat, options: ExportOptions) -> String {
+    match format {
+        OutputFormat::Json => export::export_comps(players, comps, ExportFormat::Json, options) + "\n",
+        OutputFormat::Csv => export::export_comps(players, comps, ExportFormat::Csv, options),
+        OutputFormat::Table => {
+            let (header, rows) = export::table(players, comps, options);
+            aligned_table(&header, &rows)
+        }
+    }
+}
+
+/// Format partial matches.
+pub fn partial_matches(players: &[PlayerRecord], matches: &[PartialMatch], format: OutputFormat) -> String {
+    let names: Vec<String> = player_names(players);
+
+    if format == OutputFormat::Json {
+        let printed: Vec<PrintedPartialMatch> = matches
+            .iter()
+            .map(|partial| PrintedPartialMatch {
+                skinset: partial.skinset.skinset_name(),
+                matched_players: partial.matched_players(),
+                players: partial
+                    .players
+                    .iter()
+                    .zip(&names)
+                    .map(|(champs, name)| PrintedPlayerChamps {
+                        player: name.clone(),
+                        champs: champs.iter().map(|champ_id| champ_id.champ_name()).collect(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        return serde_json::to_string_pretty(&printed).expect("partial matches always serialize") + "\n";
+    }
+
+    let header: Vec<String> = ["Skinset".to_owned(), "Players".to_owned()].into_iter().chain(names).collect();
+
+    let rows: Vec<Vec<String>> = matches
+        .iter()
+        .map(|partial| {
+            let champs = partial.players.iter().map(|champs| {
+                champs.iter().map(|champ_id| champ_id.champ_name()).collect::<Vec<&str>>().join("; ")
+            });
+
+            [partial.skinset.skinset_name().to_owned(), format!("{}/{}", partial.matched_players(), players.len())]
+                .into_iter()
+                .chain(champs)
+                .collect()
+        })
+        .collect();
+
+    match format {
+        OutputFormat::Csv => csv(&header, &rows),
+        _ => aligned_table(&header, &rows),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{aligned_table, csv, partial_matches};
+    use crate::OutputFormat;
+    use skinset_core::{
+        constants::{ChampId, SkinsetId},
+        model::PlayerRecord,
+        solver::PartialMatch,
+    };
+
+    /// Convert string literals into a row of owned strings.
+    fn row(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|cell| (*cell).to_owned()).collect()
+    }
+
+    #[test]
+    fn tables_pad_columns_to_the_widest_cell() {
+        let printed: String =
+            aligned_table(&row(&["Champ", "Lane"]), &[row(&["Vel'Koz", "Support"]), row(&["Ahri", ""])]);
+
+        assert_eq!(printed, "Champ    Lane\n-------  -------\nVel'Koz  Support\nAhri\n");
+    }
+
+    #[test]
+    fn csv_fields_are_quoted_when_needed() {
+        let printed: String = csv(&row(&["Champ", "Skinsets"]), &[row(&["Vel'Koz", "Arclight, \"Battlecast\""])]);
+
+        assert_eq!(printed, "Champ,Skinsets\r\nVel'Koz,\"Arclight, \"\"Battlecast\"\"\"\r\n");
+    }
+
+    #[test]
+    fn partial_matches_print_each_players_champs() {
+        let mut emma = PlayerRecord::new();
+        emma.name = Some("Emma".to_owned());
+        let players: Vec<PlayerRecord> = vec![emma, PlayerRecord::new()];
+
+        let ahri: ChampId = ChampId::from_champ_name("Ahri").unwrap();
+        let akali: ChampId = ChampId::from_champ_name("Akali").unwrap();
+        let matches = [PartialMatch {
+            skinset: SkinsetId::from_skinset_name("K/DA").unwrap(),
+            players: vec![vec![ahri, akali], Vec::new()],
+        }];
+
+        let table: String = partial_matches(&players, &matches, OutputFormat::Table);
+        assert_eq!(table.lines().next().unwrap(), "Skinset  Players  Emma         Player 2");
+        assert_eq!(table.lines().nth(2).unwrap(), "K/DA     1/2      Ahri; Akali");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&partial_matches(&players, &matches, OutputFormat::Json)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([{
+                "skinset": "K/DA",
+                "matched_players": 1,
+                "players": [{ "player": "Emma", "champs": ["Ahri", "Akali"] }, { "player": "Player 2", "champs": [] }]
+            }])
+        );
+    }
+}
@@ -0,0 +1,273 @@
+//! Routing and handling of API requests, independent of the HTTP server so every endpoint can be tested directly.
+//!
+//! Every response is a JSON object with a `data_version` field (see [`DATA_VERSION`]) so clients can tell when the
+//! champ and skinset data has changed under them. Errors have an `error` message, invalid rosters list every problem
+//! in `errors`, and unknown champ names come with `suggestions`. Names are matched ignoring case and punctuation.
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use skinset_core::{
+    constants::{ChampId, SkinsetId},
+    export::{self, ExportOptions, ExportedComp},
+    generated::DATA_VERSION,
+    model::SolverSettings,
+    roster::{RosterDocument, ValidatedRoster},
+    solver::{self, Comp},
+};
+
+/// A champ as it is listed by `GET /champions`.
+#[derive(Debug, Serialize)]
+struct ListedChamp {
+    name: &'static str,
+    lanes: Vec<String>,
+}
+
+/// A skinset as it is listed by `GET /skinsets`.
+#[derive(Debug, Serialize)]
+struct ListedSkinset {
+    name: &'static str,
+    champ_count: usize,
+    lanes: Vec<String>,
+    full_team: bool,
+    universes: Vec<&'static str>,
+}
+
+/// One of a champ's skinsets as it is listed by `GET /champions/{name}/skinsets`.
+#[derive(Debug, Serialize)]
+struct ChampSkinset {
+    name: &'static str,
+    skins: Vec<&'static str>,
+}
+
+/// An API response -- a status code and a JSON body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiResponse {
+    /// The HTTP status code.
+    pub status: u16,
+
+    /// The JSON body, which always has a `data_version` field.
+    pub body: Value,
+}
+
+impl ApiResponse {
+    /// Make a response, adding the data version to the body.
+    fn new(status: u16, mut body: Value) -> Self {
+        body["data_version"] = json!(DATA_VERSION);
+        Self { status, body }
+    }
+
+    /// Make a 200 response with a single field.
+    fn ok(field: &str, value: impl Serialize) -> Self {
+        Self::new(200, json!({ field: value }))
+    }
+
+    /// Make an error response with a message.
+    pub fn error(status: u16, message: impl Into<String>) -> Self {
+        Self::new(status, json!({ "error": message.into() }))
+    }
+}
+
+/// Decode `%XX` escapes in a URL path segment or query value. Returns [`None`] for malformed escapes or non-UTF-8.
+fn percent_decode(input: &str) -> Option<String> {
+    let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
+    let mut iter = input.bytes();
+
+    while let Some(byte) = iter.next() {
+        if byte == b'%' {
+            let hex: String = [iter.next()?, iter.next()?].iter().map(|byte| *byte as char).collect();
+            bytes.push(u8::from_str_radix(&hex, 16).ok()?);
+        } else {
+            bytes.push(byte);
+        }
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
+/// The options `POST /solve` takes in its query string.
+#[derive(Debug, Default)]
+struct SolveQuery {
+    /// The most comps to return.
+    limit: Option<usize>,
+
+    /// Whether to include the names of the skins each player would use.
+    skins: bool,
+}
+
+impl SolveQuery {
+    /// Parse the query string, without the leading `?`.
+    fn parse(query: &str) -> Result<Self, String> {
+        let mut parsed = Self::default();
+
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value: String = percent_decode(value).ok_or_else(|| format!("malformed query value for {key:?}"))?;
+
+            match key {
+                "limit" => parsed.limit = Some(value.parse().map_err(|_| format!("invalid limit {value:?}"))?),
+                "skins" => parsed.skins = matches!(value.as_str(), "" | "1" | "true"),
+                _ => return Err(format!("unknown query parameter {key:?}")),
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// `GET /champions` -- every champ and their default lanes.
+fn champions() -> ApiResponse {
+    let champs: Vec<ListedChamp> = ChampId::iter_all()
+        .map(|champ_id| ListedChamp {
+            name: champ_id.champ_name(),
+            lanes: champ_id.default_lanes().iter().map(|lane| lane.to_string()).collect(),
+        })
+        .collect();
+
+    ApiResponse::ok("champions", champs)
+}
+
+/// `GET /skinsets` -- every skinset, how many champs are in it, the lanes they cover, whether they can make a full team
+/// and the universes it's listed under.
+fn skinsets() -> ApiResponse {
+    let skinsets: Vec<ListedSkinset> = SkinsetId::iter_all()
+        .map(|skinset_id| ListedSkinset {
+            name: skinset_id.skinset_name(),
+            champ_count: skinset_id.champ_count(),
+            lanes: skinset_id.lane_coverage().iter().map(|lane| lane.to_string()).collect(),
+            full_team: skinset_id.has_full_team(),
+            universes: skinset_id.universes().map(|universe_id| universe_id.universe_name()).collect(),
+        })
+        .collect();
+
+    ApiResponse::ok("skinsets", skinsets)
+}
+
+/// `GET /champions/{name}/skinsets` -- the skinsets a champ is in, with their skins in each.
+fn champion_skinsets(champ_name: &str) -> ApiResponse {
+    let Some(champ_name) = percent_decode(champ_name) else {
+        return ApiResponse::error(400, format!("malformed champion name {champ_name:?}"));
+    };
+
+    let champ_id: ChampId = match champ_name.parse() {
+        Ok(champ_id) => champ_id,
+        Err(err) => return ApiResponse::new(404, json!({ "error": err.to_string(), "suggestions": err.suggestions })),
+    };
+
+    let skinsets: Vec<ChampSkinset> = champ_id
+        .skinsets()
+        .map(|skinset_id| ChampSkinset {
+            name: skinset_id.skinset_name(),
+            skins: champ_id.skins_in(skinset_id).map(|skin_id| skin_id.skin_name()).collect(),
+        })
+        .collect();
+
+    ApiResponse::new(200, json!({ "champion": champ_id.champ_name(), "skinsets": skinsets }))
+}
+
+/// `POST /solve` -- resolve the comps for a roster, in the same format the web app imports and exports.
+fn solve(query: &str, body: &str) -> ApiResponse {
+    let query: SolveQuery = match SolveQuery::parse(query) {
+        Ok(query) => query,
+        Err(err) => return ApiResponse::error(400, err),
+    };
+
+    let roster: ValidatedRoster = match RosterDocument::parse(body) {
+        Ok(roster) => roster,
+        Err(errors) => {
+            let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+            return ApiResponse::new(400, json!({ "error": "invalid roster", "errors": errors }));
+        }
+    };
+
+    let settings = SolverSettings { game_mode: roster.game_mode, owned_only: roster.owned_only };
+
+    // Solve for one more comp than the limit, just to tell whether there were more, without resolving them all.
+    let solve_limit: Option<usize> = query.limit.map(|limit| limit.saturating_add(1));
+    let mut comps: Vec<Comp> =
+        solver::solve_limited(&roster.players, &roster.skinsets, settings, solve_limit, |_| true);
+    let truncated: bool = query.limit.is_some_and(|limit| comps.len() > limit);
+    comps.truncate(query.limit.unwrap_or(usize::MAX));
+
+    let options = ExportOptions { include_skin_names: query.skins, owned_only: settings.owned_only };
+    let exported: Vec<ExportedComp> = export::exported_comps(&roster.players, &comps, options);
+
+    ApiResponse::new(200, json!({ "truncated": truncated, "comps": exported }))
+}
+
+/// Handle a request and produce the response.
+///
+/// # Arguments
+/// - `method` - The HTTP method, in upper case.
+/// - `url` - The request path, with any query string.
+/// - `body` - The request body, empty for requests without one.
+pub fn handle(method: &str, url: &str, body: &str) -> ApiResponse {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+    match (method, segments.as_slice()) {
+        ("GET", ["champions"]) => champions(),
+        ("GET", ["skinsets"]) => skinsets(),
+        ("GET", ["champions", champ_name, "skinsets"]) => champion_skinsets(champ_name),
+        ("POST", ["solve"]) => solve(query, body),
+        (_, ["champions"] | ["skinsets"] | ["champions", _, "skinsets"] | ["solve"]) => {
+            ApiResponse::error(405, format!("{method} is not allowed on {path}"))
+        }
+        _ => ApiResponse::error(404, format!("no endpoint at {path}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{handle, percent_decode};
+    use skinset_core::generated::DATA_VERSION;
+
+    #[test]
+    fn every_response_has_the_data_version() {
+        for (method, url) in [("GET", "/champions"), ("GET", "/skinsets"), ("GET", "/nope"), ("DELETE", "/solve")] {
+            let response = handle(method, url, "");
+            assert_eq!(response.body["data_version"], DATA_VERSION, "{method} {url}");
+        }
+
+        assert_eq!(handle("DELETE", "/solve", "").status, 405);
+        assert_eq!(handle("GET", "/nope", "").status, 404);
+    }
+
+    #[test]
+    fn champion_skinsets_decode_the_name() {
+        assert_eq!(percent_decode("Kai%27Sa").as_deref(), Some("Kai'Sa"));
+        assert_eq!(percent_decode("bad%2"), None);
+
+        let response = handle("GET", "/champions/kai%20sa/skinsets", "");
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body["champion"], "Kai'Sa");
+        assert!(response.body["skinsets"].as_array().is_some_and(|skinsets| !skinsets.is_empty()));
+
+        let response = handle("GET", "/champions/Jnx/skinsets", "");
+        assert_eq!(response.status, 404);
+        assert!(response.body["suggestions"].as_array().is_some_and(|names| names.contains(&"Jinx".into())));
+    }
+
+    #[test]
+    fn solve_reports_invalid_rosters() {
+        let roster = r#"{"schema_version": 1, "players": [{"champs": [{"champ": "Nobody"}]}], "included_skinsets": []}"#;
+        let response = handle("POST", "/solve", roster);
+        assert_eq!(response.status, 400);
+        assert!(response.body["errors"].as_array().is_some_and(|errors| !errors.is_empty()));
+
+        assert_eq!(handle("POST", "/solve?limit=many", "{}").status, 400);
+    }
+
+    #[test]
+    fn solve_accepts_the_largest_limit() {
+        let roster = r#"{
+            "schema_version": 1,
+            "players": [{"champs": [{"champ": "Ahri", "lanes": ["Mid"]}]}],
+            "included_skinsets": ["K/DA"]
+        }"#;
+
+        let response = handle("POST", &format!("/solve?limit={}", usize::MAX), roster);
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body["truncated"], false);
+        assert_eq!(response.body["comps"].as_array().map(Vec::len), Some(1));
+    }
+}
@@ -0,0 +1,47 @@
+//! Local HTTP JSON API for the skinset finder, so bots and spreadsheets can query the same data and solver as the web
+//! app.
+//!
+//! ```text
+//! skinset-server --addr 127.0.0.1:3000
+//! curl localhost:3000/champions/Ahri/skinsets
+//! curl -X POST --data @roster.json 'localhost:3000/solve?limit=10'
+//! ```
+//!
+//! Endpoints:
+//! - `GET /champions` -- every champ and their default lanes.
+//! - `GET /skinsets` -- every skinset, with its champ count, lane coverage and universes.
+//! - `GET /champions/{name}/skinsets` -- a champ's skinsets and their skins in each.
+//! - `POST /solve` -- the comps for a roster in the web app's JSON roster format. Takes `limit` and `skins` query
+//!   parameters, and says whether the comps were `truncated` by the limit.
+
+use clap::Parser;
+use std::{net::SocketAddr, process::ExitCode};
+use tiny_http::Server;
+
+mod api;
+mod server;
+
+/// Serve the skinset finder's data and solver over HTTP.
+#[derive(Debug, Parser)]
+#[command(name = "skinset-server", version)]
+struct Args {
+    /// The address to listen on.
+    #[arg(short, long, default_value = "127.0.0.1:3000")]
+    addr: SocketAddr,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let server: Server = match Server::http(args.addr) {
+        Ok(server) => server,
+        Err(err) => {
+            eprintln!("error: could not listen on {}: {err}", args.addr);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    eprintln!("listening on http://{}", args.addr);
+    server::serve(&server);
+    ExitCode::SUCCESS
+}
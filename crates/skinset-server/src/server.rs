@@ -0,0 +1,99 @@
+//! The HTTP side of the API -- reading requests off a [`Server`] and writing back the JSON responses from [`api`].
+
+use crate::api::{self, ApiResponse};
+use skinset_core::generated::DATA_VERSION;
+use std::io::Read;
+use tiny_http::{Header, Request, Response, Server};
+
+/// The most bytes of request body that are read. Rosters are a few kilobytes at most.
+const MAX_BODY_BYTES: u64 = 1 << 20;
+
+/// Handle one request, logging it to stderr.
+fn respond(mut request: Request) {
+    let mut body: String = String::new();
+    let response: ApiResponse = match request.as_reader().take(MAX_BODY_BYTES).read_to_string(&mut body) {
+        Ok(_) => api::handle(request.method().as_str(), request.url(), &body),
+        Err(err) => ApiResponse::error(400, format!("could not read the request body: {err}")),
+    };
+
+    eprintln!("{} {} -> {}", request.method(), request.url(), response.status);
+
+    let http_response = Response::from_string(response.body.to_string())
+        .with_status_code(response.status)
+        .with_header(Header::from_bytes("Content-Type", "application/json").expect("valid header"))
+        .with_header(Header::from_bytes("X-Data-Version", DATA_VERSION.to_string()).expect("valid header"));
+
+    if let Err(err) = request.respond(http_response) {
+        eprintln!("error: could not send response: {err}");
+    }
+}
+
+/// Serve requests one at a time until the server is shut down.
+pub fn serve(server: &Server) {
+    for request in server.incoming_requests() {
+        respond(request);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::serve;
+    use serde_json::Value;
+    use skinset_core::generated::DATA_VERSION;
+    use std::{
+        io::{Read, Write},
+        net::{SocketAddr, TcpStream},
+        sync::Arc,
+        thread,
+    };
+    use tiny_http::Server;
+
+    /// Send a raw request to the server and return the status code and parsed JSON body.
+    fn request(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, Value) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "{method} {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        let status: u16 = response.split(' ').nth(1).unwrap().parse().unwrap();
+        let (_, body) = response.split_once("\r\n\r\n").unwrap();
+        (status, serde_json::from_str(body).unwrap())
+    }
+
+    #[test]
+    fn solves_over_localhost() {
+        let server = Arc::new(Server::http("127.0.0.1:0").unwrap());
+        let addr: SocketAddr = server.server_addr().to_ip().unwrap();
+        thread::spawn({
+            let server = Arc::clone(&server);
+            move || serve(&server)
+        });
+
+        let (status, body) = request(addr, "GET", "/skinsets", "");
+        assert_eq!(status, 200);
+        assert_eq!(body["data_version"], DATA_VERSION);
+
+        let roster = r#"{
+            "schema_version": 1,
+            "players": [
+                {"champs": [{"champ": "Ahri", "lanes": ["Mid"]}]},
+                {"champs": [{"champ": "Evelynn", "lanes": ["Jungle", "Top"]}]}
+            ],
+            "included_skinsets": ["K/DA"]
+        }"#;
+        let (status, body) = request(addr, "POST", "/solve?limit=1&skins=true", roster);
+        assert_eq!(status, 200);
+        assert_eq!(body["comps"].as_array().map(Vec::len), Some(1));
+        assert_eq!(body["truncated"], true);
+        assert_eq!(body["comps"][0]["skinsets"][0], "K/DA");
+        assert_eq!(body["data_version"], DATA_VERSION);
+
+        server.unblock();
+    }
+}
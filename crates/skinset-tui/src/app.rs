@@ -0,0 +1,464 @@
+//! The terminal app's state and keyboard handling, kept apart from rendering so it can be tested without a terminal.
+
+use enumflags2::BitFlags;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use skinset_core::{
+    constants::{ChampId, Lane, SkinsetId},
+    model::{GameMode, PlayerRecord, SolverSettings, MAX_PLAYERS},
+    names,
+    roster::{RosterDocument, ValidatedRoster},
+    solver::{self, Comp},
+};
+use std::{collections::HashSet, fs, path::PathBuf};
+
+/// The most comps resolved for the results pane. Every change re-resolves, so this keeps the app responsive with big
+/// champ pools -- nobody scrolls through more than this anyway.
+pub const MAX_RESULTS: usize = 1000;
+
+/// The panes that can have focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pane {
+    /// The players and their champ pools.
+    Players,
+
+    /// The skinset checklist.
+    Skinsets,
+
+    /// The live results.
+    Results,
+}
+
+impl Pane {
+    /// The next pane, cycling back to the first.
+    fn next(self) -> Self {
+        match self {
+            Pane::Players => Pane::Skinsets,
+            Pane::Skinsets => Pane::Results,
+            Pane::Results => Pane::Players,
+        }
+    }
+
+    /// The previous pane, cycling back to the last.
+    fn previous(self) -> Self {
+        match self {
+            Pane::Players => Pane::Results,
+            Pane::Skinsets => Pane::Players,
+            Pane::Results => Pane::Skinsets,
+        }
+    }
+}
+
+/// What a line of text being typed at the prompt is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptKind {
+    /// A champ to add to the selected player.
+    AddChamp,
+
+    /// A new name for the selected player.
+    RenamePlayer,
+
+    /// The file to save the roster to.
+    SavePath,
+}
+
+impl PromptKind {
+    /// The label shown before the prompt.
+    pub const fn label(self) -> &'static str {
+        match self {
+            PromptKind::AddChamp => "Add champ",
+            PromptKind::RenamePlayer => "Player name",
+            PromptKind::SavePath => "Save roster to",
+        }
+    }
+}
+
+/// A row in the players pane -- either a player's header or one of their champs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerRow {
+    /// The player at this index.
+    Player(usize),
+
+    /// The champ at `champ_index` in the pool of the player at `player_index`.
+    Champ { player_index: usize, champ_index: usize },
+}
+
+/// The whole terminal app state.
+#[derive(Debug)]
+pub struct App {
+    /// The game mode being played.
+    pub game_mode: GameMode,
+
+    /// Whether the search only uses skins the players own.
+    pub owned_only: bool,
+
+    /// The player records.
+    pub players: Vec<PlayerRecord>,
+
+    /// The skinsets included in the search.
+    pub skinsets: HashSet<SkinsetId>,
+
+    /// The resolved comps, kept up to date with every change. At most [`MAX_RESULTS`].
+    pub comps: Vec<Comp>,
+
+    /// The pane with focus.
+    pub focus: Pane,
+
+    /// The selected row in the players pane.
+    pub player_cursor: usize,
+
+    /// The selected skinset in the skinset checklist, by position in skinset order.
+    pub skinset_cursor: usize,
+
+    /// The first comp shown in the results pane.
+    pub results_offset: usize,
+
+    /// The prompt being typed into, and what's been typed so far.
+    pub prompt: Option<(PromptKind, String)>,
+
+    /// A message for the status line, such as the result of saving.
+    pub status: String,
+
+    /// The roster file loaded from or last saved to.
+    pub roster_path: Option<PathBuf>,
+
+    /// Set when the app should exit.
+    pub should_quit: bool,
+}
+
+impl App {
+    /// Create an app with one empty player and the default skinsets.
+    pub fn new() -> Self {
+        let mut app = Self {
+            game_mode: GameMode::default(),
+            owned_only: false,
+            players: vec![PlayerRecord::new()],
+            skinsets: SkinsetId::generate_default_included_skinsets(),
+            comps: Vec::new(),
+            focus: Pane::Players,
+            player_cursor: 0,
+            skinset_cursor: 0,
+            results_offset: 0,
+            prompt: None,
+            status: "Press ? for help.".to_owned(),
+            roster_path: None,
+            should_quit: false,
+        };
+
+        app.resolve();
+        app
+    }
+
+    /// Create an app from a roster file.
+    pub fn load(path: PathBuf) -> Result<Self, String> {
+        let json: String =
+            fs::read_to_string(&path).map_err(|err| format!("could not read {}: {err}", path.display()))?;
+
+        let roster: ValidatedRoster = RosterDocument::parse(&json).map_err(|errors| {
+            let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+            format!("invalid roster {}:\n  {}", path.display(), errors.join("\n  "))
+        })?;
+
+        let mut app = Self::new();
+        app.game_mode = roster.game_mode;
+        app.owned_only = roster.owned_only;
+        app.players = roster.players;
+        app.skinsets = roster.skinsets;
+        app.status = format!("Loaded {}.", path.display());
+        app.roster_path = Some(path);
+        app.resolve();
+        Ok(app)
+    }
+
+    /// The settings comps are resolved with.
+    pub fn settings(&self) -> SolverSettings {
+        SolverSettings { game_mode: self.game_mode, owned_only: self.owned_only }
+    }
+
+    /// Re-resolve the comps after a change.
+    fn resolve(&mut self) {
+        self.comps = solver::solve_limited(&self.players, &self.skinsets, self.settings(), Some(MAX_RESULTS), |_| true);
+        self.results_offset = self.results_offset.min(self.comps.len().saturating_sub(1));
+    }
+
+    /// Every row in the players pane, in order.
+    pub fn player_rows(&self) -> Vec<PlayerRow> {
+        self.players
+            .iter()
+            .enumerate()
+            .flat_map(|(player_index, player)| {
+                std::iter::once(PlayerRow::Player(player_index)).chain(
+                    (0..player.champs.len()).map(move |champ_index| PlayerRow::Champ { player_index, champ_index }),
+                )
+            })
+            .collect()
+    }
+
+    /// The selected row in the players pane.
+    pub fn selected_player_row(&self) -> PlayerRow {
+        let rows: Vec<PlayerRow> = self.player_rows();
+        rows[self.player_cursor.min(rows.len() - 1)]
+    }
+
+    /// The index of the player the selected row belongs to.
+    fn selected_player_index(&self) -> usize {
+        match self.selected_player_row() {
+            PlayerRow::Player(player_index) | PlayerRow::Champ { player_index, .. } => player_index,
+        }
+    }
+
+    /// Move the cursor of the focused pane by `delta` rows.
+    fn move_cursor(&mut self, delta: isize) {
+        let (cursor, len) = match self.focus {
+            Pane::Players => (&mut self.player_cursor, self.players.iter().map(|p| p.champs.len() + 1).sum()),
+            Pane::Skinsets => (&mut self.skinset_cursor, SkinsetId::iter_all().len()),
+            Pane::Results => (&mut self.results_offset, self.comps.len()),
+        };
+
+        *cursor = cursor.saturating_add_signed(delta).min(len.saturating_sub(1));
+    }
+
+    /// Handle a key press.
+    pub fn on_key(&mut self, key: KeyEvent) {
+        if self.prompt.is_some() {
+            self.on_prompt_key(key);
+            return;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => self.should_quit = true,
+            KeyCode::Tab => self.focus = self.focus.next(),
+            KeyCode::BackTab => self.focus = self.focus.previous(),
+            KeyCode::Up | KeyCode::Char('k') => self.move_cursor(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_cursor(1),
+            KeyCode::PageUp => self.move_cursor(-10),
+            KeyCode::PageDown => self.move_cursor(10),
+            KeyCode::Char('?') => {
+                self.status = "Tab: switch pane | n: new player | a: add champ | r: rename | d: delete | 1-5: toggle \
+                    lane | space: toggle skinset | m: game mode | o: owned only | s: save | q: quit"
+                    .to_owned();
+            }
+            KeyCode::Char('m') => {
+                self.game_mode = match self.game_mode {
+                    GameMode::SummonersRift => GameMode::Aram,
+                    GameMode::Aram => GameMode::SummonersRift,
+                };
+                self.resolve();
+            }
+            KeyCode::Char('o') => {
+                self.owned_only = !self.owned_only;
+                self.resolve();
+            }
+            KeyCode::Char('s') => {
+                let path: String = self.roster_path.as_ref().map(|path| path.display().to_string()).unwrap_or_default();
+                self.prompt = Some((PromptKind::SavePath, path));
+            }
+            _ => match self.focus {
+                Pane::Players => self.on_players_key(key),
+                Pane::Skinsets => self.on_skinsets_key(key),
+                Pane::Results => {}
+            },
+        }
+    }
+
+    /// Handle a key press in the players pane.
+    fn on_players_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('n') if self.players.len() < MAX_PLAYERS => {
+                self.players.push(PlayerRecord::new());
+                self.player_cursor = self.player_rows().len() - 1;
+                self.resolve();
+            }
+            KeyCode::Char('a') => self.prompt = Some((PromptKind::AddChamp, String::new())),
+            KeyCode::Char('r') => {
+                let name: String = self.players[self.selected_player_index()].name.clone().unwrap_or_default();
+                self.prompt = Some((PromptKind::RenamePlayer, name));
+            }
+            KeyCode::Char('d') | KeyCode::Delete => {
+                match self.selected_player_row() {
+                    PlayerRow::Player(player_index) if self.players.len() > 1 => {
+                        self.players.remove(player_index);
+                    }
+                    PlayerRow::Player(_) => self.status = "Can't remove the last player.".to_owned(),
+                    PlayerRow::Champ { player_index, champ_index } => {
+                        let champ_id: ChampId = self.players[player_index].champs[champ_index].0;
+                        self.players[player_index].remove_champ(champ_id);
+                    }
+                }
+
+                self.player_cursor = self.player_cursor.min(self.player_rows().len() - 1);
+                self.resolve();
+            }
+            KeyCode::Char(digit @ '1'..='5') => {
+                if let PlayerRow::Champ { player_index, champ_index } = self.selected_player_row() {
+                    let lane_index: usize = digit as usize - '1' as usize;
+                    let lane: Lane = BitFlags::<Lane>::all().iter().nth(lane_index).expect("there are five lanes");
+                    self.players[player_index].champs[champ_index].1.toggle(lane);
+                    self.resolve();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle a key press in the skinset checklist.
+    fn on_skinsets_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char(' ') | KeyCode::Enter => {
+                if let Some(skinset_id) = SkinsetId::iter_all().nth(self.skinset_cursor) {
+                    if !self.skinsets.remove(&skinset_id) {
+                        self.skinsets.insert(skinset_id);
+                    }
+                    self.resolve();
+                }
+            }
+            KeyCode::Char('A') => {
+                self.skinsets = SkinsetId::iter_all().collect();
+                self.resolve();
+            }
+            KeyCode::Char('N') => {
+                self.skinsets.clear();
+                self.resolve();
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle a key press while a prompt is open.
+    fn on_prompt_key(&mut self, key: KeyEvent) {
+        let Some((kind, input)) = self.prompt.as_mut() else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => self.prompt = None,
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) => input.push(c),
+            KeyCode::Enter => {
+                let (kind, input) = (*kind, input.trim().to_owned());
+                self.prompt = None;
+                self.submit_prompt(kind, input);
+            }
+            _ => {}
+        }
+    }
+
+    /// Act on a submitted prompt.
+    fn submit_prompt(&mut self, kind: PromptKind, input: String) {
+        let player_index: usize = self.selected_player_index();
+
+        match kind {
+            PromptKind::AddChamp => match find_champ(&input) {
+                Some(champ_id) => {
+                    self.players[player_index].add_champ(champ_id);
+                    self.status = format!("Added {}.", champ_id.champ_name());
+                    self.resolve();
+                }
+                None => {
+                    let suggestions: Vec<&str> =
+                        ChampId::suggest(&input, 3).into_iter().map(ChampId::champ_name).collect();
+                    self.status = if suggestions.is_empty() {
+                        format!("No champ matches {input:?}.")
+                    } else {
+                        format!("No champ matches {input:?} -- did you mean {}?", suggestions.join(", "))
+                    };
+                }
+            },
+
+            PromptKind::RenamePlayer => self.players[player_index].name = (!input.is_empty()).then_some(input),
+
+            PromptKind::SavePath if input.is_empty() => self.status = "Not saved -- no file given.".to_owned(),
+
+            PromptKind::SavePath => {
+                let document: RosterDocument =
+                    RosterDocument::from_session(self.game_mode, self.owned_only, &self.players, &self.skinsets);
+
+                match fs::write(&input, document.to_json()) {
+                    Ok(()) => {
+                        self.status = format!("Saved {input}.");
+                        self.roster_path = Some(PathBuf::from(input));
+                    }
+                    Err(err) => self.status = format!("Could not save {input}: {err}"),
+                }
+            }
+        }
+    }
+}
+
+/// Find a champ by name, ignoring case and punctuation, or by a prefix that only one champ has.
+fn find_champ(input: &str) -> Option<ChampId> {
+    if let Some(champ_id) = ChampId::from_name(input) {
+        return Some(champ_id);
+    }
+
+    let input: String = names::normalize_name(input);
+    let mut matches =
+        ChampId::iter_all().filter(|champ_id| names::normalize_name(champ_id.champ_name()).starts_with(&input));
+
+    match (matches.next(), matches.next()) {
+        (Some(champ_id), None) if !input.is_empty() => Some(champ_id),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_champ, App, Pane, PlayerRow};
+    use ratatui::crossterm::event::{KeyCode, KeyEvent};
+    use skinset_core::constants::{ChampId, Lane, SkinsetId};
+
+    fn press(app: &mut App, keys: &str) {
+        keys.chars().for_each(|c| app.on_key(KeyEvent::from(KeyCode::Char(c))));
+    }
+
+    fn enter(app: &mut App) {
+        app.on_key(KeyEvent::from(KeyCode::Enter));
+    }
+
+    #[test]
+    fn champs_can_be_added_and_lanes_toggled() {
+        let mut app = App::new();
+        press(&mut app, "a");
+        press(&mut app, "kai'");
+        enter(&mut app);
+
+        let kaisa = ChampId::from_champ_name("Kai'Sa").unwrap();
+        assert_eq!(app.players[0].champs[0].0, kaisa);
+
+        // Select the champ and toggle top lane on.
+        app.on_key(KeyEvent::from(KeyCode::Down));
+        assert_eq!(app.selected_player_row(), PlayerRow::Champ { player_index: 0, champ_index: 0 });
+        let had_top: bool = app.players[0].champs[0].1.contains(Lane::Top);
+        press(&mut app, "1");
+        assert_ne!(app.players[0].champs[0].1.contains(Lane::Top), had_top);
+    }
+
+    #[test]
+    fn results_follow_the_skinset_checklist() {
+        let mut app = App::new();
+        app.players[0].add_champ(ChampId::from_champ_name("Ahri").unwrap());
+        press(&mut app, "n");
+        app.players[1].add_champ(ChampId::from_champ_name("Evelynn").unwrap());
+        press(&mut app, "o");
+        press(&mut app, "o");
+        assert!(!app.comps.is_empty());
+
+        app.focus = Pane::Skinsets;
+        press(&mut app, "N");
+        assert!(app.skinsets.is_empty() && app.comps.is_empty());
+
+        press(&mut app, "A");
+        assert_eq!(app.skinsets.len(), SkinsetId::iter_all().len());
+        assert!(!app.comps.is_empty());
+    }
+
+    #[test]
+    fn champ_names_match_loosely() {
+        assert_eq!(find_champ("ahri"), ChampId::from_champ_name("Ahri"));
+        assert_eq!(find_champ("vel"), ChampId::from_champ_name("Vel'Koz"));
+        assert_eq!(find_champ("chogath"), ChampId::from_champ_name("Cho'Gath"));
+        assert_eq!(find_champ("a"), None);
+    }
+}
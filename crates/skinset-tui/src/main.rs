@@ -0,0 +1,53 @@
+//! Terminal frontend for the skinset finder. Edit players and champ pools, tick skinsets and watch the comps update,
+//! loading and saving the same roster files as the web app and the command-line tool.
+//!
+//! ```text
+//! skinset-tui [roster.json]
+//! ```
+
+use app::App;
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+use std::{io, path::PathBuf, process::ExitCode};
+
+mod app;
+mod ui;
+
+/// Draw and handle key presses until the user quits.
+fn run(terminal: &mut ratatui::DefaultTerminal, app: &mut App) -> io::Result<()> {
+    while !app.should_quit {
+        terminal.draw(|frame| ui::draw(frame, app))?;
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press {
+                app.on_key(key);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let mut app: App = match std::env::args_os().nth(1).map(PathBuf::from) {
+        Some(path) => match App::load(path) {
+            Ok(app) => app,
+            Err(err) => {
+                eprintln!("error: {err}");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => App::new(),
+    };
+
+    let mut terminal = ratatui::init();
+    let result: io::Result<()> = run(&mut terminal, &mut app);
+    ratatui::restore();
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
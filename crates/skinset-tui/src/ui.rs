@@ -0,0 +1,143 @@
+//! Rendering of the terminal app.
+
+use crate::app::{App, Pane, PlayerRow, MAX_RESULTS};
+use enumflags2::BitFlags;
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+use skinset_core::constants::{Lane, SkinsetId};
+
+/// A bordered block with a title, highlighted when its pane has focus.
+fn pane_block(title: String, focused: bool) -> Block<'static> {
+    let block = Block::bordered().title(title);
+    if focused { block.border_style(Style::new().fg(Color::Yellow)) } else { block }
+}
+
+/// The style of the selected row in a focused pane.
+fn highlight_style() -> Style {
+    Style::new().add_modifier(Modifier::REVERSED)
+}
+
+/// A champ's lanes as a fixed-width strip of lane initials, with lanes the champ isn't played in dimmed.
+fn lane_strip(lanes: BitFlags<Lane>) -> Vec<Span<'static>> {
+    BitFlags::<Lane>::all()
+        .iter()
+        .map(|lane| {
+            let initial: String = lane.to_string().chars().next().unwrap_or('?').to_string();
+            if lanes.contains(lane) { Span::from(initial).bold().green() } else { Span::from(initial).dark_gray() }
+        })
+        .collect()
+}
+
+/// Draw the whole app.
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [header, body, footer] =
+        Layout::vertical([Constraint::Length(1), Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+    let [players, skinsets, results] =
+        Layout::horizontal([Constraint::Percentage(30), Constraint::Percentage(25), Constraint::Percentage(45)])
+            .areas(body);
+
+    let settings = format!(
+        " League Skinset Finder | {} | {} ",
+        app.game_mode,
+        if app.owned_only { "owned skins only" } else { "all skins" }
+    );
+    frame.render_widget(Paragraph::new(settings).bold(), header);
+
+    draw_players(frame, app, players);
+    draw_skinsets(frame, app, skinsets);
+    draw_results(frame, app, results);
+
+    let footer_line: Line = match &app.prompt {
+        Some((kind, input)) => {
+            Line::from(vec![format!("{}: ", kind.label()).bold(), Span::from(input.clone()), "_".slow_blink()])
+        }
+        None => Line::from(app.status.clone()),
+    };
+    frame.render_widget(Paragraph::new(footer_line), footer);
+}
+
+/// Draw the players and their champ pools.
+fn draw_players(frame: &mut Frame, app: &App, area: Rect) {
+    let items: Vec<ListItem> = app
+        .player_rows()
+        .into_iter()
+        .map(|row| match row {
+            PlayerRow::Player(player_index) => {
+                let player = &app.players[player_index];
+                ListItem::new(Line::from(vec![
+                    Span::from(player.display_name(player_index)).bold(),
+                    format!(" ({} champs)", player.champs.len()).dark_gray(),
+                ]))
+            }
+            PlayerRow::Champ { player_index, champ_index } => {
+                let (champ_id, lanes) = app.players[player_index].champs[champ_index];
+                let mut spans: Vec<Span> = vec![Span::from("  ")];
+                spans.extend(lane_strip(lanes));
+                spans.push(Span::from(format!(" {}", champ_id.champ_name())));
+                ListItem::new(Line::from(spans))
+            }
+        })
+        .collect();
+
+    let focused: bool = app.focus == Pane::Players;
+    let list = List::new(items).block(pane_block(" Players ".to_owned(), focused)).highlight_style(highlight_style());
+    let mut state = ListState::default().with_selected(focused.then_some(app.player_cursor));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+/// Draw the skinset checklist.
+fn draw_skinsets(frame: &mut Frame, app: &App, area: Rect) {
+    let items: Vec<ListItem> = SkinsetId::iter_all()
+        .map(|skinset_id| {
+            let check: &str = if app.skinsets.contains(&skinset_id) { "[x] " } else { "[ ] " };
+            ListItem::new(format!("{check}{}", skinset_id.skinset_name()))
+        })
+        .collect();
+
+    let focused: bool = app.focus == Pane::Skinsets;
+    let title = format!(" Skinsets ({}/{}) ", app.skinsets.len(), SkinsetId::iter_all().len());
+    let list = List::new(items).block(pane_block(title, focused)).highlight_style(highlight_style());
+    let mut state = ListState::default().with_selected(Some(app.skinset_cursor));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+/// Draw the live results, one comp per line.
+fn draw_results(frame: &mut Frame, app: &App, area: Rect) {
+    let lines: Vec<Line> = app
+        .comps
+        .iter()
+        .skip(app.results_offset)
+        .take(area.height as usize)
+        .map(|comp| {
+            let picks: Vec<String> = comp
+                .picks
+                .iter()
+                .map(|pick| match pick.lane {
+                    Some(lane) => format!("{} ({lane})", pick.champ.champ_name()),
+                    None => pick.champ.champ_name().to_owned(),
+                })
+                .collect();
+            let skinsets: Vec<&str> = comp.skinsets.iter().map(|skinset_id| skinset_id.skinset_name()).collect();
+
+            Line::from(vec![Span::from(picks.join(", ")), format!(" -- {}", skinsets.join(", ")).cyan()])
+        })
+        .collect();
+
+    let more: &str = if app.comps.len() >= MAX_RESULTS { "+" } else { "" };
+    let title = format!(" Results ({}{more}) ", app.comps.len());
+    let paragraph = if lines.is_empty() {
+        Paragraph::new("No comps -- add champs to every player, or include more skinsets.".dark_gray())
+    } else {
+        Paragraph::new(lines)
+    };
+
+    frame.render_widget(
+        paragraph.wrap(Wrap { trim: false }).block(pane_block(title, app.focus == Pane::Results)),
+        area,
+    );
+}
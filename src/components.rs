@@ -2,9 +2,27 @@
 
 pub mod app;
 mod button;
-// mod checkbox;
+mod champion_page;
+mod checkbox;
+mod comp_roller;
+mod coverage_heatmap;
+mod diagnostics_panel;
+mod download;
+mod draft_panel;
+mod export_menu;
+mod game_mode_select;
 mod link;
-// mod player;
-// // mod results_table;
+mod player;
+mod query_search;
+mod recommendations;
+mod result_filters;
+mod results;
+mod results_table;
+mod roster_file;
+mod share_link;
 mod skinset_list;
+mod skinset_page;
+mod skinset_presets;
+mod skinset_results;
+mod undo_redo;
 
@@ -3,46 +3,273 @@
 
 use std::collections::HashSet;
 
-use crate::{constants::SkinsetId, model::PlayerRecord};
-use leptos::{component, create_rw_signal, view, IntoView, RwSignal};
+use crate::{
+    constants::SkinsetId,
+    draft::Draft,
+    model::{GameMode, PlayerRecord, SolverSettings, MAX_PLAYERS},
+    route::Route,
+    share,
+    storage::{self, StoredState},
+};
+use icondata::BsPersonAdd;
+use leptos::{
+    batch, component, create_effect, create_memo, create_rw_signal, ev, leptos_dom::helpers::TimeoutHandle,
+    set_timeout_with_handle, store_value, view, window, window_event_listener, CollectView, IntoView, Memo, RwSignal,
+    Signal, SignalGet, SignalSet, SignalUpdate, SignalWith, StoredValue,
+};
+use leptos_icons::Icon;
+use crate::components::button::Button;
+use crate::components::champion_page::ChampionPage;
+use crate::components::checkbox::Checkbox;
+use crate::components::coverage_heatmap::CoverageHeatmap;
+use crate::components::draft_panel::DraftPanel;
+use crate::components::game_mode_select::GameModeSelect;
 use crate::components::link::Link;
+use crate::components::player::Player;
+use crate::components::recommendations::Recommendations;
+use crate::components::results::Results;
+use crate::components::roster_file::RosterFile;
+use crate::components::share_link::ShareLink;
+use crate::components::undo_redo::UndoRedo;
 use crate::components::skinset_list::SkinsetList;
+use crate::components::skinset_page::SkinsetPage;
 
 #[component]
 pub fn App() -> impl IntoView {
+    // Restore any app state saved in local storage from a previous visit. 
+    let stored_state: Option<StoredState> = storage::load_state();
+    // Split the stored state into settings, players and skinsets, falling back to the defaults if there was nothing
+    // stored.
+    let (mut initial_settings, mut initial_players, mut initial_skinsets) = match stored_state {
+        Some(state) => {
+            let settings = SolverSettings { game_mode: state.game_mode, owned_only: state.owned_only };
+            let skinsets: HashSet<SkinsetId> = state.included_skinsets();
+            (settings, state.players, skinsets)
+        }
+        None => (SolverSettings::default(), vec![PlayerRecord::new()], SkinsetId::generate_default_included_skinsets()),
+    };
+
+    // A roster in a share link takes priority over anything stored.
+    if let Some(shared_state) = share::take_shared_state() {
+        (initial_players, initial_skinsets, initial_settings) = shared_state.into_roster();
+    }
+
+    // Create state and read/write for the game mode being played.
+    let game_mode_rw_signal: RwSignal<GameMode> = create_rw_signal(initial_settings.game_mode);
+    // Create state and read/write for whether only owned skins are used.
+    let owned_only_rw_signal: RwSignal<bool> = create_rw_signal(initial_settings.owned_only);
     // Create player records state and signals. 
-    let players_rw_signal: RwSignal<Vec<PlayerRecord>> = create_rw_signal(vec![PlayerRecord::new()]);
+    let players_rw_signal: RwSignal<Vec<PlayerRecord>> = create_rw_signal(initial_players);
     // Create state and read/write for the set of skinsets being considered.
-    let skinsets_rw_signal: RwSignal<HashSet<SkinsetId>> = create_rw_signal(SkinsetId::generate_default_included_skinsets());
+    let skinsets_rw_signal: RwSignal<HashSet<SkinsetId>> = create_rw_signal(initial_skinsets);
+    // Create state and read/write for the champ select draft. This only matters for one game, so it isn't saved.
+    let draft_rw_signal: RwSignal<Draft> = create_rw_signal(Draft::default());
+
+    // Set when the app is reset, so that the defaults it resets to aren't saved straight back over the cleared storage.
+    let skip_next_save: StoredValue<bool> = store_value(false);
+
+    // Save the app state whenever it changes. Debounce the save by cancelling the timeout scheduled by the previous
+    // run of this effect, so that rapid edits only cause one write to local storage.
+    create_effect(move |previous_timeout: Option<Option<TimeoutHandle>>| {
+        if let Some(Some(handle)) = previous_timeout {
+            handle.clear();
+        }
+
+        let state = StoredState::new(
+            game_mode_rw_signal.get(),
+            owned_only_rw_signal.get(),
+            players_rw_signal.get(),
+            &skinsets_rw_signal.get(),
+        );
+
+        if skip_next_save.get_value() {
+            skip_next_save.set_value(false);
+            return None;
+        }
+
+        set_timeout_with_handle(move || storage::save_state(&state), storage::SAVE_DEBOUNCE).ok()
+    });
+
+    // Closure to reset the app state to the defaults and forget anything stored. 
+    let reset_to_defaults = move |_| {
+        log::debug!("Resetting app state to defaults");
+        skip_next_save.set_value(true);
+        batch(|| {
+            game_mode_rw_signal.set(GameMode::default());
+            owned_only_rw_signal.set(false);
+            players_rw_signal.set(vec![PlayerRecord::new()]);
+            skinsets_rw_signal.set(SkinsetId::generate_default_included_skinsets());
+            draft_rw_signal.set(Draft::default());
+        });
+        storage::clear_state();
+    };
+
+    // Derive the settings used to resolve comps.
+    let solver_settings: Signal<SolverSettings> = Signal::derive(move || SolverSettings {
+        game_mode: game_mode_rw_signal.get(),
+        owned_only: owned_only_rw_signal.get(),
+    });
+
+    // Memoize the number of players, so the player cards are only rebuilt when players are added or removed.
+    let player_count: Memo<usize> = create_memo(move |_| players_rw_signal.with(Vec::len));
+
+    // Track the page being shown, following the URL fragment as links are followed and the browser navigates back and
+    // forward. This is read after any share link has been taken out of the fragment.
+    let route: RwSignal<Route> = create_rw_signal(Route::current());
+    let _ = window_event_listener(ev::hashchange, move |_| {
+        // A share link opened in a tab that's already open loads its roster, just like opening it in a new tab.
+        if let Some(shared_state) = share::take_shared_state() {
+            let (players, skinsets, settings) = shared_state.into_roster();
+
+            batch(|| {
+                players_rw_signal.set(players);
+                skinsets_rw_signal.set(skinsets);
+                game_mode_rw_signal.set(settings.game_mode);
+                owned_only_rw_signal.set(settings.owned_only);
+            });
+        }
+
+        route.set(Route::current());
+        window().scroll_to_with_x_and_y(0.0, 0.0);
+    });
+
+    // The browse page for the current route, if it isn't the finder.
+    let browse_page = move || match route.get() {
+        Route::Finder => None,
+        Route::Champion(champ_id) => Some(view! { <ChampionPage champ_id=champ_id /> }.into_view()),
+        Route::Skinset(skinset_id) => Some(view! { <SkinsetPage skinset_id=skinset_id /> }.into_view()),
+        Route::NotFound(err) => Some(view! {
+            <div class="card bg-light text-dark my-2">
+                <div class="card-body">
+                    <Link href="#/" text="Back to the finder".to_owned() />
+                    <p class="h3 mt-2"> "Page not found" </p>
+                    <p> {err.to_string()} </p>
+                </div>
+            </div>
+        }.into_view()),
+    };
 
     view! {
         // Add a margin an padding to the bottom to force scroll bar to appear slightly sooner
         <div class="pb-5 mb-5">
-            <div class="mt-3 card bg-light text-dark">
-                <div class="card-body">
-                    <p class="h1"> "League of Legends skinset finder" </p>
-                    <p> "This tool is used to find League of Legend team comps that share skins from the same skinset." </p>
-                    <p>
-                        "I currently source my skin data from "
-                        <Link href="https://leagueoflegends.fandom.com/wiki/Champion_skin/Skin_themes" open_in_new_tab=true />
-                        ", and my lane data from "
-                        <Link href="https://leagueoflegends.fandom.com/wiki/List_of_champions_by_draft_position" open_in_new_tab=true />
-                        "."
-                    </p>
-                    <p> "Data was last updated from these sources on December 8th, 2023." </p>
-                    <p>
-                        "
-                        I will try to keep this generally up to date with league skins and champions, but may not always
-                        remember to update this every patch. If you notice that the date above is a long time ago, or there
-                        are champs/skins missing, please let me know by filing an Issue report at 
-                        "
-                        <Link href="https://github.com/vcfxb/league-skinset-finder/issues" open_in_new_tab=true /> 
-                        "."
-                    </p>
+            {browse_page}
+
+            // The finder is hidden rather than unmounted while a browse page is shown, so that it keeps its state.
+            <div class:d-none={move || route.with(|route| *route != Route::Finder)}>
+                <div class="mt-3 card bg-light text-dark">
+                    <div class="card-body">
+                        <p class="h1"> "League of Legends skinset finder" </p>
+                        <p> "This tool is used to find League of Legend team comps that share skins from the same skinset." </p>
+                        <p>
+                            "I currently source my skin data from "
+                            <Link href="https://leagueoflegends.fandom.com/wiki/Champion_skin/Skin_themes" open_in_new_tab=true />
+                            ", and my lane data from "
+                            <Link href="https://leagueoflegends.fandom.com/wiki/List_of_champions_by_draft_position" open_in_new_tab=true />
+                            "."
+                        </p>
+                        <p> "Data was last updated from these sources on December 8th, 2023." </p>
+                        <p>
+                            "
+                            I will try to keep this generally up to date with league skins and champions, but may not always
+                            remember to update this every patch. If you notice that the date above is a long time ago, or there
+                            are champs/skins missing, please let me know by filing an Issue report at 
+                            "
+                            <Link href="https://github.com/vcfxb/league-skinset-finder/issues" open_in_new_tab=true /> 
+                            "."
+                        </p>
+                        <p>
+                            "Your players and skinset selections are saved in this browser automatically. "
+                            // Reset button to clear saved state.
+                            <Button class="btn btn-outline-danger btn-sm" on_click=reset_to_defaults>
+                                "Reset to Defaults"
+                            </Button>
+                        </p>
+
+                        // Undo/redo for edits to players and skinsets.
+                        <p>
+                            <UndoRedo players_rw_signal={players_rw_signal} skinsets_rw_signal={skinsets_rw_signal} />
+                        </p>
+
+                        // Share link for the current session.
+                        <ShareLink
+                            players_rw_signal={players_rw_signal}
+                            skinsets_rw_signal={skinsets_rw_signal}
+                            settings={solver_settings}
+                        />
+
+                        // Roster file import/export.
+                        <RosterFile
+                            game_mode_rw_signal={game_mode_rw_signal}
+                            owned_only_rw_signal={owned_only_rw_signal}
+                            players_rw_signal={players_rw_signal}
+                            skinsets_rw_signal={skinsets_rw_signal}
+                        />
+                    </div>
+                </div>
+
+                // Search settings.
+                <div class="card bg-light text-dark my-2">
+                    <div class="card-body d-flex align-items-center gap-3">
+                        <h3 class="p2 mb-0 flex-grow-1"> "Search Settings" </h3>
+                        <div class="form-check form-switch mb-0">
+                            <Checkbox
+                                checked={Signal::derive(move || owned_only_rw_signal.get())}
+                                id="owned-only"
+                                on_change=move |checked| owned_only_rw_signal.set(checked)
+                            />
+                            <label class="form-check-label" for="owned-only"> "Only use owned skins" </label>
+                        </div>
+                        <GameModeSelect game_mode_rw_signal={game_mode_rw_signal} />
+                    </div>
                 </div>
-            </div>
 
-            <SkinsetList skinsets_rw_signal={skinsets_rw_signal} />
+                <SkinsetList
+                    skinsets_rw_signal={skinsets_rw_signal}
+                    players_rw_signal={players_rw_signal}
+                    settings={solver_settings}
+                    draft={draft_rw_signal}
+                />
+
+                // Player cards.
+                {move || {
+                    (0..player_count.get())
+                        .map(|player_index| view! { <Player player_index=player_index players_rw_signal=players_rw_signal /> })
+                        .collect_view()
+                }}
+
+                // Block button to add a player.
+                <div class="d-grid gap-2 my-2">
+                    <Button
+                        class="btn btn-success"
+                        disabled={Signal::derive(move || player_count.get() >= MAX_PLAYERS)}
+                        on_click=move |_| players_rw_signal.update(|players| players.push(PlayerRecord::new()))
+                    >
+                        <Icon icon=BsPersonAdd /> " Add Player"
+                    </Button>
+                </div>
+
+                <DraftPanel draft_rw_signal={draft_rw_signal} />
+
+                <Results
+                    players_rw_signal={players_rw_signal}
+                    skinsets_rw_signal={skinsets_rw_signal}
+                    settings={solver_settings}
+                    draft={draft_rw_signal}
+                />
+
+                <Recommendations
+                    players_rw_signal={players_rw_signal}
+                    skinsets_rw_signal={skinsets_rw_signal}
+                    settings={solver_settings}
+                    draft={draft_rw_signal}
+                />
+
+                <CoverageHeatmap
+                    players_rw_signal={players_rw_signal}
+                    skinsets_rw_signal={skinsets_rw_signal}
+                    draft={draft_rw_signal}
+                />
+            </div>
 
         </div>
     }
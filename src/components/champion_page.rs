@@ -0,0 +1,60 @@
+//! Browse page for a single champion -- their default lanes and every skin they have, by skinset.
+
+use crate::{
+    components::link::{Link, SkinsetLink},
+    constants::{ChampId, Lane},
+};
+use leptos::{component, view, CollectView, IntoView};
+
+/// Page listing a champion's default lanes, the skinsets they're in and their skins in each.
+///
+/// # Arguments
+/// - `champ_id` - The champion to show.
+#[component]
+pub fn ChampionPage(champ_id: ChampId) -> impl IntoView {
+    // Badges for each lane the champ is played in by default.
+    let lanes = champ_id
+        .default_lanes()
+        .iter()
+        .map(|lane: Lane| view! { <span class="badge bg-secondary me-1"> {lane.to_string()} </span> })
+        .collect_view();
+
+    // One row per skinset, with the champ's skins in it.
+    let skinset_rows = champ_id
+        .skinsets()
+        .map(|skinset_id| {
+            let universes: Vec<&str> = skinset_id.universes().map(|universe_id| universe_id.universe_name()).collect();
+            let skins: Vec<&str> = champ_id.skins_in(skinset_id).map(|skin_id| skin_id.skin_name()).collect();
+
+            view! {
+                <tr>
+                    <td> <SkinsetLink skinset_id=skinset_id /> </td>
+                    <td class="text-muted"> {universes.join(", ")} </td>
+                    <td> {skins.join(", ")} </td>
+                </tr>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div class="card bg-light text-dark my-2">
+            <div class="card-body">
+                <Link href="#/" text="Back to the finder".to_owned() />
+                <p class="h1 mt-2"> {champ_id.champ_name()} </p>
+                <p> "Default lanes: " {lanes} </p>
+                <p> "In " {champ_id.skinsets().len()} " skinsets." </p>
+
+                <table class="table table-sm table-striped">
+                    <thead>
+                        <tr>
+                            <th> "Skinset" </th>
+                            <th> "Universes" </th>
+                            <th> "Skins" </th>
+                        </tr>
+                    </thead>
+                    <tbody> {skinset_rows} </tbody>
+                </table>
+            </div>
+        </div>
+    }
+}
@@ -1,27 +1,35 @@
 //! Checkbox components.
 
-use leptos::{component, view, Callback, IntoView, MaybeSignal, Callable};
+use leptos::{component, event_target_checked, view, Callable, Callback, IntoView, MaybeSignal};
 
 /// Checkbox component.
-/// 
+///
 /// # Arguments
-/// - `on_change` - [Callback] to
-/// # WARNING
-/// The reactivity of this component is likely buggy -- steal from the skinset selector when you get a chance. 
+/// - `checked` - Whether the checkbox is checked. This can be a signal.
+/// - `indeterminate` - Whether the checkbox is shown as partially checked (default: false). This can be a signal.
+/// - `id` - The HTML id of the checkbox, used to connect it to a label (optional).
+/// - `on_change` - [Callback] triggered with the new checked state when the user toggles the checkbox.
 #[component]
 pub fn Checkbox(
     #[prop(into)]
     checked: MaybeSignal<bool>,
+    #[prop(into, optional)]
+    indeterminate: MaybeSignal<bool>,
+    #[prop(into, optional)]
+    id: Option<String>,
     #[prop(into)]
-    on_change: Callback<()>
+    on_change: Callback<bool>
 ) -> impl IntoView {
-
+    // Set the checked state as a property rather than an attribute -- the attribute only sets the initial state, so
+    // the checkbox would stop following the signal once it's been clicked.
     view! {
         <input
             class="form-check-input"
             type="checkbox"
-            checked={checked}
-            on:change=move |_| on_change.call(())
+            id=id
+            prop:checked=checked
+            prop:indeterminate=indeterminate
+            on:change=move |ev| on_change.call(event_target_checked(&ev))
         />
     }
 }
@@ -0,0 +1,175 @@
+//! A button to roll a random comp from the results, with per-player rerolls.
+
+use crate::{
+    components::button::Button,
+    model::PlayerRecord,
+    roller::{self, Roll, RollMode},
+    solver::Comp,
+};
+use icondata::{BiRefreshRegular, BiShuffleRegular};
+use leptos::{
+    component, create_effect, create_rw_signal, view, CollectView, IntoView, RwSignal, Show, Signal, SignalGet,
+    SignalGetUntracked, SignalSet, SignalWith, SignalWithUntracked,
+};
+use leptos_icons::Icon;
+
+/// The random comp roller.
+///
+/// # Arguments
+/// - `players_rw_signal` - A read/write signal to the current list of [`PlayerRecord`]s.
+/// - `comps` - The comps to roll from.
+#[component]
+pub fn CompRoller(
+    players_rw_signal: RwSignal<Vec<PlayerRecord>>,
+    #[prop(into)]
+    comps: Signal<Vec<Comp>>,
+) -> impl IntoView {
+    // How to roll, and the current roll.
+    let mode: RwSignal<RollMode> = create_rw_signal(RollMode::default());
+    let rolled: RwSignal<Option<Roll>> = create_rw_signal(None);
+
+    // Set if the system's random source ever fails, which disables rolling rather than rolling the same comp each time.
+    let random_failed: RwSignal<bool> = create_rw_signal(false);
+
+    // Unwrap a roll made with the system's random source, disabling rolling if the random source failed.
+    let checked = move |rolled: Result<Option<Roll>, getrandom::Error>| {
+        rolled.unwrap_or_else(|err| {
+            log::error!("Failed to get random bytes, disabling rolling: {err}");
+            random_failed.set(true);
+            None
+        })
+    };
+
+    // Clear the roll whenever the results change, since it may no longer be valid.
+    create_effect(move |previous: Option<()>| {
+        comps.track();
+
+        if previous.is_some() {
+            rolled.set(None);
+        }
+    });
+
+    let on_roll = move |_| {
+        let roll_mode: RollMode = mode.get_untracked();
+        let roll = comps.with_untracked(|comps| {
+            roller::with_system_random(|random_index| roller::roll(comps, roll_mode, random_index))
+        });
+
+        rolled.set(checked(roll));
+    };
+
+    // Toggle buttons to choose the roll mode.
+    let mode_select = RollMode::ALL
+        .iter()
+        .map(|option: &RollMode| {
+            let option: RollMode = *option;
+            let radio_id: String = format!("roll-mode-{option:?}");
+
+            view! {
+                <input
+                    type="radio"
+                    class="btn-check"
+                    name="roll-mode"
+                    id={radio_id.clone()}
+                    prop:checked={move || mode.get() == option}
+                    on:change=move |_| mode.set(option)
+                />
+                <label class="btn btn-outline-secondary" for={radio_id}> {option.to_string()} </label>
+            }
+        })
+        .collect_view();
+
+    // A row for each player in the roll, with their skins and a reroll button.
+    let rolled_rows = move || {
+        let Some(roll) = rolled.get() else {
+            return view! {}.into_view();
+        };
+
+        let names: Vec<String> = players_rw_signal.with(|players| {
+            players.iter().enumerate().map(|(index, player)| player.display_name(index)).collect()
+        });
+
+        let rows = roll
+            .picks
+            .iter()
+            .zip(roll.skins())
+            .zip(names)
+            .enumerate()
+            .map(|(player_index, ((pick, skins), name))| {
+                let lane: String = pick.lane.map(|lane| format!(" {lane}")).unwrap_or_default();
+                let skins: String =
+                    skins.into_iter().map(|skin_id| skin_id.skin_name()).collect::<Vec<&str>>().join(", ");
+
+                // Whether this player has anything else they could play.
+                let can_reroll = Signal::derive(move || {
+                    rolled.with(|current| {
+                        current.as_ref().is_some_and(|current| {
+                            comps.with(|comps| roller::reroll_player(comps, current, player_index, |_| 0).is_some())
+                        })
+                    })
+                });
+
+                let on_reroll = move |_| {
+                    let Some(current) = rolled.get_untracked() else {
+                        return;
+                    };
+
+                    let rerolled: Option<Roll> = checked(comps.with_untracked(|comps| {
+                        roller::with_system_random(|random_index| {
+                            roller::reroll_player(comps, &current, player_index, random_index)
+                        })
+                    }));
+
+                    if rerolled.is_some() {
+                        rolled.set(rerolled);
+                    }
+                };
+
+                view! {
+                    <li class="list-group-item d-flex align-items-center gap-2">
+                        <div class="flex-grow-1">
+                            <strong> {name} ": " {pick.champ.champ_name()} {lane} </strong>
+                            <div class="text-muted small"> {skins} </div>
+                        </div>
+
+                        <Button
+                            class="btn btn-sm btn-outline-secondary"
+                            disabled={Signal::derive(move || !can_reroll.get() || random_failed.get())}
+                            on_click=on_reroll
+                        >
+                            <Icon icon=BiRefreshRegular /> " Reroll"
+                        </Button>
+                    </li>
+                }
+            })
+            .collect_view();
+
+        view! {
+            <div class="card border-primary mb-2">
+                <div class="card-header fs-5"> {roll.skinset.skinset_name()} </div>
+                <ul class="list-group list-group-flush"> {rows} </ul>
+            </div>
+        }
+        .into_view()
+    };
+
+    view! {
+        <div class="d-flex align-items-center gap-2 mb-2">
+            <Button
+                class="btn btn-primary"
+                disabled={Signal::derive(move || comps.with(Vec::is_empty) || random_failed.get())}
+                on_click=on_roll
+            >
+                <Icon icon=BiShuffleRegular /> " Roll"
+            </Button>
+
+            <div class="btn-group" role="group" aria-label="Roll mode"> {mode_select} </div>
+
+            <Show when={move || random_failed.get()} fallback={move || view! {}}>
+                <span class="text-danger small"> "Rolling is unavailable -- no random source in this browser." </span>
+            </Show>
+        </div>
+
+        {rolled_rows}
+    }
+}
@@ -0,0 +1,186 @@
+//! A heatmap of players against included skinsets, showing how many champs each player has in each skinset.
+
+use crate::{
+    components::button::Button,
+    constants::{Lane, SkinsetId},
+    coverage::{self, CoverageCell, CoverageColumn, CoverageSort},
+    draft::Draft,
+    model::PlayerRecord,
+};
+use icondata::{BiChevronDownRegular, BiChevronUpRegular, BiSortDownRegular, BiSortUpRegular};
+use leptos::{
+    component, create_memo, create_rw_signal, view, CollectView, IntoView, Memo, RwSignal, Show, Signal, SignalGet,
+    SignalUpdate, SignalWith,
+};
+use leptos_icons::Icon;
+use std::collections::HashSet;
+
+/// The background of a cell, shaded by how many champs it has relative to the busiest cell.
+fn cell_style(cell: &CoverageCell, max_champs: usize) -> String {
+    let alpha: f64 = if max_champs == 0 { 0.0 } else { cell.champs.len() as f64 / max_champs as f64 };
+    format!("background-color: rgba(25, 135, 84, {:.2});", alpha * 0.8)
+}
+
+/// The hover text of a cell, listing the champs and lanes.
+fn cell_title(cell: &CoverageCell) -> String {
+    let champs: Vec<&str> = cell.champs.iter().map(|champ_id| champ_id.champ_name()).collect();
+    let lanes: Vec<String> = cell.lanes.iter().map(|lane: Lane| lane.to_string()).collect();
+    let excluded: &str = if cell.excluded { " (excluded)" } else { "" };
+
+    format!("{} -- {}{excluded}", champs.join(", "), lanes.join("/"))
+}
+
+/// Coverage heatmap card, collapsed by default.
+///
+/// # Arguments
+/// - `players_rw_signal` - A read/write signal to the current list of [`PlayerRecord`]s.
+/// - `skinsets_rw_signal` - A read/write signal to the set of skinsets included in the search.
+/// - `draft` - The champ select draft, whose champs are left out of every player's options.
+#[component]
+pub fn CoverageHeatmap(
+    players_rw_signal: RwSignal<Vec<PlayerRecord>>,
+    skinsets_rw_signal: RwSignal<HashSet<SkinsetId>>,
+    #[prop(into)]
+    draft: Signal<Draft>,
+) -> impl IntoView {
+    // Whether the heatmap is shown, and how its columns are ordered.
+    let show: RwSignal<bool> = create_rw_signal(false);
+    let sort: RwSignal<CoverageSort> = create_rw_signal(CoverageSort::default());
+
+    let columns: Memo<Vec<CoverageColumn>> = create_memo(move |_| {
+        if !show.get() {
+            return Vec::new();
+        }
+
+        players_rw_signal.with(|players| {
+            let players: Vec<PlayerRecord> = draft.with(|draft| draft.apply(players));
+            skinsets_rw_signal.with(|skinsets| coverage::coverage(&players, skinsets, sort.get()))
+        })
+    });
+
+    // Cycle through skinset order, most covered first, and least covered first.
+    let cycle_sort = move |_| {
+        sort.update(|sort| {
+            *sort = match sort {
+                CoverageSort::Skinset => CoverageSort::TotalDescending,
+                CoverageSort::TotalDescending => CoverageSort::TotalAscending,
+                CoverageSort::TotalAscending => CoverageSort::Skinset,
+            }
+        })
+    };
+
+    let header = move || {
+        columns.with(|columns| {
+            columns
+                .iter()
+                .map(|column| view! {
+                    <th class="text-nowrap small" class:text-danger={column.min() == 0}>
+                        {column.skinset.skinset_name()}
+                    </th>
+                })
+                .collect_view()
+        })
+    };
+
+    let rows = move || {
+        let max_champs: usize = columns.with(|columns| {
+            columns.iter().flat_map(|column| column.cells.iter().map(|cell| cell.champs.len())).max().unwrap_or_default()
+        });
+
+        let rows = players_rw_signal.with(|players| {
+            players
+                .iter()
+                .enumerate()
+                .map(|(player_index, player)| {
+                    let cells = columns.with(|columns| {
+                        columns
+                            .iter()
+                            .map(|column| {
+                                let cell: &CoverageCell = &column.cells[player_index];
+
+                                view! {
+                                    <td
+                                        class="text-center"
+                                        class:text-decoration-line-through={cell.excluded}
+                                        style={cell_style(cell, max_champs)}
+                                        title={cell_title(cell)}
+                                    >
+                                        {cell.champs.len()}
+                                    </td>
+                                }
+                            })
+                            .collect_view()
+                    });
+
+                    view! {
+                        <tr>
+                            <th class="text-nowrap"> {player.display_name(player_index)} </th>
+                            {cells}
+                        </tr>
+                    }
+                })
+                .collect_view()
+        });
+
+        let totals = columns.with(|columns| {
+            columns.iter().map(|column| view! { <td class="text-center fw-bold"> {column.total()} </td> }).collect_view()
+        });
+
+        view! {
+            {rows}
+            <tr>
+                <th> "Total" </th>
+                {totals}
+            </tr>
+        }
+    };
+
+    view! {
+        <div class="card bg-light text-dark my-2">
+            <div class="card-body">
+                <Button class="btn btn-outline-secondary w-100" on_click=move |_| show.update(|s| *s = !*s)>
+                    "Skinset Coverage by Player "
+                    {move || if show.get() {
+                        view! { <Icon icon=BiChevronUpRegular /> }
+                    } else {
+                        view! { <Icon icon=BiChevronDownRegular /> }
+                    }}
+                </Button>
+
+                <Show when={move || show.get()} fallback={move || view! {} }>
+                    <p class="text-muted my-2">
+                        "Each cell is how many of that player's champs are in the skinset -- hover for the champs and "
+                        "lanes. Skinsets someone has no champs in are in red, and crossed out cells are excluded by "
+                        "that player."
+                    </p>
+
+                    <div class="table-responsive">
+                        <table class="table table-sm table-bordered">
+                            <thead>
+                                <tr>
+                                    <th>
+                                        <Button class="btn btn-sm btn-outline-secondary text-nowrap" on_click=cycle_sort>
+                                            {move || match sort.get() {
+                                                CoverageSort::Skinset => view! { "Sort by total" }.into_view(),
+                                                CoverageSort::TotalDescending => view! {
+                                                    <Icon icon=BiSortDownRegular /> " Total"
+                                                }.into_view(),
+                                                CoverageSort::TotalAscending => view! {
+                                                    <Icon icon=BiSortUpRegular /> " Total"
+                                                }.into_view(),
+                                            }}
+                                        </Button>
+                                    </th>
+                                    {header}
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {rows}
+                            </tbody>
+                        </table>
+                    </div>
+                </Show>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,132 @@
+//! The "why no results?" panel shown in place of an empty results table.
+
+use crate::{
+    constants::SkinsetId,
+    diagnostics::{self, Diagnostics, Exclusion},
+    draft::Draft,
+    model::{PlayerRecord, SolverSettings},
+};
+use leptos::{component, create_memo, view, CollectView, IntoView, Memo, RwSignal, Signal, SignalGet, SignalWith};
+use std::collections::HashSet;
+
+/// The most near misses and excluded skinsets listed, so that a large skinset selection doesn't flood the panel.
+const MAX_LISTED_SKINSETS: usize = 15;
+
+/// Explanations for why there are no results.
+///
+/// # Arguments
+/// - `players_rw_signal` - A read/write signal to the current list of [`PlayerRecord`]s.
+/// - `skinsets_rw_signal` - A read/write signal to the set of skinsets included in the search.
+/// - `settings` - The settings comps are resolved with.
+/// - `draft` - The champ select draft, whose champs are left out of every player's options.
+#[component]
+pub fn DiagnosticsPanel(
+    players_rw_signal: RwSignal<Vec<PlayerRecord>>,
+    skinsets_rw_signal: RwSignal<HashSet<SkinsetId>>,
+    #[prop(into)]
+    settings: Signal<SolverSettings>,
+    #[prop(into)]
+    draft: Signal<Draft>,
+) -> impl IntoView {
+    let diagnostics: Memo<Diagnostics> = create_memo(move |_| {
+        players_rw_signal.with(|players| {
+            let players: Vec<PlayerRecord> = draft.with(|draft| draft.apply(players));
+            skinsets_rw_signal.with(|skinsets| diagnostics::diagnose(&players, skinsets, settings.get()))
+        })
+    });
+
+    // Get the display name of a player by index.
+    let name = move |player_index: usize| -> String {
+        players_rw_signal.with(|players| {
+            players.get(player_index).map(|player| player.display_name(player_index)).unwrap_or_default()
+        })
+    };
+
+    // One list item per finding.
+    let findings = move || {
+        let diagnostics: Diagnostics = diagnostics.get();
+        let nothing_found: bool = diagnostics.is_empty();
+        let mut items: Vec<String> = Vec::new();
+
+        if players_rw_signal.with(Vec::is_empty) {
+            items.push("There are no players.".to_owned());
+        }
+
+        if skinsets_rw_signal.with(HashSet::is_empty) {
+            items.push("No skinsets are included in the search.".to_owned());
+        }
+
+        for player_index in diagnostics.empty_pools {
+            items.push(format!("{} has no champs.", name(player_index)));
+        }
+
+        for player_index in diagnostics.no_usable_champs {
+            items.push(format!(
+                "None of {}'s champs can be played in an included skinset they'll accept.",
+                name(player_index)
+            ));
+        }
+
+        if let Some(shortage) = diagnostics.lane_shortage {
+            let names: Vec<String> = shortage.players.into_iter().map(name).collect();
+            let lanes: Vec<String> = shortage.lanes.iter().map(|lane| lane.to_string()).collect();
+            items.push(format!(
+                "Not enough lanes to go around -- {} only play {} between them.",
+                names.join(" and "),
+                if lanes.is_empty() { "no lanes".to_owned() } else { lanes.join(", ") }
+            ));
+        }
+
+        for (first, second) in diagnostics.disjoint_pairs {
+            items.push(format!("{} and {} share no included skinset.", name(first), name(second)));
+        }
+
+        let near_miss_count: usize = diagnostics.near_misses.len();
+        for near_miss in diagnostics.near_misses.into_iter().take(MAX_LISTED_SKINSETS) {
+            items.push(format!(
+                "{} only needs a champ from {}.",
+                near_miss.skinset.skinset_name(),
+                name(near_miss.missing_player)
+            ));
+        }
+
+        if near_miss_count > MAX_LISTED_SKINSETS {
+            let more: usize = near_miss_count - MAX_LISTED_SKINSETS;
+            items.push(format!("...and {more} more skinsets missing one player."));
+        }
+
+        let excluded_count: usize = diagnostics.excluded_skinsets.len();
+        for excluded in diagnostics.excluded_skinsets.into_iter().take(MAX_LISTED_SKINSETS) {
+            let reason: String = match excluded.exclusion {
+                Exclusion::NotIncluded => "it isn't included in the search".to_owned(),
+                Exclusion::ExcludedByPlayers(player_indices) => {
+                    let names: Vec<String> = player_indices.into_iter().map(name).collect();
+                    format!("{} excluded it", names.join(" and "))
+                }
+            };
+
+            items.push(format!("{} would work, but {reason}.", excluded.skinset.skinset_name()));
+        }
+
+        if excluded_count > MAX_LISTED_SKINSETS {
+            let more: usize = excluded_count - MAX_LISTED_SKINSETS;
+            items.push(format!("...and {more} more excluded skinsets that would work."));
+        }
+
+        if nothing_found && items.is_empty() {
+            items.push(
+                "Every pair of players shares a skinset, but no skinset has a champ for everyone in distinct lanes."
+                    .to_owned(),
+            );
+        }
+
+        items.into_iter().map(|item| view! { <li> {item} </li> }).collect_view()
+    };
+
+    view! {
+        <div class="alert alert-warning">
+            <h5 class="alert-heading"> "Why no results?" </h5>
+            <ul class="mb-0"> {findings} </ul>
+        </div>
+    }
+}
@@ -0,0 +1,19 @@
+//! Helper to offer generated files to the user as downloads.
+
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+/// Offer a string to the user as a file download.
+pub fn download_text(file_name: &str, mime_type: &str, contents: &str) -> Result<(), JsValue> {
+    let parts = js_sys::Array::of1(&JsValue::from_str(contents));
+    let blob = Blob::new_with_str_sequence_and_options(&parts, BlobPropertyBag::new().type_(mime_type))?;
+    let url: String = Url::create_object_url_with_blob(&blob)?;
+
+    // Click a temporary link to the blob to start the download.
+    let anchor: HtmlAnchorElement = leptos::document().create_element("a")?.unchecked_into();
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+
+    Url::revoke_object_url(&url)
+}
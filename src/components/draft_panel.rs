@@ -0,0 +1,99 @@
+//! Card for tracking champ select -- the champs banned or picked by the enemy team as the draft goes on.
+
+use crate::{
+    components::{button::Button, link::ChampLink, player::champ_dropdown::ChampDropdown},
+    constants::ChampId,
+    draft::Draft,
+};
+use icondata::BiXRegular;
+use leptos::{component, view, CollectView, IntoView, RwSignal, Signal, SignalUpdate, SignalWith};
+use leptos_icons::Icon;
+use std::collections::HashSet;
+
+/// Draft panel component.
+///
+/// # Arguments
+/// - `draft_rw_signal` - A read/write signal to the current [`Draft`].
+#[component]
+pub fn DraftPanel(draft_rw_signal: RwSignal<Draft>) -> impl IntoView {
+    // Champs already banned or picked, which can't be added again.
+    let unavailable: Signal<HashSet<ChampId>> =
+        Signal::derive(move || draft_rw_signal.with(|draft| draft.unavailable().collect()));
+
+    // Badges for a list of drafted champs, each with a button to undo it.
+    let badges = move |champs: fn(&Draft) -> &Vec<ChampId>, class: &'static str| {
+        move || {
+            draft_rw_signal.with(|draft| {
+                champs(draft)
+                    .iter()
+                    .map(|champ_id: &ChampId| {
+                        let champ_id: ChampId = *champ_id;
+
+                        view! {
+                            <span class={format!("badge {class} me-1")}>
+                                <ChampLink champ_id=champ_id />
+                                <button
+                                    class="btn btn-sm btn-link p-0 ms-1 text-light"
+                                    title="Undo"
+                                    on:click=move |_| draft_rw_signal.update(|draft| draft.remove(champ_id))
+                                >
+                                    <Icon icon=BiXRegular />
+                                </button>
+                            </span>
+                        }
+                    })
+                    .collect_view()
+            })
+        }
+    };
+
+    view! {
+        <div class="card bg-light text-dark my-2">
+            <div class="card-body">
+                <div class="d-flex align-items-center mb-2">
+                    <h3 class="p2 mb-0 flex-grow-1"> "Draft" </h3>
+                    <Button
+                        class="btn btn-outline-danger btn-sm"
+                        disabled={Signal::derive(move || draft_rw_signal.with(Draft::is_empty))}
+                        on_click=move |_| draft_rw_signal.update(|draft| *draft = Draft::default())
+                    >
+                        "Clear Draft"
+                    </Button>
+                </div>
+
+                <p class="text-muted">
+                    "Mark champions as they're banned or picked by the enemy team. Nobody can play them, so comps that "
+                    "use them are marked as no longer possible."
+                </p>
+
+                <div class="row g-2">
+                    <div class="col-md">
+                        <h5>
+                            "Bans (" {move || draft_rw_signal.with(|draft| draft.bans.len())} "/" {Draft::MAX_BANS} ")"
+                        </h5>
+                        <ChampDropdown
+                            unavailable={unavailable}
+                            on_change=move |champ_id| draft_rw_signal.update(|draft| { draft.ban(champ_id); })
+                        />
+                        <div class="mt-1"> {badges(|draft| &draft.bans, "bg-danger")} </div>
+                    </div>
+
+                    <div class="col-md">
+                        <h5>
+                            "Enemy Picks ("
+                            {move || draft_rw_signal.with(|draft| draft.enemy_picks.len())}
+                            "/" {Draft::MAX_ENEMY_PICKS} ")"
+                        </h5>
+                        <ChampDropdown
+                            unavailable={unavailable}
+                            on_change=move |champ_id| {
+                                draft_rw_signal.update(|draft| { draft.add_enemy_pick(champ_id); })
+                            }
+                        />
+                        <div class="mt-1"> {badges(|draft| &draft.enemy_picks, "bg-warning")} </div>
+                    </div>
+                </div>
+            </div>
+        </div>
+    }
+}
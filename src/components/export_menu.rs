@@ -0,0 +1,80 @@
+//! Buttons to export the results shown in the results table.
+
+use crate::{
+    components::{button::Button, checkbox::Checkbox, download::download_text},
+    export::{self, ExportFormat, ExportOptions},
+    model::{PlayerRecord, SolverSettings},
+    solver::Comp,
+};
+use icondata::BiDownloadRegular;
+use leptos::{
+    component, create_rw_signal, view, CollectView, IntoView, RwSignal, Signal, SignalGet, SignalGetUntracked,
+    SignalSet, SignalWithUntracked,
+};
+use leptos_icons::Icon;
+
+/// The file name (without extension) used for exported results.
+const EXPORT_FILE_STEM: &str = "skinset-results";
+
+/// Export controls for the results.
+///
+/// # Arguments
+/// - `players_rw_signal` - A read/write signal to the current list of [`PlayerRecord`]s.
+/// - `comps` - The comps to export, already filtered and sorted the way they're shown.
+/// - `settings` - The settings the comps were resolved with, so that only owned skins are listed when asked.
+#[component]
+pub fn ExportMenu(
+    players_rw_signal: RwSignal<Vec<PlayerRecord>>,
+    #[prop(into)]
+    comps: Signal<Vec<Comp>>,
+    #[prop(into)]
+    settings: Signal<SolverSettings>,
+) -> impl IntoView {
+    // Whether skin names are included in exports.
+    let include_skin_names: RwSignal<bool> = create_rw_signal(false);
+
+    // A button to export in each format.
+    let export_buttons = ExportFormat::ALL
+        .iter()
+        .map(|format: &ExportFormat| {
+            let format: ExportFormat = *format;
+
+            let on_click = move |_| {
+                let options = ExportOptions {
+                    include_skin_names: include_skin_names.get_untracked(),
+                    owned_only: settings.get_untracked().owned_only,
+                };
+                let contents: String = players_rw_signal.with_untracked(|players| {
+                    comps.with_untracked(|comps| export::export_comps(players, comps, format, options))
+                });
+
+                let file_name: String = format!("{EXPORT_FILE_STEM}.{}", format.extension());
+
+                if let Err(err) = download_text(&file_name, format.mime_type(), &contents) {
+                    log::error!("Failed to export results as {format}: {err:?}");
+                }
+            };
+
+            view! {
+                <Button class="btn btn-outline-primary" on_click=on_click>
+                    <Icon icon=BiDownloadRegular /> " " {format.to_string()}
+                </Button>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div class="d-flex align-items-center gap-3 mb-2">
+            <div class="btn-group" role="group" aria-label="Export results"> {export_buttons} </div>
+
+            <div class="form-check mb-0">
+                <Checkbox
+                    checked={Signal::derive(move || include_skin_names.get())}
+                    id="export-skin-names"
+                    on_change=move |checked| include_skin_names.set(checked)
+                />
+                <label class="form-check-label" for="export-skin-names"> "Include skin names" </label>
+            </div>
+        </div>
+    }
+}
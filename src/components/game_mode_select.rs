@@ -0,0 +1,37 @@
+//! Selector for the game mode being played.
+
+use crate::model::GameMode;
+use leptos::{component, view, CollectView, IntoView, RwSignal, SignalGet, SignalSet};
+
+/// A group of toggle buttons to choose the [`GameMode`].
+///
+/// # Arguments
+/// - `game_mode_rw_signal` - A read/write signal to the current [`GameMode`].
+#[component]
+pub fn GameModeSelect(game_mode_rw_signal: RwSignal<GameMode>) -> impl IntoView {
+    view! {
+        <div class="btn-group" role="group" aria-label="Game mode">
+            {
+                GameMode::ALL
+                    .iter()
+                    .map(|game_mode: &GameMode| {
+                        let game_mode: GameMode = *game_mode;
+                        let radio_id: String = format!("game-mode-{game_mode:?}");
+
+                        view! {
+                            <input
+                                type="radio"
+                                class="btn-check"
+                                name="game-mode"
+                                id={radio_id.clone()}
+                                prop:checked={move || game_mode_rw_signal.get() == game_mode}
+                                on:change=move |_| game_mode_rw_signal.set(game_mode)
+                            />
+                            <label class="btn btn-outline-secondary" for={radio_id}> {game_mode.to_string()} </label>
+                        }
+                    })
+                    .collect_view()
+            }
+        </div>
+    }
+}
@@ -1,4 +1,8 @@
 //! Link component for the league skinset finder. 
+use crate::{
+    constants::{ChampId, SkinsetId},
+    route::Route,
+};
 use leptos::{component, view, IntoView};
 use leptos_icons::Icon;
 use icondata::TbExternalLink;
@@ -31,3 +35,21 @@ pub fn Link(
         view! { <a href={&href}> {link_text} </a> }
     }
 }
+
+/// Component that renders a link to a champion's browse page.
+///
+/// # Arguments
+/// - `champ_id` - The champion to link to.
+#[component]
+pub fn ChampLink(champ_id: ChampId) -> impl IntoView {
+    view! { <a href={Route::Champion(champ_id).href()}> {champ_id.champ_name()} </a> }
+}
+
+/// Component that renders a link to a skinset's browse page.
+///
+/// # Arguments
+/// - `skinset_id` - The skinset to link to.
+#[component]
+pub fn SkinsetLink(skinset_id: SkinsetId) -> impl IntoView {
+    view! { <a href={Route::Skinset(skinset_id).href()}> {skinset_id.skinset_name()} </a> }
+}
@@ -1,255 +1,210 @@
 //! Player components in the league skinset finder.
 
-use crate::constants::{ChampId, Lane};
-
-use super::{button::Button, App, app::{PlayerRecord, AppMsg}};
-// use champ::ChampSelection;
-use enumflags2::BitFlags;
-use name_field::Name;
-use std::rc::Rc;
-use yew::{prelude::*, html::Scope};
-use yew_icons::{Icon, IconId};
-
-// mod champ;
-mod champ_dropdown;
+use crate::components::{button::Button, checkbox::Checkbox};
+use crate::constants::{ChampId, SkinsetId};
+use crate::model::PlayerRecord;
+use champ::ChampSelection;
+use champ_dropdown::ChampDropdown;
+use icondata::{BiChevronDownRegular, BiChevronUpRegular, BsTrash};
+use leptos::{
+    component, create_memo, create_rw_signal, view, CollectView, For, IntoView, Memo, RwSignal, Show, Signal,
+    SignalGet, SignalUpdate, SignalWith,
+};
+use leptos_icons::Icon;
+use lock_select::LockSelect;
+use name_field::NameField;
+use std::collections::HashSet;
+
+mod champ;
+pub mod champ_dropdown;
 mod lanes_select;
+mod lock_select;
 mod name_field;
 
-
-/// Player component. 
-pub struct Player;
-
-impl Player {
-    /// Get the scope of the parent app component. 
-    fn get_parent_app_scope(ctx: &Context<Self>) -> Scope<App> {
-        ctx.link()
-            .get_parent()
-            .expect("found parent component")
-            .downcast()
-    }
-}
-
-pub enum PlayerMsg {
-    /// Change this players name. 
-    ChangeName {
-        new_name: String,
-    },
-
-    /// Remove this player. 
-    RemoveThisPlayer,
-
+/// Apply an update to the player at `player_index`, if there is one.
+fn update_player(
+    players_rw_signal: RwSignal<Vec<PlayerRecord>>,
+    player_index: usize,
+    update: impl FnOnce(&mut PlayerRecord),
+) {
+    players_rw_signal.update(|players| {
+        if let Some(player) = players.get_mut(player_index) {
+            update(player);
+        }
+    });
 }
 
-/// The properties passed to the player component. 
-#[derive(Properties, PartialEq, Debug)]
-pub struct PlayerProps {
-    /// Player ID (index) used for connecting inputs and labels.
-    pub id: usize,
-}
+/// Player card, with the player's name, champs, lock-in and personal skinset exclusions.
+///
+/// # Arguments
+/// - `player_index` - The index of this player in the list of players.
+/// - `players_rw_signal` - A read/write signal to the current list of [`PlayerRecord`]s.
+#[component]
+pub fn Player(player_index: usize, players_rw_signal: RwSignal<Vec<PlayerRecord>>) -> impl IntoView {
+    // Memoize this player's champs (without lanes), so the champ list only re-renders when champs are added, removed
+    // or swapped.
+    let champ_ids: Memo<Vec<ChampId>> = create_memo(move |_| {
+        players_rw_signal.with(|players| {
+            players
+                .get(player_index)
+                .map(|player| player.champs.iter().map(|(champ_id, _)| *champ_id).collect())
+                .unwrap_or_default()
+        })
+    });
 
-impl Component for Player {
-    type Message = PlayerMsg;
+    // Derive the name of this player.
+    let player_name: Signal<String> = Signal::derive(move || {
+        players_rw_signal.with(|players| {
+            players
+                .get(player_index)
+                .and_then(|player| player.name.clone())
+                .unwrap_or_default()
+        })
+    });
 
-    type Properties = PlayerProps;
+    // Determine if this player is removable.
+    let disable_remove: Signal<bool> = Signal::derive(move || players_rw_signal.with(|players| players.len() <= 1));
 
-    fn create(ctx: &Context<Self>) -> Self {
-        Player
-    }
+    // Track whether the skinset exclusions are shown.
+    let show_exclusions: RwSignal<bool> = create_rw_signal(false);
 
-    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
-        match msg {
-            // Player Name change 
-            PlayerMsg::ChangeName { new_name } => {
-                Player::get_parent_app_scope(ctx)
-                    .send_message(AppMsg::PlayerNameUpdate { index: ctx.props().id, new_name });
-
-                // Do not re-render with name that user just input.
-                false
-            }
-
-            // Player removal
-            PlayerMsg::RemoveThisPlayer => {
-                Player::get_parent_app_scope(ctx)
-                    .send_message(AppMsg::RemovePlayer { player_index: ctx.props().id });
-
-                // Do not re-render this player if it has been removed. 
-                false
-            }
-        }
-    }
+    // Memoize the skinsets relevant to this player -- the ones any of their champs are in.
+    let relevant_skinsets: Memo<Vec<SkinsetId>> = create_memo(move |_| {
+        let mut skinsets: Vec<SkinsetId> = champ_ids
+            .get()
+            .into_iter()
+            .flat_map(|champ_id| champ_id.skinsets())
+            .collect::<HashSet<SkinsetId>>()
+            .into_iter()
+            .collect();
 
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        // Get the parent app scope so we can list the available champs for this player. 
-        let parent_app_scope = Player::get_parent_app_scope(ctx);
-        // Get the component so that we can get the list of champs for this player. 
-        let parent_app = parent_app_scope.get_component().expect("got parent app");
-        // Get the player record for this player. 
-        let player_record: &PlayerRecord = &parent_app.players[ctx.props().id];
-        // Get the list (in order) of champions anmd their lanes selected by this player. 
-        let champ_selections: &[(ChampId, BitFlags<Lane>)] = player_record.champs.as_slice();
-        // Determine if this player is removable
-        let enable_remove: bool = parent_app.players.len() > 1;
-        // Resolve the name of this player.
-        let player_name: AttrValue = player_record.name.clone().unwrap_or_default();
-
-        html! {
-            <div class={"card mt-2 bg-light text-dark"}>
-                <div class={"card-body row g-2 align-items-center w-100"}>
-                    <div class={"col-10"}>
-                        // Name field and handling
-                        <Name player_id={ctx.props().id} player_name={player_name} onchange={ctx.link().callback(|new_name| PlayerMsg::ChangeName { new_name })} />
-                    </div>
-                    <div class={"col-2"}>
-                        // Remove player button.
-                        <Button enable={enable_remove} on_click={ctx.link().callback(|_| PlayerMsg::RemoveThisPlayer)} class={"btn btn-danger w-100 fs-5 py-2"} >
-                            <Icon icon_id={IconId::BootstrapTrash} /> {" Remove Player"}
-                        </Button>
-                    </div>
-                </div>
+        skinsets.sort();
+        skinsets
+    });
 
-                // Champ selectors.
-                // <ul class={"list-group list-group-flush"}>
-                //     {
-                //         champ_selections.iter().map(|(champ_id, lanes)| html!{
-                //             <li class={"list-group-item"}>
-                //                 <ChampSelection
-                //                     change_champ_callback={change_champ_callback.clone()}
-                //                     other_available_champs={other_available_champs.clone()}
-                //                     lane_change_callback={lane_change_callback.clone()}
-                //                     remove_champ_callback={remove_champ_callback.clone()}
-
-                //                     selected_champ={
-                //                         Some((champ_name.clone(), *lanes))
-                //                     }
-                //                 />
-                //             </li>
-                //         }).collect::<Html>()
-                //     }
-                //     <li class={"list-group-item"}>
-                //         <ChampSelection
-                //             selected_champ={None}
-                //             change_champ_callback={change_champ_callback.clone()}
-                //             other_available_champs={other_available_champs.clone()}
-                //             // Leave the lane-change callback and remove champ callback no-ops
-                //             // because there should not be any lanes or champ data
-                //             // on an empty champ selector.
-                //             lane_change_callback={Callback::noop()}
-                //             remove_champ_callback={Callback::noop()}
-                //         />
-                //     </li>
-                // </ul>
-            </div>
-        }
-    }
-}
+    // Derive the number of skinsets this player has excluded.
+    let excluded_count: Signal<usize> = Signal::derive(move || {
+        players_rw_signal.with(|players| {
+            players.get(player_index).map(|player| player.excluded_skinsets.len()).unwrap_or_default()
+        })
+    });
 
-/*
-pub fn player(props: &PlayerProps) -> Html {
-    // Get an Rc'd list of all the other available champs.
-    let other_available_champs = GLOBAL_LANES_MAP.with(|lanes_map: &LanesMap| {
-        // Get a list of all champions
-        let all_champs = lanes_map.all_champs();
-        // Get a reference to the list of selected champs.
-        let selected_champs = props.champs.as_slice();
-        // Filter any selected champs out of the list of all champs.
-        let mut filtered_champs = all_champs
+    // Checkboxes for each relevant skinset -- checked means excluded.
+    let exclusion_checkboxes = move || {
+        relevant_skinsets
+            .get()
             .into_iter()
-            // Apply the filter
-            .filter(|champ| {
-                selected_champs
-                    .iter()
-                    .all(|(selected_champ, _)| selected_champ != *champ)
+            .map(|skinset_id: SkinsetId| {
+                let check_id: String = format!("player-{player_index}-skinset-{}-exclude", skinset_id.inner());
+
+                let excluded = Signal::derive(move || {
+                    players_rw_signal.with(|players| {
+                        players
+                            .get(player_index)
+                            .is_some_and(|player| player.excluded_skinsets.contains(&skinset_id))
+                    })
+                });
+
+                let toggle_excluded = move |checked: bool| {
+                    update_player(players_rw_signal, player_index, |player| {
+                        if checked {
+                            player.excluded_skinsets.insert(skinset_id);
+                        } else {
+                            player.excluded_skinsets.remove(&skinset_id);
+                        }
+                    })
+                };
+
+                view! {
+                    <div class="col form-check">
+                        <Checkbox checked=excluded id={check_id.clone()} on_change=toggle_excluded />
+                        <label class="form-check-label" for={check_id}> {skinset_id.skinset_name()} </label>
+                    </div>
+                }
             })
-            // Clone all the IStrings (cheap because they're reference counted).
-            .cloned()
-            // Collect into Vec
-            .collect::<Vec<_>>();
-        // Sort the list of filter champs alphabetically.
-        filtered_champs.sort();
-        // Return an Rc'd list
-        Rc::new(filtered_champs)
-    });
-
-    // Make a callback to get triggered when changing champs.
-    let change_champ_callback = {
-        // Clone the Rc ref to the champs list.
-        let champs = props.champs.clone();
-        // Clone the callback to emit when a champ list updates.
-        let callback = props.on_champ_list_update.clone();
-
-        Callback::from(move |(old_champ, new_champ): (Option<AttrValue>, String)| {
-            // Take the champ list to modify.
-            let mut list = Rc::try_unwrap(champs.clone()).unwrap_or_else(|e| (*e).clone());
-            // If there is a champ to replace, find and replace them.
-            if let Some(entry) = list
-                .iter_mut()
-                .find(|(champ, _)| Some(champ) == old_champ.as_ref())
-            {
-                entry.0 = new_champ.into();
-            } else {
-                // Get the default lanes for the champ.
-                let default_lanes =
-                    GLOBAL_LANES_MAP.with(|lanes_map| lanes_map.lanes_for_champ(&new_champ));
-                list.push((new_champ.into(), default_lanes));
-            }
-
-            // Emit a callback to update the parent.
-            callback.emit(list)
-        })
+            .collect_view()
     };
 
-    // Make a callback to get triggered when a champ's lanes change.
-    let lane_change_callback = {
-        // Clone the Rc ref to the champs list.
-        let champs = props.champs.clone();
-        // Clone the callback to emit when a champ list updates.
-        let callback = props.on_champ_list_update.clone();
-
-        Callback::from(
-            move |(champ_name, new_lanes): (AttrValue, BitFlags<Lane>)| {
-                // Get the current champ list.
-                let mut list = Rc::try_unwrap(champs.clone()).unwrap_or_else(|e| (*e).clone());
-                // Find the champ in the champ list.
-                let entry = list
-                    .iter_mut()
-                    .find(|(champ, _)| champ == &champ_name)
-                    .expect("found champ entry");
-
-                // Update their lanes.
-                entry.1 = new_lanes;
-                // Emit the callback to re-render.
-                callback.emit(list);
-            },
-        )
-    };
+    view! {
+        <div class="card mt-2 bg-light text-dark">
+            <div class="card-body row g-2 align-items-center w-100">
+                <div class="col-10">
+                    // Name field and handling
+                    <NameField
+                        player_index=player_index
+                        player_name=player_name
+                        on_change=move |new_name: String| {
+                            update_player(players_rw_signal, player_index, |player| {
+                                player.name = (!new_name.is_empty()).then_some(new_name);
+                            })
+                        }
+                    />
+                </div>
+                <div class="col-2">
+                    // Remove player button.
+                    <Button
+                        class="btn btn-danger w-100 fs-5 py-2"
+                        disabled=disable_remove
+                        on_click=move |_| players_rw_signal.update(|players| { players.remove(player_index); })
+                    >
+                        <Icon icon=BsTrash /> " Remove Player"
+                    </Button>
+                </div>
+            </div>
 
-    // Callback to be triggered when a champ is removed.
-    let remove_champ_callback = {
-        // Clone the Rc ref to the champs list.
-        let champs = props.champs.clone();
-        // Clone the callback to emit when a champ list updates.
-        let callback = props.on_champ_list_update.clone();
-
-        Callback::from(move |champ_name: AttrValue| {
-            // Get the current champ list.
-            let mut list = Rc::try_unwrap(champs.clone()).unwrap_or_else(|e| (*e).clone());
-
-            // Get the index of the champion to remove from the list.
-            let index_to_remove = list
-                .iter()
-                .enumerate()
-                .find(|(_, (champ, _))| champ == &champ_name)
-                .expect("Find champ to remove")
-                .0;
-
-            // Remove the champ at that index.
-            list.remove(index_to_remove);
-            // Emit the callback to update the parent.
-            callback.emit(list);
-        })
-    };
+            // Champ selectors.
+            <ul class="list-group list-group-flush">
+                <For
+                    each=move || champ_ids.get()
+                    key=|champ_id: &ChampId| *champ_id
+                    children=move |champ_id: ChampId| view! {
+                        <li class="list-group-item">
+                            <ChampSelection
+                                player_index=player_index
+                                champ_id=champ_id
+                                players_rw_signal=players_rw_signal
+                            />
+                        </li>
+                    }
+                />
+
+                // Dropdown to add a champ.
+                <li class="list-group-item">
+                    <div class="card-body">
+                        <ChampDropdown
+                            unavailable={Signal::derive(move || champ_ids.get().into_iter().collect::<HashSet<ChampId>>())}
+                            on_change=move |champ_id| {
+                                update_player(players_rw_signal, player_index, |player| player.add_champ(champ_id))
+                            }
+                        />
+                    </div>
+                </li>
+
+                // Champ and lane lock-in.
+                <li class="list-group-item">
+                    <LockSelect player_index=player_index players_rw_signal=players_rw_signal />
+                </li>
+
+                // Personal skinset exclusions.
+                <li class="list-group-item">
+                    <div class="card-body">
+                        <Button class="btn btn-outline-secondary w-100" on_click=move |_| show_exclusions.update(|s| *s = !*s)>
+                            "Skinsets This Player Won't Play (" {excluded_count} ") "
+                            {move || if show_exclusions.get() {
+                                view! { <Icon icon=BiChevronUpRegular /> }
+                            } else {
+                                view! { <Icon icon=BiChevronDownRegular /> }
+                            }}
+                        </Button>
 
-    html! {
-            }
+                        <Show when={move || show_exclusions.get()} fallback={move || view! {} }>
+                            <div class="row row-cols-4 mt-2">
+                                {exclusion_checkboxes}
+                            </div>
+                        </Show>
+                    </div>
+                </li>
+            </ul>
+        </div>
+    }
 }
-*/
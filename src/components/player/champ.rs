@@ -1,131 +1,153 @@
 //! Champion component used for player champ lists.
 
-use super::lanes_select::LaneSelect;
-use crate::components::{button::Button, player::champ_dropdown::ChampDropdown};
-use crate::lanes::Lane;
+use super::{champ_dropdown::ChampDropdown, lanes_select::LaneSelect, update_player};
+use crate::components::{button::Button, checkbox::Checkbox};
+use crate::constants::{ChampId, Lane, SkinId};
+use crate::model::PlayerRecord;
 use enumflags2::BitFlags;
-use std::rc::Rc;
-use yew::prelude::*;
-use yew_icons::{Icon, IconId};
-
-/// Champ Selection component including champion drop-down, lane checkboxes and button to remove champion from player.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct ChampSelection;
-
-/// Properties passed to the champ selection component.
-#[derive(Properties, PartialEq)]
-pub struct ChampSelectionProps {
-    /// The selected champion and lanes.
-    pub selected_champ: Option<(AttrValue, BitFlags<Lane>)>,
-    /// The list of other champions not currently selected by this player.
-    pub other_available_champs: Rc<Vec<AttrValue>>,
-    /// Callback to handle changes to the champ selected -- passes the previously selected champ for ease of use.
-    pub change_champ_callback: Callback<(Option<AttrValue>, String)>,
-    /// Callback to handle changes to the lanes selected.
-    pub lane_change_callback: Callback<(AttrValue, BitFlags<Lane>)>,
-    /// Callback to remove this champ from the player.
-    pub remove_champ_callback: Callback<AttrValue>,
-}
-
-/// Messages passed to the champ selection component by its callbacks.
-pub enum Msg {
-    /// Change the selected champion.
-    ChangeChamp { new_champ: String },
-
-    /// Change in the lanes for the selected champion.
-    ChangeLanes { new_lanes: BitFlags<Lane> },
-
-    /// Remove this champion from the player.
-    RemoveChamp,
-}
-
-impl Component for ChampSelection {
-    type Message = Msg;
-
-    type Properties = ChampSelectionProps;
-
-    fn create(_: &Context<Self>) -> Self {
-        Self
-    }
-
-    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
-        match msg {
-            Msg::ChangeChamp { new_champ } => {
-                // Get the currently selected champ.
-                let currently_selected: Option<AttrValue> = ctx
-                    .props()
-                    .selected_champ
-                    .as_ref()
-                    .map(|(champ_name, _)| champ_name.clone());
-
-                // Trigger the callback to the parent.
-                ctx.props()
-                    .change_champ_callback
-                    .emit((currently_selected, new_champ));
-            }
-
-            Msg::ChangeLanes { new_lanes } => {
-                // Get the currently selected champ name. Use unwrap here as lane selection should only be available
-                // on selected champs.
-                let current_champ_name: AttrValue =
-                    ctx.props().selected_champ.as_ref().unwrap().0.clone();
-
-                // Trigger the callback to the parent.
-                ctx.props()
-                    .lane_change_callback
-                    .emit((current_champ_name, new_lanes));
+use icondata::{BsTrash, BiChevronDownRegular, BiChevronUpRegular};
+use leptos::{
+    component, create_rw_signal, view, CollectView, IntoView, RwSignal, Show, Signal, SignalGet, SignalUpdate,
+    SignalWith,
+};
+use leptos_icons::Icon;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// Champ selection component including champion drop-down, lane checkboxes, owned skins and a button to remove the
+/// champion from the player.
+///
+/// # Arguments
+/// - `player_index` - The index of the player this champ belongs to.
+/// - `champ_id` - The champ selected.
+/// - `players_rw_signal` - A read/write signal to the current list of [`PlayerRecord`]s.
+#[component]
+pub fn ChampSelection(
+    player_index: usize,
+    champ_id: ChampId,
+    players_rw_signal: RwSignal<Vec<PlayerRecord>>,
+) -> impl IntoView {
+    // Derive the lanes selected for this champ.
+    let lanes: Signal<BitFlags<Lane>> = Signal::derive(move || {
+        players_rw_signal.with(|players| {
+            players
+                .get(player_index)
+                .and_then(|player| player.champs.iter().find(|(iter_champ_id, _)| *iter_champ_id == champ_id))
+                .map(|(_, lanes)| *lanes)
+                .unwrap_or_default()
+        })
+    });
+
+    // Derive the set of champs this player already has, which can't be selected again.
+    let unavailable: Signal<HashSet<ChampId>> = Signal::derive(move || {
+        players_rw_signal.with(|players| {
+            players
+                .get(player_index)
+                .map(|player| player.champs.iter().map(|(iter_champ_id, _)| *iter_champ_id).collect())
+                .unwrap_or_default()
+        })
+    });
+
+    // Track whether the owned skins list is shown.
+    let show_skins: RwSignal<bool> = create_rw_signal(false);
+
+    // Count this champ's skins.
+    let skin_count: usize = unique_skins(champ_id).len();
+
+    // Derive how many of this champ's skins the player owns.
+    let owned_count: Signal<usize> = Signal::derive(move || {
+        players_rw_signal.with(|players| {
+            players
+                .get(player_index)
+                .map(|player| unique_skins(champ_id).iter().filter(|skin_id| player.owned_skins.contains(skin_id)).count())
+                .unwrap_or_default()
+        })
+    });
+
+    // Checkboxes to mark skins as owned.
+    let skin_checkboxes = move || unique_skins(champ_id)
+        .into_iter()
+        .map(|skin_id: SkinId| {
+            let check_id: String = Uuid::new_v4().to_string();
+
+            let owned = Signal::derive(move || {
+                players_rw_signal.with(|players| {
+                    players.get(player_index).is_some_and(|player| player.owned_skins.contains(&skin_id))
+                })
+            });
+
+            let toggle_owned = move |checked: bool| {
+                update_player(players_rw_signal, player_index, |player| {
+                    if checked {
+                        player.owned_skins.insert(skin_id);
+                    } else {
+                        player.owned_skins.remove(&skin_id);
+                    }
+                })
+            };
+
+            view! {
+                <div class="col form-check">
+                    <Checkbox checked=owned id={check_id.clone()} on_change=toggle_owned />
+                    <label class="form-check-label" for={check_id}> {skin_id.skin_name()} </label>
+                </div>
             }
-
-            Msg::RemoveChamp => {
-                // Get the currently selected champ name. Use unwrap here as lane selection should only be available
-                // on selected champs.
-                let current_champ_name: AttrValue =
-                    ctx.props().selected_champ.as_ref().unwrap().0.clone();
-
-                // Trigger the callback to the parent.
-                ctx.props().remove_champ_callback.emit(current_champ_name);
-            }
-        }
-
-        // Never cause a direct re-render of this object.
-        // Always pass to the parent object to re-render.
-        false
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        // Get the properties passed to the component.
-        let ChampSelectionProps {
-            selected_champ,
-            other_available_champs,
-            ..
-        } = ctx.props();
-
-        // Get the selected champ name.
-        let selected_champ_name = selected_champ
-            .as_ref()
-            .map(|(champ_name, _)| champ_name.clone());
-
-        html! {
-            <div class={"card-body"}>
-                <ChampDropdown
-                    selected_champ={selected_champ_name.clone()}
-                    other_available_champs={other_available_champs.clone()}
-                    on_change={ ctx.link().callback(|new_champ| Msg::ChangeChamp { new_champ }) }
+        })
+        .collect_view();
+
+    view! {
+        <div class="card-body">
+            <ChampDropdown
+                selected=champ_id
+                unavailable=unavailable
+                on_change=move |new_champ_id| {
+                    update_player(players_rw_signal, player_index, |player| player.replace_champ(champ_id, new_champ_id))
+                }
+            />
+
+            <div class="my-1 align-items-center">
+                <LaneSelect
+                    lanes=lanes
+                    on_change=move |new_lanes| {
+                        update_player(players_rw_signal, player_index, |player| {
+                            player.update_champ(champ_id, new_lanes);
+                        })
+                    }
                 />
+            </div>
 
-                if selected_champ.is_some() {
-                    <div class={"my-1 align-items-center"}>
-                        <LaneSelect
-                            lanes={selected_champ.clone().unwrap().1}
-                            update_lanes_callback={ ctx.link().callback(|new_lanes| Msg::ChangeLanes { new_lanes }) }
-                        />
-                    </div>
-
-                    <Button class={"btn btn-danger w-100"} enable={true} on_click={ ctx.link().callback(|_| Msg::RemoveChamp) }>
-                        <Icon icon_id={IconId::BootstrapTrash} /> {" Remove "} {selected_champ_name.clone()}
-                    </Button>
-                }
+            <div class="d-flex gap-2">
+                // Show/hide owned skins.
+                <Button class="btn btn-outline-secondary flex-grow-1" on_click=move |_| show_skins.update(|s| *s = !*s)>
+                    "Owned Skins (" {owned_count} "/" {skin_count} ") "
+                    {move || if show_skins.get() {
+                        view! { <Icon icon=BiChevronUpRegular /> }
+                    } else {
+                        view! { <Icon icon=BiChevronDownRegular /> }
+                    }}
+                </Button>
+
+                <Button
+                    class="btn btn-danger flex-grow-1"
+                    on_click=move |_| update_player(players_rw_signal, player_index, |player| player.remove_champ(champ_id))
+                >
+                    <Icon icon=BsTrash /> " Remove " {champ_id.champ_name()}
+                </Button>
             </div>
-        }
+
+            <Show when={move || show_skins.get()} fallback={move || view! {} }>
+                <div class="row row-cols-4 mt-2">
+                    {skin_checkboxes}
+                </div>
+            </Show>
+        </div>
     }
 }
+
+/// Get all of a champ's skins, without duplicates for skins in multiple skinsets.
+fn unique_skins(champ_id: ChampId) -> Vec<SkinId> {
+    let mut skins: Vec<SkinId> = champ_id.skins().map(|(_, skin_id)| skin_id).collect();
+    skins.sort();
+    skins.dedup();
+    skins
+}
@@ -1,111 +1,81 @@
 //! Champion dropdown component.
 
-use std::rc::Rc;
-use uuid::Uuid;
-use web_sys::HtmlSelectElement;
-use yew::prelude::*;
 use crate::constants::ChampId;
-
-/// Properties passed to the champion dropdown component.
-#[derive(Properties, PartialEq)]
-pub struct ChampDropdownProps {
-    /// The selected champ, if there is one.
-    pub selected_champ: Option<ChampId>,
-    /// Shared list of all other available champs to select from
-    pub other_available_champs: Rc<[ChampId]>,
-    /// Callback emitted when a new champ is selected.
-    pub on_change: Callback<ChampId>,
-}
+use leptos::{
+    component, create_node_ref, event_target_value, html::Select, view, Callable, Callback, CollectView, IntoView,
+    NodeRef, Signal, SignalWith,
+};
+use std::collections::HashSet;
+use uuid::Uuid;
 
 /// Dropdown component to choose a champ from.
-#[derive(Debug)]
-pub struct ChampDropdown {
-    /// Node ref used to identify the select element.
-    select_ref: NodeRef,
-}
-
-impl Component for ChampDropdown {
-    type Message = ();
-
-    type Properties = ChampDropdownProps;
-
-    fn create(_: &Context<Self>) -> Self {
-        ChampDropdown {
-            select_ref: NodeRef::default(),
+///
+/// # Arguments
+/// - `selected` - The selected champ, if there is one. When there isn't, the dropdown resets after every selection so
+///   it can be used to add champs.
+/// - `unavailable` - Champs that should not be listed (e.g. because the player already has them).
+/// - `on_change` - Callback triggered with the newly selected champ.
+#[component]
+pub fn ChampDropdown(
+    #[prop(optional)]
+    selected: Option<ChampId>,
+    #[prop(into)]
+    unavailable: Signal<HashSet<ChampId>>,
+    #[prop(into)]
+    on_change: Callback<ChampId>,
+) -> impl IntoView {
+    // Make a node ID to connect the select to the label.
+    let select_id: String = Uuid::new_v4().to_string();
+    // Node ref used to reset the dropdown after adding a champ.
+    let select_ref: NodeRef<Select> = create_node_ref::<Select>();
+
+    // Handler for a champ being selected.
+    let on_select = move |ev| {
+        if let Some(champ_id) = ChampId::from_champ_name(&event_target_value(&ev)) {
+            on_change.call(champ_id);
         }
-    }
 
-    // Custom `changed` implementation needed to deal with stupid select/auto-fill issue on firefox.
-    fn changed(&mut self, ctx: &Context<Self>, _: &Self::Properties) -> bool {
-        if let Some(select) = self.select_ref.cast::<HtmlSelectElement>() {
-            if let Some(selected_champ) = ctx.props().selected_champ {
-                select.set_value(selected_champ.champ_name());
-            } else {
-                select.set_value("Select a champion...");
+        // Go back to the placeholder if this dropdown is for adding champs.
+        if selected.is_none() {
+            if let Some(select) = select_ref.get_untracked() {
+                select.set_selected_index(0);
             }
         }
-
-        true
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        // Get a reference to the properties passed to this component.
-        let props = ctx.props();
-        // Make a node ID to connect the select to the label.
-        let select_id: AttrValue = Uuid::new_v4().to_string().into();
-
-        // Make the event handler callback to be triggered when a champ is selected.
-        let onchange = {
-            // Clone the callback to pass the selected champ to.
-            let callback = props.on_change.clone();
-            // Clone the reference to the select node that we're waiting for a change on.
-            let select_node_ref = self.select_ref.clone();
-
-            Callback::from(move |_| {
-                if let Some(select) = select_node_ref.cast::<HtmlSelectElement>() {
-                    callback.emit(select.value());
-                }
-            })
-        };
-
-        // Make a list of all the champs and collect to a vec to keep as alphabetical.
-        let mut all_listed_champs_alphabetical: Vec<&AttrValue> = props
-            .other_available_champs
-            .iter()
-            .chain(props.selected_champ.iter())
-            .collect();
-
-        all_listed_champs_alphabetical.sort();
-
-        html! {
-            // Form tag in here is necessary to prevent firefox from auto selecting an option
-            <div class="form-floating">
-                <select ref={self.select_ref.clone()} id={select_id.clone()} class={"form-select"} aria-label={"Champion Selection"} {onchange} autocomplete="off">
-                    // If there is no selected champs
-                    if props.selected_champ.is_none() {
-                        <option selected={true} disabled={true}>
-                            {"Select a champion..."}
-                        </option>
+    };
+
+    // List every champ that's either selected or available, in alphabetical order.
+    let options = move || {
+        unavailable.with(|unavailable| {
+            ChampId::iter_all()
+                .filter(|champ_id| Some(*champ_id) == selected || !unavailable.contains(champ_id))
+                .map(|champ_id| {
+                    view! {
+                        <option selected={Some(champ_id) == selected}> {champ_id.champ_name()} </option>
                     }
-
-                    // All champs in list (handle for selections)
-                    {
-                        all_listed_champs_alphabetical
-                            .iter()
-                            .map(|champ_name| {
-                                html! {
-                                    <option selected={Some(champ_name) == props.selected_champ.clone().as_ref().as_ref()}>
-                                        {champ_name}
-                                    </option>
-                                }
-                            })
-                            .collect::<Html>()
-                    }
-
-                </select>
-
-                <label for={select_id}> {"Select a champion..."} </label>
-            </div>
-        }
+                })
+                .collect_view()
+        })
+    };
+
+    view! {
+        <div class="form-floating">
+            <select
+                _ref=select_ref
+                id={select_id.clone()}
+                class="form-select"
+                aria-label="Champion Selection"
+                autocomplete="off"
+                on:change=on_select
+            >
+                // Placeholder when there's no selected champ.
+                {selected.is_none().then(|| view! {
+                    <option selected=true disabled=true value=""> "Select a champion..." </option>
+                })}
+
+                {options}
+            </select>
+
+            <label for={select_id}> "Select a champion..." </label>
+        </div>
     }
 }
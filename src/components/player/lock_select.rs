@@ -0,0 +1,88 @@
+//! Lock-in selector for a player's champ and lane.
+
+use super::update_player;
+use crate::components::button::Button;
+use crate::constants::{ChampId, Lane};
+use crate::model::{LockIn, PlayerRecord};
+use enumflags2::BitFlags;
+use icondata::{BiLockAltRegular, BiLockOpenAltRegular};
+use leptos::{component, event_target_value, view, CollectView, IntoView, RwSignal, Signal, SignalGet, SignalWith};
+use leptos_icons::Icon;
+
+/// Lock-in selector with a dropdown for the locked champ (from the player's champs), a dropdown for the locked lane,
+/// and a button to clear both.
+///
+/// # Arguments
+/// - `player_index` - The index of the player this lock belongs to.
+/// - `players_rw_signal` - A read/write signal to the current list of [`PlayerRecord`]s.
+#[component]
+pub fn LockSelect(player_index: usize, players_rw_signal: RwSignal<Vec<PlayerRecord>>) -> impl IntoView {
+    // Derive this player's lock-in.
+    let lock: Signal<LockIn> = Signal::derive(move || {
+        players_rw_signal.with(|players| players.get(player_index).map(|player| player.lock).unwrap_or_default())
+    });
+
+    // Options for each of this player's champs.
+    let champ_options = move || {
+        players_rw_signal.with(|players| {
+            players
+                .get(player_index)
+                .map(|player| player.champs.iter().map(|(champ_id, _)| *champ_id).collect::<Vec<ChampId>>())
+                .unwrap_or_default()
+                .into_iter()
+                .map(|champ_id| view! {
+                    <option value={champ_id.champ_name()} selected={move || lock.get().champ == Some(champ_id)}>
+                        {champ_id.champ_name()}
+                    </option>
+                })
+                .collect_view()
+        })
+    };
+
+    // Options for each lane.
+    let lane_options = BitFlags::<Lane>::all()
+        .iter()
+        .map(|lane: Lane| view! {
+            <option value={lane.to_string()} selected={move || lock.get().lane == Some(lane)}> {lane.to_string()} </option>
+        })
+        .collect_view();
+
+    let on_champ = move |ev| {
+        let champ: Option<ChampId> = ChampId::from_champ_name(&event_target_value(&ev));
+        update_player(players_rw_signal, player_index, |player| player.lock.champ = champ)
+    };
+
+    let on_lane = move |ev| {
+        let value: String = event_target_value(&ev);
+        let lane: Option<Lane> = BitFlags::<Lane>::all().iter().find(|lane| lane.to_string() == value);
+        update_player(players_rw_signal, player_index, |player| player.lock.lane = lane)
+    };
+
+    view! {
+        <div class="card-body d-flex gap-2 align-items-center">
+            {move || if lock.get().is_empty() {
+                view! { <Icon icon=BiLockOpenAltRegular /> }
+            } else {
+                view! { <Icon icon=BiLockAltRegular /> }
+            }}
+
+            <select class="form-select" aria-label="Locked champ" on:change=on_champ>
+                <option value="" selected={move || lock.get().champ.is_none()}> "Any champ" </option>
+                {champ_options}
+            </select>
+
+            <select class="form-select" aria-label="Locked lane" on:change=on_lane>
+                <option value="" selected={move || lock.get().lane.is_none()}> "Any lane" </option>
+                {lane_options}
+            </select>
+
+            <Button
+                class="btn btn-outline-secondary text-nowrap"
+                disabled={Signal::derive(move || lock.get().is_empty())}
+                on_click=move |_| update_player(players_rw_signal, player_index, |player| player.lock = LockIn::default())
+            >
+                "Unlock"
+            </Button>
+        </div>
+    }
+}
@@ -0,0 +1,93 @@
+//! Search box for the text query language, showing where any error in the query is and offering fixes.
+
+use crate::query::QueryError;
+use leptos::{
+    component, event_target_value, view, CollectView, IntoView, RwSignal, Show, Signal, SignalGet, SignalGetUntracked,
+    SignalSet, SignalWith,
+};
+
+/// Replace the text in a span of the query with a suggestion, quoting it if it has spaces.
+fn apply_suggestion(query: &str, err: &QueryError, suggestion: &str) -> String {
+    let replacement: String =
+        if suggestion.contains(' ') { format!("\"{suggestion}\"") } else { suggestion.to_owned() };
+    format!("{}{replacement}{}", &query[..err.span.start], &query[err.span.end..])
+}
+
+/// Query search box.
+///
+/// # Arguments
+/// - `query_text` - A read/write signal to the text of the query.
+/// - `error` - The error in the current query, if it doesn't parse.
+#[component]
+pub fn QuerySearch(
+    query_text: RwSignal<String>,
+    #[prop(into)]
+    error: Signal<Option<QueryError>>,
+) -> impl IntoView {
+    // The query with the text in error highlighted.
+    let highlighted = move || {
+        let query: String = query_text.get();
+
+        error.with(|err| {
+            err.as_ref().and_then(|err| {
+                let (before, rest) = query.split_at_checked(err.span.start)?;
+                let (marked, after) = rest.split_at_checked(err.span.len())?;
+
+                // Errors at the end of the query have an empty span, so mark a space to show where.
+                let marked: String = if marked.is_empty() { " ".to_owned() } else { marked.to_owned() };
+
+                Some(view! {
+                    <code class="d-block my-1">
+                        {before.to_owned()}
+                        <mark class="bg-danger-subtle"> {marked} </mark>
+                        {after.to_owned()}
+                    </code>
+                })
+            })
+        })
+    };
+
+    // Buttons to replace the text in error with each suggestion.
+    let suggestions = move || {
+        error.with(|err| {
+            err.iter()
+                .flat_map(|err| err.suggestions.iter().map(move |suggestion| (err.clone(), suggestion.clone())))
+                .map(|(err, suggestion)| {
+                    let label: String = suggestion.clone();
+                    let on_click = move |_| {
+                        query_text.set(apply_suggestion(&query_text.get_untracked(), &err, &suggestion));
+                    };
+
+                    view! {
+                        <button class="btn btn-sm btn-outline-secondary ms-1" on:click=on_click> {label} </button>
+                    }
+                })
+                .collect_view()
+        })
+    };
+
+    view! {
+        <div class="mb-2">
+            <input
+                type="search"
+                class="form-control font-monospace"
+                class:is-invalid={move || error.with(Option::is_some)}
+                placeholder="Search, e.g. skinset:\"Star Guardian\" Venus:support champ:Lux -skinset:Legacy lanes:any"
+                aria-label="Search query"
+                prop:value={move || query_text.get()}
+                on:input=move |ev| query_text.set(event_target_value(&ev))
+            />
+
+            <Show when={move || error.with(Option::is_some)} fallback={move || view! {} }>
+                <div class="small text-danger">
+                    {highlighted}
+                    {move || error.with(|err| err.as_ref().map(|err| err.message.clone()))}
+                    <Show when={move || error.with(|err| err.as_ref().is_some_and(|err| !err.suggestions.is_empty()))}
+                        fallback={move || view! {} }>
+                        " -- did you mean" {suggestions} "?"
+                    </Show>
+                </div>
+            </Show>
+        </div>
+    }
+}
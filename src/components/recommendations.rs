@@ -0,0 +1,178 @@
+//! Champ recommendations -- which champ each player should learn next to open up the most skinsets or comps.
+
+use crate::{
+    components::button::Button,
+    constants::SkinsetId,
+    draft::Draft,
+    model::{PlayerRecord, SolverSettings},
+    recommend::{self, RankedRecommendations, RecommendBy, Recommendation, MAX_OTHER_COMPS},
+};
+use icondata::{BiChevronDownRegular, BiChevronUpRegular};
+use instant::Instant;
+use leptos::{
+    component, create_memo, create_rw_signal, event_target_value, view, CollectView, IntoView, Memo, RwSignal, Show,
+    Signal, SignalGet, SignalGetUntracked, SignalSet, SignalUpdate, SignalWith,
+};
+use leptos_icons::Icon;
+use std::collections::HashSet;
+
+/// Card listing the best champs for a player to add to their pool.
+///
+/// # Arguments
+/// - `players_rw_signal` - A read/write signal to the current list of [`PlayerRecord`]s.
+/// - `skinsets_rw_signal` - A read/write signal to the set of skinsets included in the search.
+/// - `settings` - The settings to resolve comps with.
+/// - `draft` - The champ select draft, whose champs are left out of every player's options.
+#[component]
+pub fn Recommendations(
+    players_rw_signal: RwSignal<Vec<PlayerRecord>>,
+    skinsets_rw_signal: RwSignal<HashSet<SkinsetId>>,
+    #[prop(into)]
+    settings: Signal<SolverSettings>,
+    #[prop(into)]
+    draft: Signal<Draft>,
+) -> impl IntoView {
+    // Whether the recommendations are shown. They're only computed while shown, since it means solving once per champ.
+    let show: RwSignal<bool> = create_rw_signal(false);
+
+    // The player to recommend champs for, and how to rank them.
+    let player_index: RwSignal<usize> = create_rw_signal(0);
+    let rank_by: RwSignal<RecommendBy> = create_rw_signal(RecommendBy::default());
+
+    let recommendations: Memo<RankedRecommendations> = create_memo(move |_| {
+        if !show.get() {
+            return RankedRecommendations::default();
+        }
+
+        let start = Instant::now();
+
+        let recommendations: RankedRecommendations = players_rw_signal.with(|players| {
+            let players: Vec<PlayerRecord> = draft.with(|draft| draft.apply(players));
+            skinsets_rw_signal.with(|skinsets| {
+                recommend::recommend_champs(&players, player_index.get(), skinsets, settings.get(), rank_by.get())
+            })
+        });
+
+        log::info!("Ranked champ recommendations in {:?}", Instant::now() - start);
+        recommendations
+    });
+
+    // Options for each player.
+    let player_options = move || {
+        players_rw_signal.with(|players| {
+            players
+                .iter()
+                .enumerate()
+                .map(|(index, player)| view! {
+                    <option value={index.to_string()} selected={move || player_index.get() == index}>
+                        {player.display_name(index)}
+                    </option>
+                })
+                .collect_view()
+        })
+    };
+
+    // Toggle buttons to choose the ranking.
+    let rank_select = move || RecommendBy::ALL
+        .iter()
+        .map(|option: &RecommendBy| {
+            let option: RecommendBy = *option;
+            let radio_id: String = format!("recommend-by-{option:?}");
+
+            view! {
+                <input
+                    type="radio"
+                    class="btn-check"
+                    name="recommend-by"
+                    id={radio_id.clone()}
+                    prop:checked={move || rank_by.get() == option}
+                    on:change=move |_| rank_by.set(option)
+                />
+                <label class="btn btn-outline-secondary" for={radio_id}> {option.to_string()} </label>
+            }
+        })
+        .collect_view();
+
+    // A row for each recommendation, with a button to add the champ to the player.
+    let rows = move || {
+        recommendations
+            .get()
+            .recommendations
+            .into_iter()
+            .map(|recommendation: Recommendation| {
+                let champ_id = recommendation.champ;
+                let lanes: Vec<String> = recommendation.lanes.iter().map(|lane| lane.to_string()).collect();
+                let skinsets: String = recommendation
+                    .unlocked_skinsets
+                    .iter()
+                    .map(|skinset_id| skinset_id.skinset_name())
+                    .collect::<Vec<&str>>()
+                    .join(", ");
+
+                let add_champ = move |_| {
+                    players_rw_signal.update(|players| {
+                        if let Some(player) = players.get_mut(player_index.get_untracked()) {
+                            player.add_champ(champ_id);
+                        }
+                    })
+                };
+
+                view! {
+                    <li class="list-group-item d-flex align-items-center gap-2">
+                        <div class="flex-grow-1">
+                            <strong> {champ_id.champ_name()} </strong> " (" {lanes.join("/")} ") -- "
+                            "+" {recommendation.unlocked_skinsets.len()} " skinsets, "
+                            "+" {recommendation.new_comps} " comps"
+                            <div class="text-muted small"> {skinsets} </div>
+                        </div>
+
+                        <Button class="btn btn-sm btn-outline-success" on_click=add_champ> "Add" </Button>
+                    </li>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <div class="card bg-light text-dark my-2">
+            <div class="card-body">
+                <Button class="btn btn-outline-secondary w-100" on_click=move |_| show.update(|s| *s = !*s)>
+                    "Which Champ Should We Learn Next? "
+                    {move || if show.get() {
+                        view! { <Icon icon=BiChevronUpRegular /> }
+                    } else {
+                        view! { <Icon icon=BiChevronDownRegular /> }
+                    }}
+                </Button>
+
+                <Show when={move || show.get()} fallback={move || view! {} }>
+                    <div class="d-flex align-items-center gap-2 my-2">
+                        <select
+                            class="form-select w-auto"
+                            aria-label="Player to recommend champs for"
+                            on:change=move |ev| player_index.set(event_target_value(&ev).parse().unwrap_or_default())
+                        >
+                            {player_options}
+                        </select>
+
+                        <div class="btn-group" role="group" aria-label="Rank recommendations by"> {rank_select} </div>
+                    </div>
+
+                    <Show when={move || recommendations.with(|ranked| ranked.approximate)} fallback={move || view! {} }>
+                        <p class="text-muted small">
+                            "The other players have more than " {MAX_OTHER_COMPS} " comps, so only some were "
+                            "considered -- these gains are estimates."
+                        </p>
+                    </Show>
+
+                    <Show
+                        when={move || recommendations.with(|ranked| !ranked.recommendations.is_empty())}
+                        fallback={move || view! { <p class="text-muted"> "No single champ would add anything." </p> }}
+                    >
+                        <ol class="list-group list-group-numbered"> {rows} </ol>
+                    </Show>
+                </Show>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,235 @@
+//! Controls to filter and sort the results table.
+
+use crate::{
+    components::button::Button,
+    constants::{ChampId, Lane, SkinsetId},
+    filters::{ResultFilter, ResultSort, SortKey},
+    model::PlayerRecord,
+    solver::Comp,
+};
+use enumflags2::BitFlags;
+use icondata::{BiSortDownRegular, BiSortUpRegular};
+use leptos::{
+    component, event_target_value, view, CollectView, IntoView, RwSignal, Signal, SignalGet, SignalSet, SignalUpdate,
+    SignalWith,
+};
+use leptos_icons::Icon;
+
+/// Encode a sort key as the value of its dropdown option.
+fn sort_key_value(key: Option<SortKey>) -> String {
+    match key {
+        None => String::new(),
+        Some(SortKey::PlayerChamp(player_index)) => format!("champ-{player_index}"),
+        Some(SortKey::PlayerLane(player_index)) => format!("lane-{player_index}"),
+        Some(SortKey::SkinsetCount) => "skinsets".to_owned(),
+    }
+}
+
+/// Decode a sort key from the value of its dropdown option.
+fn sort_key_from_value(value: &str) -> Option<SortKey> {
+    if value == "skinsets" {
+        return Some(SortKey::SkinsetCount);
+    }
+
+    let (kind, player_index) = value.split_once('-')?;
+    let player_index: usize = player_index.parse().ok()?;
+
+    match kind {
+        "champ" => Some(SortKey::PlayerChamp(player_index)),
+        "lane" => Some(SortKey::PlayerLane(player_index)),
+        _ => None,
+    }
+}
+
+/// Filter and sort controls for the results table.
+///
+/// # Arguments
+/// - `players_rw_signal` - A read/write signal to the current list of [`PlayerRecord`]s.
+/// - `comps` - The unfiltered comps, used to list the champs and skinsets that can be filtered on.
+/// - `filter` - A read/write signal to the current [`ResultFilter`].
+/// - `sort` - A read/write signal to the current [`ResultSort`], if the results are sorted.
+#[component]
+pub fn ResultFilters(
+    players_rw_signal: RwSignal<Vec<PlayerRecord>>,
+    #[prop(into)]
+    comps: Signal<Vec<Comp>>,
+    filter: RwSignal<ResultFilter>,
+    sort: RwSignal<Option<ResultSort>>,
+) -> impl IntoView {
+    // Names of the current players.
+    let player_names = move || -> Vec<String> {
+        players_rw_signal.with(|players| {
+            players.iter().enumerate().map(|(index, player)| player.display_name(index)).collect()
+        })
+    };
+
+    // Options for every champ in the results.
+    let champ_options = move || {
+        let mut champs: Vec<ChampId> =
+            comps.with(|comps| comps.iter().flat_map(|comp| comp.picks.iter().map(|pick| pick.champ)).collect());
+        champs.sort();
+        champs.dedup();
+
+        champs
+            .into_iter()
+            .map(|champ_id| view! {
+                <option value={champ_id.champ_name()} selected={move || filter.get().champ == Some(champ_id)}>
+                    {champ_id.champ_name()}
+                </option>
+            })
+            .collect_view()
+    };
+
+    // Options for every skinset in the results.
+    let skinset_options = move || {
+        let mut skinsets: Vec<SkinsetId> =
+            comps.with(|comps| comps.iter().flat_map(|comp| comp.skinsets.iter().copied()).collect());
+        skinsets.sort();
+        skinsets.dedup();
+
+        skinsets
+            .into_iter()
+            .map(|skinset_id| view! {
+                <option value={skinset_id.skinset_name()} selected={move || filter.get().skinset == Some(skinset_id)}>
+                    {skinset_id.skinset_name()}
+                </option>
+            })
+            .collect_view()
+    };
+
+    // Options for every player and lane.
+    let player_lane_options = move || {
+        player_names()
+            .into_iter()
+            .enumerate()
+            .flat_map(|(player_index, player_name)| {
+                BitFlags::<Lane>::all().iter().map(move |lane| (player_index, player_name.clone(), lane))
+            })
+            .map(|(player_index, player_name, lane)| view! {
+                <option
+                    value={format!("{player_index}-{lane}")}
+                    selected={move || filter.get().player_lane == Some((player_index, lane))}
+                >
+                    {player_name} " in " {lane.to_string()}
+                </option>
+            })
+            .collect_view()
+    };
+
+    // Options for every sort.
+    let sort_options = move || {
+        let mut options: Vec<(SortKey, String)> = Vec::new();
+
+        for (player_index, player_name) in player_names().into_iter().enumerate() {
+            options.push((SortKey::PlayerChamp(player_index), format!("{player_name}'s champ")));
+            options.push((SortKey::PlayerLane(player_index), format!("{player_name}'s lane")));
+        }
+
+        options.push((SortKey::SkinsetCount, "Skinset count".to_owned()));
+
+        options
+            .into_iter()
+            .map(|(key, label)| view! {
+                <option value={sort_key_value(Some(key))} selected={move || sort.get().map(|sort| sort.key) == Some(key)}>
+                    {label}
+                </option>
+            })
+            .collect_view()
+    };
+
+    // Handlers for each control.
+    let on_champ = move |ev| {
+        filter.update(|filter| filter.champ = ChampId::from_champ_name(&event_target_value(&ev)))
+    };
+
+    let on_player_lane = move |ev| {
+        let value: String = event_target_value(&ev);
+
+        let player_lane: Option<(usize, Lane)> = value.split_once('-').and_then(|(player_index, lane_name)| {
+            let lane: Lane = BitFlags::<Lane>::all().iter().find(|lane| lane.to_string() == lane_name)?;
+            Some((player_index.parse().ok()?, lane))
+        });
+
+        filter.update(|filter| filter.player_lane = player_lane)
+    };
+
+    let on_skinset = move |ev| {
+        filter.update(|filter| filter.skinset = SkinsetId::from_skinset_name(&event_target_value(&ev)))
+    };
+
+    let on_min_skinsets = move |ev| {
+        let min_skinsets: usize = event_target_value(&ev).parse().unwrap_or_default();
+        filter.update(|filter| filter.min_skinsets = min_skinsets)
+    };
+
+    let on_sort = move |ev| {
+        let descending: bool = sort.get().is_some_and(|sort| sort.descending);
+        sort.set(sort_key_from_value(&event_target_value(&ev)).map(|key| ResultSort { key, descending }))
+    };
+
+    let toggle_descending = move |_| {
+        sort.update(|sort| {
+            if let Some(sort) = sort {
+                sort.descending = !sort.descending;
+            }
+        })
+    };
+
+    view! {
+        <div class="row g-2 mb-2 align-items-center">
+            <div class="col-md">
+                <select class="form-select form-select-sm" aria-label="Filter by champ" on:change=on_champ>
+                    <option value="" selected={move || filter.get().champ.is_none()}> "Any champ" </option>
+                    {champ_options}
+                </select>
+            </div>
+
+            <div class="col-md">
+                <select class="form-select form-select-sm" aria-label="Filter by player lane" on:change=on_player_lane>
+                    <option value="" selected={move || filter.get().player_lane.is_none()}> "Any lanes" </option>
+                    {player_lane_options}
+                </select>
+            </div>
+
+            <div class="col-md">
+                <select class="form-select form-select-sm" aria-label="Filter by skinset" on:change=on_skinset>
+                    <option value="" selected={move || filter.get().skinset.is_none()}> "Any skinset" </option>
+                    {skinset_options}
+                </select>
+            </div>
+
+            <div class="col-md">
+                <div class="input-group input-group-sm">
+                    <span class="input-group-text"> "Min. skinsets" </span>
+                    <input
+                        type="number"
+                        min="0"
+                        class="form-control"
+                        aria-label="Minimum shared skinsets"
+                        prop:value={move || filter.get().min_skinsets.to_string()}
+                        on:input=on_min_skinsets
+                    />
+                </div>
+            </div>
+
+            <div class="col-md d-flex gap-1">
+                <select class="form-select form-select-sm" aria-label="Sort results" on:change=on_sort>
+                    <option value={sort_key_value(None)} selected={move || sort.get().is_none()}> "Unsorted" </option>
+                    {sort_options}
+                </select>
+
+                <Button
+                    class="btn btn-sm btn-outline-secondary"
+                    disabled={Signal::derive(move || sort.get().is_none())}
+                    on_click=toggle_descending
+                >
+                    {move || if sort.get().is_some_and(|sort| sort.descending) {
+                        view! { <Icon icon=BiSortDownRegular /> }
+                    } else {
+                        view! { <Icon icon=BiSortUpRegular /> }
+                    }}
+                </Button>
+            </div>
+        </div>
+    }
+}
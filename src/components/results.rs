@@ -0,0 +1,128 @@
+//! The results card, which shows the resolved comps in one of several views.
+
+use crate::{
+    components::{results_table::ResultsTable, skinset_results::SkinsetResults},
+    constants::SkinsetId,
+    draft::Draft,
+    filters::{ResultFilter, ResultSort, SortKey},
+    model::{PlayerRecord, SolverSettings},
+};
+use derive_more::Display;
+use leptos::{
+    component, create_effect, create_rw_signal, view, CollectView, IntoView, RwSignal, Signal, SignalGet,
+    SignalGetUntracked, SignalSet, SignalUpdate, SignalWith, SignalWithUntracked,
+};
+use std::collections::HashSet;
+
+/// The ways the results can be shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+enum ResultsView {
+    /// One row per comp.
+    #[display(fmt = "By Comp")]
+    ByComp,
+
+    /// One card per skinset, with each player's options in it.
+    #[display(fmt = "By Skinset")]
+    BySkinset,
+}
+
+impl ResultsView {
+    /// All the views, in the order they're listed in the UI.
+    const ALL: [Self; 2] = [Self::ByComp, Self::BySkinset];
+}
+
+/// The results card.
+///
+/// # Arguments
+/// - `players_rw_signal` - A read/write signal to the current list of [`PlayerRecord`]s.
+/// - `skinsets_rw_signal` - A read/write signal to the set of skinsets included in the search.
+/// - `settings` - The settings to resolve comps with.
+/// - `draft` - The champ select draft, whose champs nobody can play.
+#[component]
+pub fn Results(
+    players_rw_signal: RwSignal<Vec<PlayerRecord>>,
+    skinsets_rw_signal: RwSignal<HashSet<SkinsetId>>,
+    #[prop(into)]
+    settings: Signal<SolverSettings>,
+    #[prop(into)]
+    draft: Signal<Draft>,
+) -> impl IntoView {
+    // The view being shown.
+    let results_view: RwSignal<ResultsView> = create_rw_signal(ResultsView::ByComp);
+
+    // The search query, filters and sort of the by-comp view. These are kept here rather than in the table, so that
+    // they're still set after switching to another view and back.
+    let query_text: RwSignal<String> = create_rw_signal(String::new());
+    let filter: RwSignal<ResultFilter> = create_rw_signal(ResultFilter::default());
+    let sort: RwSignal<Option<ResultSort>> = create_rw_signal(None);
+
+    // Filters and sorts on a player refer to them by index, so once a player is removed they'd silently point at
+    // someone else (or no one). Clear them whenever the roster shrinks.
+    create_effect(move |previous_count: Option<usize>| {
+        let player_count: usize = players_rw_signal.with(Vec::len);
+
+        if previous_count.is_some_and(|previous_count| player_count < previous_count) {
+            if filter.with_untracked(|filter| filter.player_lane.is_some()) {
+                filter.update(|filter| filter.player_lane = None);
+            }
+
+            if sort.get_untracked().is_some_and(|sort| sort.key != SortKey::SkinsetCount) {
+                sort.set(None);
+            }
+        }
+
+        player_count
+    });
+
+    // Toggle buttons to choose the view.
+    let view_select = ResultsView::ALL
+        .iter()
+        .map(|option: &ResultsView| {
+            let option: ResultsView = *option;
+            let radio_id: String = format!("results-view-{option:?}");
+
+            view! {
+                <input
+                    type="radio"
+                    class="btn-check"
+                    name="results-view"
+                    id={radio_id.clone()}
+                    prop:checked={move || results_view.get() == option}
+                    on:change=move |_| results_view.set(option)
+                />
+                <label class="btn btn-outline-secondary" for={radio_id}> {option.to_string()} </label>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div class="card bg-light text-dark my-2">
+            <div class="card-body">
+                <div class="btn-group mb-2" role="group" aria-label="Results view"> {view_select} </div>
+
+                {move || match results_view.get() {
+                    ResultsView::ByComp => view! {
+                        <ResultsTable
+                            players_rw_signal={players_rw_signal}
+                            skinsets_rw_signal={skinsets_rw_signal}
+                            settings={settings}
+                            draft={draft}
+                            query_text={query_text}
+                            filter={filter}
+                            sort={sort}
+                        />
+                    },
+
+                    ResultsView::BySkinset => view! {
+                        <SkinsetResults
+                            players_rw_signal={players_rw_signal}
+                            skinsets_rw_signal={skinsets_rw_signal}
+                            settings={settings}
+                            draft={draft}
+                        />
+                    },
+                }}
+            </div>
+        </div>
+    }
+}
@@ -1,186 +1,267 @@
 //! The results table component, used to render the skinsets resolved for the selected champs.
 
-use super::app::PlayerRecord;
 use crate::{
-    lanes::Lane,
-    skinsets::{Skinsets, GLOBAL_SKINSETS_MAP},
+    components::{
+        comp_roller::CompRoller,
+        diagnostics_panel::DiagnosticsPanel,
+        export_menu::ExportMenu,
+        link::{ChampLink, SkinsetLink},
+        query_search::QuerySearch,
+        result_filters::ResultFilters,
+    },
+    constants::{ChampId, SkinsetId},
+    draft::Draft,
+    filters::{self, ResultFilter, ResultSort},
+    model::{LockIn, PlayerRecord, SolverSettings},
+    query::{Query, QueryError},
+    solver::{self, Comp, Pick},
 };
+use icondata::{BiLockAltRegular, BiLockOpenAltRegular};
 use instant::Instant;
+use leptos::{
+    component, create_memo, view, CollectView, IntoView, Memo, RwSignal, Show, Signal, SignalGet, SignalUpdate,
+    SignalWith,
+};
+use leptos_icons::Icon;
 use std::collections::HashSet;
-use yew::prelude::*;
 
-/// Get a list of every combination of champs that this set of players could queue.
-/// This list will match the order of the list of players stored in the app.
+/// The most rows the table will render at once. Larger rosters can resolve tens of thousands of comps, and rendering
+/// all of them would freeze the page.
+pub const MAX_RENDERED_ROWS: usize = 500;
+
+/// Results table component.
 ///
-/// Requires that the slice has length >= 1 or panics.
-fn resolve_all_champ_combinations(players: &[PlayerRecord]) -> Vec<Vec<(AttrValue, Lane)>> {
-    match players.len() {
-        0 => unreachable!("This function requires at least one champ in the slice to call"),
-
-        // One player -- suggest any of their champs in any available lane.
-        1 => {
-            // Borrow the players champ list.
-            let champs_list = players[0].champs.as_slice();
-            // Create new result vec to populate -- starting capacity is at least the number of
-            // champs for the player.
-            let mut result: Vec<Vec<(AttrValue, Lane)>> = Vec::with_capacity(champs_list.len());
-
-            // Iterate over all the champs for the one player.
-            for (champ, lanes) in champs_list.iter() {
-                // Iterate over the lanes for a champ
-                for lane in lanes.iter() {
-                    result.push(vec![(champ.clone(), lane)]);
-                }
-            }
+/// # Arguments
+/// - `players_rw_signal` - A read/write signal to the current list of [`PlayerRecord`]s.
+/// - `skinsets_rw_signal` - A read/write signal to the set of skinsets included in the search.
+/// - `settings` - The settings to resolve comps with.
+/// - `draft` - The champ select draft, whose champs are left out of every player's options. Comps from before the
+///   draft that it rules out are listed last, marked as no longer possible, but left out of the counts and exports.
+/// - `query_text` - A read/write signal to the text of the search query.
+/// - `filter` - A read/write signal to the current [`ResultFilter`].
+/// - `sort` - A read/write signal to the current [`ResultSort`], if the results are sorted.
+#[component]
+pub fn ResultsTable(
+    players_rw_signal: RwSignal<Vec<PlayerRecord>>,
+    skinsets_rw_signal: RwSignal<HashSet<SkinsetId>>,
+    #[prop(into)]
+    settings: Signal<SolverSettings>,
+    #[prop(into)]
+    draft: Signal<Draft>,
+    query_text: RwSignal<String>,
+    filter: RwSignal<ResultFilter>,
+    sort: RwSignal<Option<ResultSort>>,
+) -> impl IntoView {
+    // The search query, parsed against the current players.
+    let query: Memo<Result<Query, QueryError>> =
+        create_memo(move |_| players_rw_signal.with(|players| Query::parse(&query_text.get(), players)));
+    let query_error = Signal::derive(move || query.with(|query| query.as_ref().err().cloned()));
 
-            result
-        }
+    // Resolve every comp whenever the players, skinsets, settings or query change, before anything is drafted. A
+    // query that doesn't parse is ignored until it's fixed.
+    let solve = move |players: &[PlayerRecord]| -> Vec<Comp> {
+        skinsets_rw_signal.with(|skinsets| {
+            query.with(|query| match query {
+                Ok(query) => query.solve(players, skinsets, settings.get()),
+                Err(_) => solver::solve(players, skinsets, settings.get()),
+            })
+        })
+    };
 
-        _ => {
-            // Get a list of all champ combinataions not including the first player.
-            let others: Vec<Vec<(AttrValue, Lane)>> = resolve_all_champ_combinations(&players[1..]);
-            // Borrow the first player's champ list.
-            let champ_list = players[0].champs.as_slice();
-            // Make a list to copy results into.
-            let mut result = Vec::new();
-
-            // Iterate over all the champs a player could play.
-            for (champ, lanes) in champ_list.iter() {
-                // Iterate over all the lanes the champ could play.
-                for lane in lanes.iter() {
-                    // Iterate over all the other champ combos for the rest of the team.
-                    for champ_combo in others.iter() {
-                        // Check if this champ is already in the combo
-                        let contains_champ: bool =
-                            champ_combo.iter().find(|(c, _)| c == champ).is_some();
-                        // Check if this lane is already covered in the combo.
-                        let lane_covered: bool =
-                            champ_combo.iter().find(|(_, l)| *l == lane).is_some();
-
-                        // If neither are true then we can make a new combo using this champ in this lane for this
-                        // player.
-                        if !contains_champ && !lane_covered {
-                            let mut new_combo = champ_combo.clone();
-                            new_combo.insert(0, (champ.clone(), lane));
-                            result.push(new_combo);
-                        }
-                    }
-                }
-            }
+    let comps: Memo<Vec<Comp>> = create_memo(move |_| {
+        // Track the start instant so we can log resolution times.
+        let start = Instant::now();
+
+        let comps: Vec<Comp> = players_rw_signal.with(|players| solve(players));
 
-            result
+        log::info!("Resolved {} comps in {:?}", comps.len(), Instant::now() - start);
+        comps
+    });
+
+    // Resolve the comps still possible with the drafted champs taken out of every player's options. Taking out a
+    // champ can also release a lock on it, so these aren't just the comps above without the drafted champs.
+    let available_comps: Memo<Vec<Comp>> = create_memo(move |_| {
+        if draft.with(Draft::is_empty) {
+            return comps.get();
         }
-    }
-}
 
-/// Properties passed to the table.
-#[derive(PartialEq, Properties)]
-pub struct ResultsTableProps {
-    /// The list of players with their champ selections.
-    pub players: Vec<PlayerRecord>,
-    /// The set of skinsets to exclude from results.
-    pub skinsets_excluded: HashSet<AttrValue>,
-}
+        let start = Instant::now();
 
-/// Results table component.
-pub struct ResultsTable;
+        let available: Vec<Comp> = players_rw_signal.with(|players| solve(&draft.with(|draft| draft.apply(players))));
 
-impl Component for ResultsTable {
-    type Message = ();
+        log::info!("Resolved {} comps after the draft in {:?}", available.len(), Instant::now() - start);
+        available
+    });
 
-    type Properties = ResultsTableProps;
+    // The comps from before the draft that it has ruled out.
+    let ruled_out_comps: Memo<Vec<Comp>> = create_memo(move |_| {
+        available_comps.with(|available| {
+            let still_possible: HashSet<&[Pick]> = available.iter().map(|comp| comp.picks.as_slice()).collect();
+            comps.with(|comps| {
+                comps.iter().filter(|comp| !still_possible.contains(comp.picks.as_slice())).cloned().collect()
+            })
+        })
+    });
 
-    fn create(_: &Context<Self>) -> Self {
-        Self
-    }
+    // Filter and sort the comps still possible without re-solving.
+    let shown_comps: Memo<Vec<Comp>> = create_memo(move |_| {
+        available_comps.with(|comps| filters::filter_and_sort(comps, &filter.get(), sort.get()))
+    });
 
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        // Get the component props.
-        let props = ctx.props();
+    // The ruled out comps, filtered and sorted the same way, which are listed after the rest.
+    let shown_ruled_out: Memo<Vec<Comp>> = create_memo(move |_| {
+        ruled_out_comps.with(|comps| filters::filter_and_sort(comps, &filter.get(), sort.get()))
+    });
 
-        // Track the start instant so we can log resolution/render times.
-        let start = Instant::now();
-        // Get an iterator over the champ-combinations that could be played.
-        let all_comps = resolve_all_champ_combinations(&props.players);
-        // Log info on resolution speed.
-        log::info!(
-            "Resolved all champion combos in {:?}",
-            Instant::now() - start
-        );
-        // Get an iterator over the champ combinations that filters out any with no-overlapping, non-excluded skinsets.
-        let displayed_comps = all_comps
-            .into_iter()
-            // Add the set of overlapping non-excluded skinsets.
-            .map(|champ_combo: Vec<(AttrValue, Lane)>| {
-                // Get the list of overlapping skinsets.
-                let overlapping_skinsets: HashSet<AttrValue> = GLOBAL_SKINSETS_MAP
-                    .with(|s: &Skinsets| s.get_overlapping_skinsets(&champ_combo));
-
-                // Remove any excluded/unwanted skinsets.
-                let final_skinsets: Vec<AttrValue> = overlapping_skinsets
-                    .difference(&props.skinsets_excluded)
-                    .cloned()
-                    .collect();
-
-                (champ_combo, final_skinsets)
-            })
-            // Filter out champ combos with no skinsets
-            .filter(|(_, skinsets)| !skinsets.is_empty());
-
-        html! {
-            <div class="card m-2">
-                <div class="card-body">
-                    <table class="table table-stripped">
-                        <tr>
-                            {
-                                props.players
-                                    .iter()
-                                    .enumerate()
-                                    .map(|(id, player)| {
-                                        // Resolve the player name
-                                        let player_name = player
-                                            .clone()
-                                            .name
-                                            .unwrap_or_else(|| format!("Player {}", id + 1).into());
-
-                                        html! {
-                                            <th> {player_name} </th>
-                                        }
+    // Note on how many comps the draft has left, once anything has been drafted.
+    let draft_note = move || {
+        (!draft.with(Draft::is_empty)).then(|| {
+            let available: usize = available_comps.with(Vec::len);
+            let ruled_out: usize = ruled_out_comps.with(Vec::len);
+            view! {
+                <p class="text-muted"> {available} " comps still possible, " {ruled_out} " ruled out by the draft." </p>
+            }
+        })
+    };
+
+    // Header cells with each player's name.
+    let header = move || {
+        players_rw_signal.with(|players| {
+            players
+                .iter()
+                .enumerate()
+                .map(|(index, player)| view! { <th> {player.display_name(index)} </th> })
+                .collect_view()
+        })
+    };
+
+    // Table rows, capped at the max rendered rows.
+    let rows = move || {
+        shown_comps.with(|shown| {
+            shown_ruled_out.with(|ruled_out| {
+                shown
+                    .iter()
+                    .map(|comp| (comp, false))
+                    .chain(ruled_out.iter().map(|comp| (comp, true)))
+                    .take(MAX_RENDERED_ROWS)
+                    .map(|(comp, is_ruled_out)| {
+                        // The champs in this comp that have been banned or picked by the enemy team.
+                        let taken: Vec<ChampId> = draft.with(|draft| draft.taken_in(comp));
+                        let title: Option<String> = is_ruled_out.then(|| {
+                            let names: Vec<&str> = taken.iter().map(|champ_id| champ_id.champ_name()).collect();
+                            format!("No longer possible -- {} taken in the draft", names.join(", "))
+                        });
+
+                        let picks = comp
+                            .picks
+                            .iter()
+                            .copied()
+                            .enumerate()
+                            .map(|(player_index, pick): (usize, Pick)| {
+                                let lane: String = pick.lane.map(|lane| format!(" {lane}")).unwrap_or_default();
+
+                                // Whether the player is already locked into exactly this pick.
+                                let locked = move || {
+                                    players_rw_signal.with(|players| {
+                                        players.get(player_index).is_some_and(|player| {
+                                            player.lock.champ == Some(pick.champ) && player.lock.lane == pick.lane
+                                        })
                                     })
-                                    .collect::<Html>()
-                            }
-
-                            // Header for skinsets collumn
-                            <th> {"Overlapping Skinsets"} </th>
-                        </tr>
-
-                        // Table data
-                        {
-                            displayed_comps
-                                .map(|(champ_combo, skinsets): (Vec<(AttrValue, Lane)>, Vec<AttrValue>)| html! {
-                                    <tr>
-                                        // Champs and lanes
-                                        {
-                                            champ_combo
-                                                .into_iter()
-                                                .map(|(champ, lane): (AttrValue, Lane)| html! {
-                                                    <td> {champ} {" "} {lane} </td>
-                                                })
-                                                .collect::<Html>()
-                                        }
+                                };
 
-                                        <td>
-                                            {
-                                                skinsets.join(", ")
+                                // Lock the player into this pick, or unlock them if they already are.
+                                let toggle_lock = move |_| {
+                                    let unlock: bool = locked();
+
+                                    players_rw_signal.update(|players| {
+                                        if let Some(player) = players.get_mut(player_index) {
+                                            if unlock {
+                                                player.lock = LockIn::default();
+                                            } else {
+                                                player.lock_pick(pick.champ, pick.lane);
                                             }
-                                        </td>
-                                    </tr>
-                                })
-                                .collect::<Html>()
+                                        }
+                                    })
+                                };
+
+                                view! {
+                                    <td>
+                                        <ChampLink champ_id=pick.champ /> {lane} " "
+                                        <button
+                                            class="btn btn-sm btn-link p-0 align-baseline"
+                                            title={move || if locked() { "Unlock" } else { "Lock in this pick" }}
+                                            on:click=toggle_lock
+                                        >
+                                            {move || if locked() {
+                                                view! { <Icon icon=BiLockAltRegular /> }
+                                            } else {
+                                                view! { <Icon icon=BiLockOpenAltRegular /> }
+                                            }}
+                                        </button>
+                                    </td>
+                                }
+                            })
+                            .collect_view();
+
+                        // Link each skinset to its page, separated by commas.
+                        let skinsets = comp
+                            .skinsets
+                            .iter()
+                            .enumerate()
+                            .map(|(index, skinset_id)| {
+                                let separator: &str = if index == 0 { "" } else { ", " };
+                                view! { {separator} <SkinsetLink skinset_id=*skinset_id /> }
+                            })
+                            .collect_view();
+
+                        view! {
+                            <tr class:table-danger={is_ruled_out} class:opacity-50={is_ruled_out} title={title}>
+                                {picks}
+                                <td> {skinsets} </td>
+                            </tr>
                         }
-                    </table>
-                </div>
-            </div>
-        }
+                    })
+                    .collect_view()
+            })
+        })
+    };
+
+    // Note shown when some results were not rendered.
+    let truncation_note = move || {
+        let total: usize = shown_comps.with(Vec::len) + shown_ruled_out.with(Vec::len);
+
+        (total > MAX_RENDERED_ROWS).then(|| view! {
+            <p class="text-muted"> "Showing the first " {MAX_RENDERED_ROWS} " of " {total} " comps." </p>
+        })
+    };
+
+    view! {
+        <h3> "Results (" {move || shown_comps.with(Vec::len)} " of " {move || available_comps.with(Vec::len)} ")" </h3>
+        <QuerySearch query_text={query_text} error={query_error} />
+        {draft_note}
+        <CompRoller players_rw_signal={players_rw_signal} comps={available_comps} />
+        <ResultFilters players_rw_signal={players_rw_signal} comps={available_comps} filter={filter} sort={sort} />
+        <ExportMenu players_rw_signal={players_rw_signal} comps={shown_comps} settings={settings} />
+        {truncation_note}
+        <Show when={move || available_comps.with(Vec::is_empty)} fallback={move || view! {} }>
+            <DiagnosticsPanel
+                players_rw_signal={players_rw_signal}
+                skinsets_rw_signal={skinsets_rw_signal}
+                settings={settings}
+                draft={draft}
+            />
+        </Show>
+        <table class="table table-striped">
+            <thead>
+                <tr>
+                    {header}
+                    // Header for skinsets column.
+                    <th> "Overlapping Skinsets" </th>
+                </tr>
+            </thead>
+            <tbody>
+                {rows}
+            </tbody>
+        </table>
     }
 }
@@ -0,0 +1,119 @@
+//! Buttons to export the session as a JSON roster file and to import one.
+
+use crate::{
+    components::{button::Button, download::download_text},
+    constants::SkinsetId,
+    model::{GameMode, PlayerRecord},
+    roster::{RosterDocument, RosterFieldError, ValidatedRoster},
+};
+use icondata::{BiDownloadRegular, BiUploadRegular};
+use leptos::{
+    component, create_rw_signal, ev::Event, event_target, spawn_local, view, CollectView, IntoView, RwSignal, Show,
+    SignalGet, SignalSet, SignalWith,
+};
+use leptos_icons::Icon;
+use std::collections::HashSet;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::HtmlInputElement;
+
+/// The file name used for exported rosters.
+const EXPORT_FILE_NAME: &str = "roster.json";
+
+/// Export and import controls for roster files.
+///
+/// # Arguments
+/// - `game_mode_rw_signal` - A read/write signal to the current [`GameMode`].
+/// - `owned_only_rw_signal` - A read/write signal to whether the search only uses owned skins.
+/// - `players_rw_signal` - A read/write signal to the current list of [`PlayerRecord`]s.
+/// - `skinsets_rw_signal` - A read/write signal to the current [`HashSet`] of skinsets selected.
+#[component]
+pub fn RosterFile(
+    game_mode_rw_signal: RwSignal<GameMode>,
+    owned_only_rw_signal: RwSignal<bool>,
+    players_rw_signal: RwSignal<Vec<PlayerRecord>>,
+    skinsets_rw_signal: RwSignal<HashSet<SkinsetId>>,
+) -> impl IntoView {
+    // Errors from the most recent import, shown per field.
+    let import_errors: RwSignal<Vec<RosterFieldError>> = create_rw_signal(Vec::new());
+
+    // Closure to export the current session as a roster file.
+    let export_roster = move |_| {
+        let document: RosterDocument = players_rw_signal.with(|players| {
+            skinsets_rw_signal.with(|skinsets| {
+                RosterDocument::from_session(game_mode_rw_signal.get(), owned_only_rw_signal.get(), players, skinsets)
+            })
+        });
+
+        if let Err(err) = download_text(EXPORT_FILE_NAME, "application/json", &document.to_json()) {
+            log::error!("Failed to export roster: {err:?}");
+        }
+    };
+
+    // Handler for a roster file being chosen for import.
+    let import_roster = move |ev: Event| {
+        let input: HtmlInputElement = event_target(&ev);
+        let Some(file) = input.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+
+        // Clear the input so that choosing the same file again still triggers a change.
+        input.set_value("");
+
+        spawn_local(async move {
+            let text: Option<String> = JsFuture::from(file.text()).await.ok().and_then(|text| text.as_string());
+
+            let Some(text) = text else {
+                import_errors.set(vec![RosterFieldError {
+                    field: "document".to_owned(),
+                    message: "could not read file".to_owned(),
+                }]);
+                return;
+            };
+
+            match RosterDocument::parse(&text) {
+                Ok(ValidatedRoster { game_mode, owned_only, players, skinsets }) => {
+                    log::info!("Imported roster with {} players", players.len());
+                    game_mode_rw_signal.set(game_mode);
+                    owned_only_rw_signal.set(owned_only);
+                    players_rw_signal.set(players);
+                    skinsets_rw_signal.set(skinsets);
+                    import_errors.set(Vec::new());
+                }
+
+                Err(errors) => {
+                    log::warn!("Rejected roster file with {} errors", errors.len());
+                    import_errors.set(errors);
+                }
+            }
+        });
+    };
+
+    view! {
+        <div class="d-inline-flex gap-2 my-2">
+            <Button class="btn btn-outline-primary" on_click=export_roster>
+                <Icon icon=BiDownloadRegular /> " Export Roster"
+            </Button>
+
+            // Style a label as a button to trigger the hidden file input.
+            <label class="btn btn-outline-primary mb-0">
+                <Icon icon=BiUploadRegular /> " Import Roster"
+                <input class="d-none" type="file" accept=".json,application/json" on:change=import_roster />
+            </label>
+        </div>
+
+        <Show when={move || import_errors.with(|errors| !errors.is_empty())} fallback={move || view! {} }>
+            <div class="alert alert-danger">
+                <p class="mb-1"> "The roster file could not be imported:" </p>
+                <ul class="mb-0">
+                    {move || {
+                        import_errors
+                            .get()
+                            .into_iter()
+                            .map(|error| view! { <li> <code> {error.field} </code> ": " {error.message} </li> })
+                            .collect_view()
+                    }}
+                </ul>
+            </div>
+        </Show>
+    }
+}
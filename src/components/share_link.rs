@@ -0,0 +1,87 @@
+//! Button and field to copy a link that reproduces the current session.
+
+use crate::{
+    components::button::Button,
+    constants::SkinsetId,
+    model::{PlayerRecord, SolverSettings},
+    share,
+};
+use icondata::BiLinkRegular;
+use leptos::{
+    component, create_node_ref, create_rw_signal, html::Input, view, IntoView, NodeRef, RwSignal, Show,
+    Signal, SignalGet, SignalGetUntracked, SignalSet, SignalWith,
+};
+use leptos_icons::Icon;
+use std::collections::HashSet;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlDocument;
+
+/// A button that generates a share link for the current players and skinsets, shows it, and copies it to the
+/// clipboard.
+///
+/// # Arguments
+/// - `players_rw_signal` - A read/write signal to the current list of [`PlayerRecord`]s.
+/// - `skinsets_rw_signal` - A read/write signal to the current [`HashSet`] of skinsets selected.
+/// - `settings` - The game mode and owned skins setting, which are shared along with the roster.
+#[component]
+pub fn ShareLink(
+    players_rw_signal: RwSignal<Vec<PlayerRecord>>,
+    skinsets_rw_signal: RwSignal<HashSet<SkinsetId>>,
+    #[prop(into)]
+    settings: Signal<SolverSettings>,
+) -> impl IntoView {
+    // The most recently generated share link, if any.
+    let link: RwSignal<Option<String>> = create_rw_signal(None);
+    // Node ref to the read-only field showing the link, so we can select its text to copy it.
+    let input_ref: NodeRef<Input> = create_node_ref::<Input>();
+
+    // Closure to generate the link and copy it to the clipboard.
+    let copy_share_link = move |_| {
+        let url: String = players_rw_signal.with(|players| {
+            skinsets_rw_signal.with(|skinsets| share::share_url(players, skinsets, settings.get_untracked()))
+        });
+
+        log::debug!("Generated share link {url}");
+
+        // Update the field first so that its contents can be selected and copied.
+        if let Some(input) = input_ref.get_untracked() {
+            input.set_value(&url);
+            input.select();
+
+            // Use the document copy command here, since the async clipboard API is still unstable in web-sys.
+            let copied: bool = leptos::document()
+                .dyn_into::<HtmlDocument>()
+                .ok()
+                .and_then(|document| document.exec_command("copy").ok())
+                .unwrap_or(false);
+
+            if !copied {
+                log::warn!("Could not copy share link to clipboard");
+            }
+        }
+
+        link.set(Some(url));
+    };
+
+    view! {
+        <div class="input-group">
+            <Button class="btn btn-outline-primary" on_click=copy_share_link>
+                <Icon icon=BiLinkRegular /> " Copy Share Link"
+            </Button>
+
+            // Keep the field in the DOM so it can be selected, but only show it once there is a link.
+            <input
+                _ref=input_ref
+                class="form-control"
+                class:d-none={move || link.get().is_none()}
+                type="text"
+                readonly=true
+                aria-label="Share link"
+            />
+
+            <Show when={move || link.get().is_some()} fallback={move || view! {} }>
+                <span class="input-group-text"> "Copied!" </span>
+            </Show>
+        </div>
+    }
+}
@@ -1,23 +1,69 @@
 //! Component/card listing all the skinsets to be played.
 
-use crate::{components::button::Button, constants::SkinsetId};
-use icondata::{BiHideRegular, BiShowRegular};
+use crate::{
+    components::{button::Button, checkbox::Checkbox, skinset_presets::SkinsetPresets},
+    constants::{Lane, SkinsetId, UniverseId},
+    draft::Draft,
+    model::{PlayerRecord, SolverSettings},
+    route::Route,
+    solver,
+};
+use icondata::{BiChevronDownRegular, BiChevronUpRegular, BiHideRegular, BiInfoCircleRegular, BiShowRegular};
 use leptos::{
-    component, create_effect, create_memo, create_node_ref, create_rw_signal, ev::Event,
-    event_target_checked, html::Input, view, CollectView, Fragment, IntoView, Memo, NodeRef,
-    RwSignal, Show, Signal, SignalGet, SignalSet, SignalUpdate, View,
+    component, create_memo, create_rw_signal, event_target_value, view, CollectView, Fragment, IntoView, Memo,
+    RwSignal, Show, Signal, SignalGet, SignalGetUntracked, SignalSet, SignalUpdate, SignalWith,
 };
 use leptos_icons::Icon;
-use std::collections::HashSet;
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+/// How the skinsets in the list are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SkinsetSort {
+    /// Alphabetically, by universe and then skinset name.
+    Name,
+
+    /// Achievable skinsets first, then by how many players have a champ in them.
+    Achievability,
+}
+
+/// Check whether a skinset matches the filter typed into the skinset list. The filter is matched case-insensitively
+/// against the skinset's name and the names of its universes, so typing a universe name shows every set in it.
+fn matches_filter(skinset_id: SkinsetId, filter: &str) -> bool {
+    let filter: String = filter.trim().to_lowercase();
+
+    filter.is_empty()
+        || skinset_id.skinset_name().to_lowercase().contains(&filter)
+        || skinset_id
+            .universes()
+            .any(|universe_id| universe_id.universe_name().to_lowercase().contains(&filter))
+}
 
 /// An interactive card that displays all the skinsets available with information on which ones are selected.
 ///
 /// # Arguments
 /// - `skinsets_rw_signal` - A read/write signal to the current [`HashSet`] of skinsets selected.
+/// - `players_rw_signal` - A read/write signal to the current list of [`PlayerRecord`]s, used to annotate each
+///   skinset with how relevant it is to the roster.
+/// - `settings` - The settings comps are resolved with, used to check which skinsets are achievable.
+/// - `draft` - The champ select draft, whose champs are left out of every player's options.
 #[component]
-pub fn SkinsetList(skinsets_rw_signal: RwSignal<HashSet<SkinsetId>>) -> impl IntoView {
+pub fn SkinsetList(
+    skinsets_rw_signal: RwSignal<HashSet<SkinsetId>>,
+    players_rw_signal: RwSignal<Vec<PlayerRecord>>,
+    #[prop(into)]
+    settings: Signal<SolverSettings>,
+    #[prop(into)]
+    draft: Signal<Draft>,
+) -> impl IntoView {
     // Create a signal to track the state of whether the card is collapsed.
     let collapsed: RwSignal<bool> = create_rw_signal(false);
+    // The text typed into the filter box.
+    let filter: RwSignal<String> = create_rw_signal(String::new());
+    // The universes that have been expanded to show their skinsets.
+    let expanded_universes: RwSignal<HashSet<UniverseId>> = create_rw_signal(HashSet::new());
 
     // Derive a signal to track the icon to use for the show/hide button.
     let show_hide_body: Signal<Fragment> = Signal::derive(move || {
@@ -40,71 +86,222 @@ pub fn SkinsetList(skinsets_rw_signal: RwSignal<HashSet<SkinsetId>>) -> impl Int
         skinsets_rw_signal.set(SkinsetId::iter_all().collect())
     };
 
-    // Create a derived that will produce the rendered list of skinsets with checkboxes.
-    let skinset_checkboxes: Memo<View> = create_memo(move |_| {
-        // For each skinset that exists, determine a good shortened name and render a view.
-        SkinsetId::iter_all()
-            .map(|skinset_id: SkinsetId| {
-                // Make a transformed skinset name to handle long skinset names.
-                let transformed_skinset_name = if skinset_id.skinset_name().len() > 22 {
-                    format!("{}...", &skinset_id.skinset_name()[0..21])
+    // How the skinsets are ordered.
+    let sort: RwSignal<SkinsetSort> = create_rw_signal(SkinsetSort::Name);
+
+    // Memoize how many players have a champ in each skinset.
+    let players_covering: Memo<HashMap<SkinsetId, usize>> = create_memo(move |_| {
+        players_rw_signal.with(|players| {
+            let players: Vec<PlayerRecord> = draft.with(|draft| draft.apply(players));
+            SkinsetId::iter_all()
+                .map(|skinset_id| (skinset_id, solver::players_with_champ_in(&players, skinset_id)))
+                .collect()
+        })
+    });
+
+    // Memoize the skinsets the roster could queue a full comp in.
+    let achievable: Memo<HashSet<SkinsetId>> = create_memo(move |_| {
+        players_rw_signal.with(|players| {
+            let players: Vec<PlayerRecord> = draft.with(|draft| draft.apply(players));
+            solver::achievable_skinsets(&players, settings.get())
+        })
+    });
+
+    // Closure to select only the achievable skinsets.
+    let include_achievable_skinsets = move |_| {
+        log::debug!("Including only achievable skinsets");
+        skinsets_rw_signal.set(achievable.get_untracked())
+    };
+
+    // Key to sort skinsets by achievability -- lower sorts first.
+    let achievability_key = move |skinset_id: &SkinsetId| {
+        let covering: usize = players_covering.with(|covering| covering[skinset_id]);
+        (!achievable.with(|achievable| achievable.contains(skinset_id)), Reverse(covering))
+    };
+
+    // Memoize the universes with at least one skinset matching the filter, along with those skinsets, in the chosen
+    // order.
+    let visible_universes: Memo<Vec<(UniverseId, Vec<SkinsetId>)>> = create_memo(move |_| {
+        let mut universes: Vec<(UniverseId, Vec<SkinsetId>)> = filter.with(|filter| {
+            UniverseId::iter_all()
+                .map(|universe_id| {
+                    let skinsets: Vec<SkinsetId> = universe_id
+                        .skinsets()
+                        .filter(|skinset_id| matches_filter(*skinset_id, filter))
+                        .collect();
+
+                    (universe_id, skinsets)
+                })
+                .filter(|(_, skinsets)| !skinsets.is_empty())
+                .collect()
+        });
+
+        // Sort by achievability within and then between universes. The sorts are stable, so ties stay alphabetical.
+        if sort.get() == SkinsetSort::Achievability {
+            for (_, skinsets) in universes.iter_mut() {
+                skinsets.sort_by_key(achievability_key);
+            }
+
+            universes.sort_by_key(|(_, skinsets)| achievability_key(&skinsets[0]));
+        }
+
+        universes
+    });
+
+    // Render a checkbox for a single skinset. The full name is always shown on hover, since long names are cut off.
+    let skinset_checkbox = move |skinset_id: SkinsetId, universe_id: UniverseId| {
+        // Skinsets can be listed under more than one universe, so include both in the checkbox ID.
+        let checkbox_id: String = format!("universe-{}-skinset-{}-check", universe_id.inner(), skinset_id.inner());
+
+        let included = Signal::derive(move || skinsets_rw_signal.with(|skinsets| skinsets.contains(&skinset_id)));
+
+        let toggle_included = move |checked: bool| {
+            skinsets_rw_signal.update(|skinsets: &mut HashSet<SkinsetId>| {
+                if checked {
+                    skinsets.insert(skinset_id);
                 } else {
-                    skinset_id.skinset_name().to_string()
+                    skinsets.remove(&skinset_id);
+                }
+            })
+        };
+
+        // Annotate the skinset with how many players have a champ in it and how many champs it has, noting when its
+        // champs can't make a full five-lane team with their usual lanes.
+        let annotation = move || {
+            let covering: usize = players_covering.with(|covering| covering[&skinset_id]);
+            let player_count: usize = players_rw_signal.with(Vec::len);
+            let full_team: &str = if skinset_id.has_full_team() { "" } else { " · no full team" };
+            format!("{covering}/{player_count} players · {} champs{full_team}", skinset_id.champ_count())
+        };
+
+        // Hover text with the full name, the champs in the skinset and the lanes they cover.
+        let champ_names: Vec<&str> = skinset_id.champs().map(|champ_id| champ_id.champ_name()).collect();
+        let lane_names: Vec<String> = skinset_id.lane_coverage().iter().map(|lane: Lane| lane.to_string()).collect();
+        let title: String = format!(
+            "{}\nChamps: {}\nLanes: {}",
+            skinset_id.skinset_name(),
+            champ_names.join(", "),
+            lane_names.join("/")
+        );
+
+        let is_achievable = move || achievable.with(|achievable| achievable.contains(&skinset_id));
+
+        view! {
+            <div class="col form-check" title={title}>
+                <Checkbox checked=included id={checkbox_id.clone()} on_change=toggle_included />
+                <label class="form-check-label text-truncate d-block" for={checkbox_id}>
+                    {skinset_id.skinset_name()}
+                    // Link to the skinset's page, kept apart from the name so clicking the name still toggles it.
+                    " "
+                    <a href={Route::Skinset(skinset_id).href()} title="Skinset details">
+                        <Icon icon=BiInfoCircleRegular />
+                    </a>
+                    <Show when=is_achievable fallback={move || view! {} }>
+                        " " <span class="badge bg-success"> "Achievable" </span>
+                    </Show>
+                    <small class="d-block text-muted"> {annotation} </small>
+                </label>
+            </div>
+        }
+    };
+
+    // Render every visible universe with a tri-state checkbox to select all of its (visible) skinsets.
+    let universe_groups = move || {
+        visible_universes
+            .get()
+            .into_iter()
+            .map(|(universe_id, skinsets)| {
+                let checkbox_id: String = format!("universe-{}-check", universe_id.inner());
+                let total: usize = skinsets.len();
+
+                // Count how many of this universe's visible skinsets are included.
+                let included_skinsets = skinsets.clone();
+                let included_count: Memo<usize> = create_memo(move |_| {
+                    skinsets_rw_signal.with(|selected| {
+                        included_skinsets.iter().filter(|skinset_id| selected.contains(skinset_id)).count()
+                    })
+                });
+
+                // Select all of the universe's visible skinsets, unless they're all selected already.
+                let toggle_skinsets = skinsets.clone();
+                let toggle_universe = move |_: bool| {
+                    let select: bool = included_count.get_untracked() < total;
+
+                    skinsets_rw_signal.update(|selected: &mut HashSet<SkinsetId>| {
+                        for skinset_id in toggle_skinsets.iter() {
+                            if select {
+                                selected.insert(*skinset_id);
+                            } else {
+                                selected.remove(skinset_id);
+                            }
+                        }
+                    })
                 };
 
-                // Make an ID for the checkbox.
-                let checkbox_id: String = format!("skinset-{}-check", skinset_id.inner());
-
-                // Make a checkbox node ref so that we can manually debounce checkbox events since stuff seems broken.
-                let node_ref: NodeRef<Input> = create_node_ref::<Input>();
-
-                // Make a function to handle the checkbox.
-                let on_change = move |ev: Event| {
-                    if event_target_checked(&ev) {
-                        skinsets_rw_signal.update(|hash_set: &mut HashSet<SkinsetId>| {
-                            hash_set.insert(skinset_id);
-                        });
-                    } else {
-                        skinsets_rw_signal.update(|hash_set: &mut HashSet<SkinsetId>| {
-                            hash_set.remove(&skinset_id);
-                        });
-                    }
+                // Expand the universe when it's toggled open or whenever the list is being filtered.
+                let is_expanded = move || {
+                    expanded_universes.with(|expanded| expanded.contains(&universe_id))
+                        || filter.with(|filter| !filter.trim().is_empty())
                 };
 
-                // Use an effect to update the state of the checkbox in the browser when it's clicked.
-                // This is necessary to prevent checkboxes from misbehaving.
-                create_effect(move |_| {
-                    node_ref
-                        .get_untracked()
-                        .unwrap()
-                        .set_checked(skinsets_rw_signal.get().contains(&skinset_id))
-                });
+                let toggle_expanded = move |_| {
+                    expanded_universes.update(|expanded| {
+                        if !expanded.remove(&universe_id) {
+                            expanded.insert(universe_id);
+                        }
+                    })
+                };
+
+                // Build the skinset checkboxes fresh each time the universe is expanded.
+                let skinsets_view = move || {
+                    skinsets
+                        .iter()
+                        .map(|skinset_id| skinset_checkbox(*skinset_id, universe_id))
+                        .collect_view()
+                };
 
                 view! {
-                    <div class="col form-check">
-                        <input
-                            _ref=node_ref
-                            class="form-check-input"
-                            type="checkbox"
-                            id={checkbox_id.clone()}
-                            on:change=on_change
-                            checked={move || skinsets_rw_signal.get().contains(&skinset_id)}
-                        />
-                        <label class="form-check-label" for={checkbox_id}>
-                            {transformed_skinset_name}
-                        </label>
+                    <div class="col mb-2">
+                        <div class="d-flex align-items-center" title={universe_id.universe_name()}>
+                            <div class="form-check flex-grow-1 text-truncate mb-0">
+                                <Checkbox
+                                    checked={Signal::derive(move || included_count.get() == total)}
+                                    indeterminate={Signal::derive(move || {
+                                        let count: usize = included_count.get();
+                                        count > 0 && count < total
+                                    })}
+                                    id={checkbox_id.clone()}
+                                    on_change=toggle_universe
+                                />
+                                <label class="form-check-label fw-bold" for={checkbox_id}>
+                                    {universe_id.universe_name()} " (" {included_count} "/" {total} ")"
+                                </label>
+                            </div>
+
+                            <Button class="btn btn-sm btn-link" on_click=toggle_expanded>
+                                {move || if is_expanded() {
+                                    view! { <Icon icon=BiChevronUpRegular /> }
+                                } else {
+                                    view! { <Icon icon=BiChevronDownRegular /> }
+                                }}
+                            </Button>
+                        </div>
+
+                        <Show when=is_expanded fallback={move || view! {} }>
+                            <div class="ms-4"> {skinsets_view.clone()} </div>
+                        </Show>
                     </div>
                 }
             })
             .collect_view()
-    });
+    };
 
     view! {
         <div class="card bg-light text-dark my-2">
             <div class="card-body">
                 <span class="card-title d-inline-flex w-100">
                     <h3 class="p2 flex-grow-1">
-                        "Selected Skinsets"
+                        "Selected Skinsets (" {move || skinsets_rw_signal.with(HashSet::len)} "/" {SkinsetId::iter_all().len()} ")"
                     </h3>
 
                     // De-select all skinsets
@@ -117,6 +314,11 @@ pub fn SkinsetList(skinsets_rw_signal: RwSignal<HashSet<SkinsetId>>) -> impl Int
                         "Select All Skinsets"
                     </Button>
 
+                    // Select only achievable button
+                    <Button class="btn btn-success mx-1" disabled={collapsed} on_click=include_achievable_skinsets>
+                        "Select Only Achievable (" {move || achievable.with(HashSet::len)} ")"
+                    </Button>
+
                     // Show/hide button
                     <Button class="btn btn-secondary mx-1" on_click={ move |_| collapsed.update(|c| *c = !*c) }>
                         {show_hide_body}
@@ -126,8 +328,46 @@ pub fn SkinsetList(skinsets_rw_signal: RwSignal<HashSet<SkinsetId>>) -> impl Int
 
             // Show/hide functionality -- show nothing when collapsed.
             <Show when={move || !collapsed.get()} fallback={move || view! {} }>
-                <div class="card-body row row-cols-6">
-                    {skinset_checkboxes}
+                <div class="card-body pt-0">
+                    // Presets.
+                    <SkinsetPresets skinsets_rw_signal={skinsets_rw_signal} />
+
+                    // Filter box and sort order.
+                    <div class="d-flex gap-2 mb-3">
+                        <input
+                            type="search"
+                            class="form-control"
+                            placeholder="Filter skinsets or universes..."
+                            aria-label="Filter skinsets"
+                            prop:value={move || filter.get()}
+                            on:input=move |ev| filter.set(event_target_value(&ev))
+                        />
+
+                        <select
+                            class="form-select w-auto"
+                            aria-label="Sort skinsets"
+                            on:change=move |ev| {
+                                sort.set(if event_target_value(&ev) == "achievability" {
+                                    SkinsetSort::Achievability
+                                } else {
+                                    SkinsetSort::Name
+                                })
+                            }
+                        >
+                            <option value="name" selected={move || sort.get() == SkinsetSort::Name}> "Sort by name" </option>
+                            <option value="achievability" selected={move || sort.get() == SkinsetSort::Achievability}>
+                                "Sort by achievability"
+                            </option>
+                        </select>
+                    </div>
+
+                    <div class="row row-cols-1 row-cols-md-3 row-cols-xl-4">
+                        {universe_groups}
+                    </div>
+
+                    <Show when={move || visible_universes.with(Vec::is_empty)} fallback={move || view! {} }>
+                        <p class="text-muted mb-0"> "No skinsets match the filter." </p>
+                    </Show>
                 </div>
             </Show>
         </div>
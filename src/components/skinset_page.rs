@@ -0,0 +1,61 @@
+//! Browse page for a single skinset -- its champs grouped by lane, and whether they can make a full team.
+
+use crate::{
+    components::link::{ChampLink, Link},
+    constants::{Lane, SkinsetId},
+};
+use enumflags2::BitFlags;
+use leptos::{component, view, CollectView, IntoView};
+
+/// Page listing a skinset's universes and champs, grouped by the lanes they're played in by default.
+///
+/// # Arguments
+/// - `skinset_id` - The skinset to show.
+#[component]
+pub fn SkinsetPage(skinset_id: SkinsetId) -> impl IntoView {
+    let universes: Vec<&str> = skinset_id.universes().map(|universe_id| universe_id.universe_name()).collect();
+
+    // Whether the skinset's champs can fill every lane with their default lanes.
+    let full_team = if skinset_id.has_full_team() {
+        view! { <span class="badge bg-success"> "A full team is possible" </span> }
+    } else {
+        view! { <span class="badge bg-danger"> "No full team with default lanes" </span> }
+    };
+
+    // One column per lane, listing the champs who play it. Champs with several default lanes show up in each.
+    let lane_columns = BitFlags::<Lane>::all()
+        .iter()
+        .map(|lane: Lane| {
+            let champs = skinset_id
+                .champs()
+                .filter(|champ_id| champ_id.default_lanes().contains(lane))
+                .map(|champ_id| {
+                    // Hover text with the champ's skins in this skinset.
+                    let skins: Vec<&str> = champ_id.skins_in(skinset_id).map(|skin_id| skin_id.skin_name()).collect();
+
+                    view! { <li title={skins.join("\n")}> <ChampLink champ_id=champ_id /> </li> }
+                })
+                .collect_view();
+
+            view! {
+                <div class="col">
+                    <h5> {lane.to_string()} </h5>
+                    <ul class="list-unstyled"> {champs} </ul>
+                </div>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div class="card bg-light text-dark my-2">
+            <div class="card-body">
+                <Link href="#/" text="Back to the finder".to_owned() />
+                <p class="h1 mt-2"> {skinset_id.skinset_name()} </p>
+                <p> "Universes: " {if universes.is_empty() { "none".to_owned() } else { universes.join(", ") }} </p>
+                <p> {skinset_id.champ_count()} " champs. " {full_team} </p>
+
+                <div class="row row-cols-2 row-cols-md-5"> {lane_columns} </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,232 @@
+//! Controls to apply, combine, save and edit skinset presets, shown in the skinset list card.
+
+use crate::{
+    components::button::Button,
+    constants::SkinsetId,
+    presets::{self, BuiltinPreset, PresetCombine, UserPreset},
+    storage,
+};
+use icondata::{BiSaveRegular, BsTrash};
+use leptos::{
+    batch, component, create_effect, create_rw_signal, event_target_value, view, CollectView, IntoView, RwSignal,
+    Signal, SignalGet, SignalGetUntracked, SignalSet, SignalUpdate, SignalWith, SignalWithUntracked,
+};
+use leptos_icons::Icon;
+use std::collections::HashSet;
+
+/// A preset chosen in the preset dropdown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PresetChoice {
+    /// One of the [`BuiltinPreset`]s.
+    Builtin(BuiltinPreset),
+
+    /// The user preset at this index.
+    User(usize),
+}
+
+impl PresetChoice {
+    /// The value used for this choice in the dropdown.
+    fn option_value(self) -> String {
+        match self {
+            PresetChoice::Builtin(preset) => {
+                let index: usize = BuiltinPreset::ALL.iter().position(|builtin| *builtin == preset).unwrap();
+                format!("builtin-{index}")
+            }
+            PresetChoice::User(index) => format!("user-{index}"),
+        }
+    }
+
+    /// Parse a choice from its dropdown value.
+    fn from_option_value(value: &str) -> Option<Self> {
+        if let Some(index) = value.strip_prefix("builtin-") {
+            return index
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| BuiltinPreset::ALL.get(index))
+                .copied()
+                .map(Self::Builtin);
+        }
+
+        value.strip_prefix("user-").and_then(|index| index.parse().ok()).map(Self::User)
+    }
+}
+
+/// Preset controls for the skinset list.
+///
+/// # Arguments
+/// - `skinsets_rw_signal` - A read/write signal to the current [`HashSet`] of skinsets selected.
+#[component]
+pub fn SkinsetPresets(skinsets_rw_signal: RwSignal<HashSet<SkinsetId>>) -> impl IntoView {
+    // The user's saved presets, kept in local storage.
+    let user_presets: RwSignal<Vec<UserPreset>> = create_rw_signal(storage::load_presets());
+    // The preset chosen in the dropdown.
+    let choice: RwSignal<PresetChoice> = create_rw_signal(PresetChoice::Builtin(BuiltinPreset::ExcludeLegacyAndNa));
+    // The name typed for saving a new preset.
+    let new_preset_name: RwSignal<String> = create_rw_signal(String::new());
+
+    // Save the user presets whenever they change. Skip the first run, which is just the presets being loaded.
+    create_effect(move |previous: Option<()>| {
+        user_presets.with(|presets| {
+            if previous.is_some() {
+                storage::save_presets(presets);
+            }
+        })
+    });
+
+    // Get the skinsets of the chosen preset, if it still exists.
+    let chosen_skinsets = move || -> Option<HashSet<SkinsetId>> {
+        match choice.get_untracked() {
+            PresetChoice::Builtin(preset) => Some(preset.skinsets()),
+            PresetChoice::User(index) => {
+                user_presets.with_untracked(|presets| presets.get(index).map(|preset| preset.skinsets.clone()))
+            }
+        }
+    };
+
+    // Derive whether a user preset is chosen, so it can be edited.
+    let user_preset_chosen: Signal<bool> = Signal::derive(move || matches!(choice.get(), PresetChoice::User(_)));
+
+    // Buttons to apply the chosen preset in each way.
+    let combine_buttons = PresetCombine::ALL
+        .iter()
+        .map(|combine: &PresetCombine| {
+            let combine: PresetCombine = *combine;
+
+            let on_click = move |_| {
+                if let Some(preset_skinsets) = chosen_skinsets() {
+                    log::debug!("Applying skinset preset ({combine})");
+                    skinsets_rw_signal.update(|skinsets| *skinsets = combine.apply(skinsets, &preset_skinsets));
+                }
+            };
+
+            view! {
+                <Button class="btn btn-outline-primary" on_click=on_click> {combine.to_string()} </Button>
+            }
+        })
+        .collect_view();
+
+    // Dropdown options for every preset.
+    let options = move || {
+        let builtin = BuiltinPreset::ALL.iter().map(|preset| (PresetChoice::Builtin(*preset), preset.to_string()));
+
+        let user: Vec<(PresetChoice, String)> = user_presets.with(|presets| {
+            presets
+                .iter()
+                .enumerate()
+                .map(|(index, preset)| (PresetChoice::User(index), preset.name.clone()))
+                .collect()
+        });
+
+        builtin
+            .chain(user)
+            .map(|(preset_choice, name)| view! {
+                <option value={preset_choice.option_value()} selected={move || choice.get() == preset_choice}>
+                    {name}
+                </option>
+            })
+            .collect_view()
+    };
+
+    // Save the current selection as a new preset (or over the one with the same name) and choose it.
+    let save_preset = move |_| {
+        let name: String = new_preset_name.get_untracked();
+        let skinsets: HashSet<SkinsetId> = skinsets_rw_signal.get_untracked();
+
+        let saved_index: Option<usize> = user_presets
+            .try_update(|presets| {
+                presets::save_user_preset(presets, &name, skinsets)
+                    .then(|| presets.iter().position(|preset| preset.name == name.trim()))
+                    .flatten()
+            })
+            .flatten();
+
+        if let Some(index) = saved_index {
+            batch(|| {
+                choice.set(PresetChoice::User(index));
+                new_preset_name.set(String::new());
+            });
+        }
+    };
+
+    // Overwrite the chosen user preset with the current selection.
+    let overwrite_preset = move |_| {
+        if let PresetChoice::User(index) = choice.get_untracked() {
+            let skinsets: HashSet<SkinsetId> = skinsets_rw_signal.get_untracked();
+
+            user_presets.update(|presets| {
+                if let Some(preset) = presets.get_mut(index) {
+                    preset.skinsets = skinsets;
+                }
+            });
+        }
+    };
+
+    // Delete the chosen user preset, and go back to the default preset.
+    let delete_preset = move |_| {
+        if let PresetChoice::User(index) = choice.get_untracked() {
+            batch(|| {
+                user_presets.update(|presets| {
+                    if index < presets.len() {
+                        presets.remove(index);
+                    }
+                });
+                choice.set(PresetChoice::Builtin(BuiltinPreset::ExcludeLegacyAndNa));
+            });
+        }
+    };
+
+    view! {
+        <div class="row g-2 align-items-center mb-3">
+            <div class="col-md-6 d-flex gap-2">
+                <select
+                    class="form-select"
+                    aria-label="Skinset preset"
+                    on:change=move |ev| {
+                        if let Some(preset_choice) = PresetChoice::from_option_value(&event_target_value(&ev)) {
+                            choice.set(preset_choice);
+                        }
+                    }
+                >
+                    {options}
+                </select>
+
+                <div class="btn-group" role="group" aria-label="Apply preset"> {combine_buttons} </div>
+            </div>
+
+            <div class="col-md-6 d-flex gap-2">
+                <input
+                    type="text"
+                    class="form-control"
+                    placeholder="New preset name"
+                    aria-label="New preset name"
+                    prop:value={move || new_preset_name.get()}
+                    on:input=move |ev| new_preset_name.set(event_target_value(&ev))
+                />
+
+                <Button
+                    class="btn btn-outline-success text-nowrap"
+                    disabled={Signal::derive(move || new_preset_name.with(|name| name.trim().is_empty()))}
+                    on_click=save_preset
+                >
+                    <Icon icon=BiSaveRegular /> " Save Selection"
+                </Button>
+
+                <Button
+                    class="btn btn-outline-secondary text-nowrap"
+                    disabled={Signal::derive(move || !user_preset_chosen.get())}
+                    on_click=overwrite_preset
+                >
+                    "Update Preset"
+                </Button>
+
+                <Button
+                    class="btn btn-outline-danger"
+                    disabled={Signal::derive(move || !user_preset_chosen.get())}
+                    on_click=delete_preset
+                >
+                    <Icon icon=BsTrash />
+                </Button>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,107 @@
+//! Results grouped by skinset -- one card per skinset with the options each player has in it.
+
+use crate::{
+    constants::SkinsetId,
+    draft::Draft,
+    model::{PlayerRecord, SolverSettings},
+    solver::{self, SkinsetOptions},
+};
+use instant::Instant;
+use leptos::{component, create_memo, view, CollectView, IntoView, Memo, RwSignal, Signal, SignalGet, SignalWith};
+use std::collections::HashSet;
+
+/// Results grouped by skinset, for picking a theme first and then a champ from it.
+///
+/// # Arguments
+/// - `players_rw_signal` - A read/write signal to the current list of [`PlayerRecord`]s.
+/// - `skinsets_rw_signal` - A read/write signal to the set of skinsets included in the search.
+/// - `settings` - The settings to resolve comps with.
+/// - `draft` - The champ select draft, whose champs are left out of every player's options.
+#[component]
+pub fn SkinsetResults(
+    players_rw_signal: RwSignal<Vec<PlayerRecord>>,
+    skinsets_rw_signal: RwSignal<HashSet<SkinsetId>>,
+    #[prop(into)]
+    settings: Signal<SolverSettings>,
+    #[prop(into)]
+    draft: Signal<Draft>,
+) -> impl IntoView {
+    // Resolve the options in every skinset whenever the players, skinsets, settings or draft change.
+    let by_skinset: Memo<Vec<SkinsetOptions>> = create_memo(move |_| {
+        let start = Instant::now();
+
+        let by_skinset: Vec<SkinsetOptions> = players_rw_signal.with(|players| {
+            let players: Vec<PlayerRecord> = draft.with(|draft| draft.apply(players));
+            skinsets_rw_signal.with(|skinsets| solver::solve_by_skinset(&players, skinsets, settings.get()))
+        });
+
+        log::info!("Resolved options in {} skinsets in {:?}", by_skinset.len(), Instant::now() - start);
+        by_skinset
+    });
+
+    // Render a card for every skinset.
+    let cards = move || {
+        let player_names: Vec<String> = players_rw_signal.with(|players| {
+            players
+                .iter()
+                .enumerate()
+                .map(|(index, player)| player.display_name(index))
+                .collect()
+        });
+
+        by_skinset
+            .get()
+            .into_iter()
+            .map(|options: SkinsetOptions| {
+                let rows = options
+                    .players
+                    .iter()
+                    .zip(player_names.iter())
+                    .map(|(champs, player_name)| {
+                        let champs: String = champs
+                            .iter()
+                            .map(|(champ_id, lanes)| {
+                                if lanes.is_empty() {
+                                    champ_id.champ_name().to_owned()
+                                } else {
+                                    let lanes: Vec<String> = lanes.iter().map(|lane| lane.to_string()).collect();
+                                    format!("{} ({})", champ_id.champ_name(), lanes.join("/"))
+                                }
+                            })
+                            .collect::<Vec<String>>()
+                            .join(", ");
+
+                        view! {
+                            <li class="list-group-item">
+                                <strong> {player_name.clone()} ": " </strong> {champs}
+                            </li>
+                        }
+                    })
+                    .collect_view();
+
+                let comp_label: &str = if options.comp_count == 1 { "comp" } else { "comps" };
+
+                view! {
+                    <div class="col">
+                        <div class="card h-100">
+                            <div class="card-header d-flex align-items-center">
+                                <span class="fw-bold flex-grow-1 text-truncate" title={options.skinset.skinset_name()}>
+                                    {options.skinset.skinset_name()}
+                                </span>
+                                <span class="badge bg-secondary"> {options.comp_count} " " {comp_label} </span>
+                            </div>
+                            <ul class="list-group list-group-flush"> {rows} </ul>
+                        </div>
+                    </div>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <h3> "Results by Skinset (" {move || by_skinset.with(Vec::len)} ")" </h3>
+        <div class="row row-cols-1 row-cols-md-2 row-cols-xl-3 g-2">
+            {cards}
+        </div>
+    }
+}
@@ -0,0 +1,139 @@
+//! Undo/redo buttons and keyboard shortcuts for edits to the players and skinsets.
+
+use crate::{
+    components::button::Button,
+    constants::SkinsetId,
+    history::History,
+    model::PlayerRecord,
+};
+use icondata::{BiRedoRegular, BiUndoRegular};
+use instant::Instant;
+use leptos::{
+    batch, component, create_effect, create_rw_signal, ev::keydown, view, window_event_listener, IntoView, RwSignal,
+    Signal, SignalGet, SignalGetUntracked, SignalSet, SignalUpdate, SignalWith,
+};
+use leptos_icons::Icon;
+use std::collections::HashSet;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, KeyboardEvent};
+
+/// A snapshot of the undoable app state.
+type Snapshot = (Vec<PlayerRecord>, HashSet<SkinsetId>);
+
+/// A field of the app state that's edited a keystroke at a time, whose consecutive edits are undone together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditedField {
+    /// The name of the player at this index.
+    PlayerName(usize),
+}
+
+/// Work out the one [`EditedField`] a change edited, if it only edited one.
+fn edited_field((old_players, old_skinsets): &Snapshot, (new_players, new_skinsets): &Snapshot) -> Option<EditedField> {
+    if old_skinsets != new_skinsets || old_players.len() != new_players.len() {
+        return None;
+    }
+
+    let mut changed = old_players.iter().zip(new_players).enumerate().filter(|(_, (old, new))| old != new);
+    let (player_index, (old, new)) = changed.next()?;
+
+    // Only a change to the name of a single player, with nothing else on them changed, counts.
+    let same_otherwise: bool = PlayerRecord { name: new.name.clone(), ..old.clone() } == *new;
+    (changed.next().is_none() && same_otherwise).then_some(EditedField::PlayerName(player_index))
+}
+
+/// Check whether a keyboard event is aimed at a text field, which handles its own undo and redo.
+fn targets_text_field(ev: &KeyboardEvent) -> bool {
+    ev.target().and_then(|target| target.dyn_into::<HtmlElement>().ok()).is_some_and(|element| {
+        let tag_name: String = element.tag_name().to_ascii_lowercase();
+        matches!(tag_name.as_str(), "input" | "textarea") || element.is_content_editable()
+    })
+}
+
+/// Undo and redo buttons, which also handle Ctrl+Z (undo) and Ctrl+Shift+Z/Ctrl+Y (redo).
+///
+/// # Arguments
+/// - `players_rw_signal` - A read/write signal to the current list of [`PlayerRecord`]s.
+/// - `skinsets_rw_signal` - A read/write signal to the current [`HashSet`] of skinsets selected.
+#[component]
+pub fn UndoRedo(
+    players_rw_signal: RwSignal<Vec<PlayerRecord>>,
+    skinsets_rw_signal: RwSignal<HashSet<SkinsetId>>,
+) -> impl IntoView {
+    // The history, starting from the state when the app loaded.
+    let history: RwSignal<History<Snapshot, EditedField>> = create_rw_signal(History::new((
+        players_rw_signal.get_untracked(),
+        skinsets_rw_signal.get_untracked(),
+    )));
+
+    // Record every change to the app state. Changes made by undo/redo are ignored by the history itself.
+    create_effect(move |_| {
+        let snapshot: Snapshot = (players_rw_signal.get(), skinsets_rw_signal.get());
+
+        history.update(|history| {
+            let field: Option<EditedField> = edited_field(history.current(), &snapshot);
+            history.record(snapshot, field, Instant::now());
+        });
+    });
+
+    // Apply a snapshot from the history. Batch the updates so the history only sees the combined state.
+    let apply = move |(players, skinsets): Snapshot| {
+        batch(|| {
+            players_rw_signal.set(players);
+            skinsets_rw_signal.set(skinsets);
+        });
+    };
+
+    // Closures to undo and redo.
+    let undo = move || {
+        if let Some(snapshot) = history.try_update(|history| history.undo()).flatten() {
+            apply(snapshot);
+        }
+    };
+
+    let redo = move || {
+        if let Some(snapshot) = history.try_update(|history| history.redo()).flatten() {
+            apply(snapshot);
+        }
+    };
+
+    // Keyboard shortcuts. Text fields keep their own undo and redo.
+    let _ = window_event_listener(keydown, move |ev| {
+        if !(ev.ctrl_key() || ev.meta_key()) || targets_text_field(&ev) {
+            return;
+        }
+
+        match (ev.key().to_ascii_lowercase().as_str(), ev.shift_key()) {
+            ("z", false) => {
+                ev.prevent_default();
+                undo();
+            }
+
+            ("z", true) | ("y", false) => {
+                ev.prevent_default();
+                redo();
+            }
+
+            _ => {}
+        }
+    });
+
+    view! {
+        <div class="btn-group" role="group" aria-label="Undo and redo">
+            <Button
+                class="btn btn-outline-secondary"
+                disabled={Signal::derive(move || history.with(|history| !history.can_undo()))}
+                on_click=move |_| undo()
+            >
+                <Icon icon=BiUndoRegular /> " Undo"
+            </Button>
+
+            <Button
+                class="btn btn-outline-secondary"
+                disabled={Signal::derive(move || history.with(|history| !history.can_redo()))}
+                on_click=move |_| redo()
+            >
+                <Icon icon=BiRedoRegular /> " Redo"
+            </Button>
+        </div>
+    }
+}
@@ -0,0 +1,170 @@
+//! Undo/redo history over snapshots of the app state.
+
+use instant::{Duration, Instant};
+use std::mem;
+
+/// Consecutive edits to the same field recorded within this long of each other are merged into the same history entry,
+/// so that e.g. typing a name is undone all at once rather than one character at a time.
+pub const COALESCE_WINDOW: Duration = Duration::from_millis(1000);
+
+/// The maximum number of entries kept in the undo stack.
+const MAX_HISTORY: usize = 100;
+
+/// Linear undo/redo history of some state. `F` identifies the field an edit changed, for coalescing.
+#[derive(Debug, Clone)]
+pub struct History<T, F> {
+    /// The current state.
+    current: T,
+
+    /// Previous states, most recent last.
+    undo_stack: Vec<T>,
+
+    /// States that have been undone, most recently undone last.
+    redo_stack: Vec<T>,
+
+    /// The field the last change edited (if it only edited one) and when it was recorded, used to coalesce rapid
+    /// edits to that field. Reset by undo and redo so that the next change always starts a new entry.
+    last_recorded: Option<(Option<F>, Instant)>,
+}
+
+impl<T: Clone + PartialEq, F: PartialEq> History<T, F> {
+    /// Create a new history starting at the given state.
+    pub fn new(initial: T) -> Self {
+        Self {
+            current: initial,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            last_recorded: None,
+        }
+    }
+
+    /// Get the current state.
+    pub fn current(&self) -> &T {
+        &self.current
+    }
+
+    /// Record that the state has changed to `state` at the instant `now`. `field` is the one field the change edited,
+    /// or `None` if it edited several or isn't something that should be coalesced (e.g. adding a player).
+    ///
+    /// Does nothing if `state` is the current state (e.g. because it was just set by [`History::undo`] or
+    /// [`History::redo`]). Otherwise clears the redo stack, and either pushes the previous state onto the undo stack
+    /// or, if the last change edited the same field less than [`COALESCE_WINDOW`] ago, merges this change into the
+    /// last entry.
+    pub fn record(&mut self, state: T, field: Option<F>, now: Instant) {
+        if state == self.current {
+            return;
+        }
+
+        let coalesce: bool = !self.undo_stack.is_empty()
+            && field.is_some()
+            && self.last_recorded.as_ref().is_some_and(|(last_field, last_recorded)| {
+                *last_field == field && now.duration_since(*last_recorded) < COALESCE_WINDOW
+            });
+
+        let previous: T = mem::replace(&mut self.current, state);
+
+        if !coalesce {
+            self.undo_stack.push(previous);
+
+            if self.undo_stack.len() > MAX_HISTORY {
+                self.undo_stack.remove(0);
+            }
+        }
+
+        self.redo_stack.clear();
+        self.last_recorded = Some((field, now));
+    }
+
+    /// Step back to the previous state, returning it if there is one.
+    pub fn undo(&mut self) -> Option<T> {
+        let previous: T = self.undo_stack.pop()?;
+        self.redo_stack.push(mem::replace(&mut self.current, previous.clone()));
+        self.last_recorded = None;
+        Some(previous)
+    }
+
+    /// Step forward to the most recently undone state, returning it if there is one.
+    pub fn redo(&mut self) -> Option<T> {
+        let next: T = self.redo_stack.pop()?;
+        self.undo_stack.push(mem::replace(&mut self.current, next.clone()));
+        self.last_recorded = None;
+        Some(next)
+    }
+
+    /// Check if there is anything to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    /// Check if there is anything to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{History, COALESCE_WINDOW};
+    use instant::{Duration, Instant};
+
+    #[test]
+    fn undo_and_redo_step_through_changes() {
+        let start = Instant::now();
+        let mut history = History::<i32, ()>::new(0);
+
+        history.record(1, None, start);
+        history.record(2, None, start + COALESCE_WINDOW * 2);
+
+        assert_eq!(history.undo(), Some(1));
+        assert_eq!(history.undo(), Some(0));
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.redo(), Some(1));
+
+        // Setting the state to what undo/redo returned is not a new change.
+        history.record(1, None, start + COALESCE_WINDOW * 3);
+        assert_eq!(history.redo(), Some(2));
+    }
+
+    #[test]
+    fn rapid_changes_are_coalesced() {
+        let start = Instant::now();
+        let mut history = History::new(String::new());
+
+        for (index, name) in ["E", "Em", "Emm", "Emma"].into_iter().enumerate() {
+            history.record(name.to_owned(), Some("name"), start + Duration::from_millis(100) * index as u32);
+        }
+
+        assert_eq!(history.undo().as_deref(), Some(""));
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn only_edits_to_the_same_field_are_coalesced() {
+        let start = Instant::now();
+        let mut history = History::new((0, 0));
+
+        // Quick edits to different fields, or to no particular field, each get their own entry.
+        history.record((1, 0), Some("first"), start);
+        history.record((1, 1), Some("second"), start + Duration::from_millis(100));
+        history.record((2, 2), None, start + Duration::from_millis(200));
+        history.record((3, 3), None, start + Duration::from_millis(300));
+
+        assert_eq!(history.undo(), Some((2, 2)));
+        assert_eq!(history.undo(), Some((1, 1)));
+        assert_eq!(history.undo(), Some((1, 0)));
+        assert_eq!(history.undo(), Some((0, 0)));
+    }
+
+    #[test]
+    fn new_changes_clear_redo() {
+        let start = Instant::now();
+        let mut history = History::<i32, ()>::new(0);
+
+        history.record(1, None, start);
+        history.undo();
+        history.record(5, None, start + COALESCE_WINDOW * 2);
+
+        assert!(!history.can_redo());
+        assert_eq!(history.undo(), Some(0));
+    }
+}
@@ -1,16 +1,17 @@
 // use crate::{components::App, 
 use constants::{SkinsetId, ChampId};
+use skinset_core::{
+    constants, coverage, diagnostics, draft, export, filters, generated, lenient, model, names, presets, query, recommend,
+    roller, roster, solver,
+};
 use log::{info, Level};
 use components::app::App;
 
 mod components;
-mod constants;
-mod model;
-
-/// Content generated by the build script.
-mod generated {
-    include!(concat!(env!("OUT_DIR"), "/generated.rs"));
-}
+mod history;
+mod route;
+mod share;
+mod storage;
 
 fn main() {
     // Set the panic handler to be the browser's `console.error`.
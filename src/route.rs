@@ -0,0 +1,121 @@
+//! Hash-based client-side routing between the finder and the champion and skinset browse pages.
+//!
+//! Routes live in the URL fragment (`#/champions/ahri`, `#/skinsets/kda`) so that the app keeps working when served
+//! as static files, and names in them are normalized so that hand-typed URLs are forgiving.
+
+use crate::{
+    constants::{ChampId, SkinsetId, UnknownNameError},
+    names::normalize_name,
+};
+use std::fmt::Write;
+
+/// A page of the app.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Route {
+    /// The skinset finder itself.
+    Finder,
+
+    /// The browse page for a champion.
+    Champion(ChampId),
+
+    /// The browse page for a skinset.
+    Skinset(SkinsetId),
+
+    /// A champion or skinset page for a name that doesn't match anything.
+    NotFound(UnknownNameError),
+}
+
+impl Route {
+    /// Parse a route from a URL fragment (without the leading `#`). Anything that isn't a browse page, including
+    /// share links, routes to the finder.
+    pub fn from_fragment(fragment: &str) -> Self {
+        let Some(path) = fragment.strip_prefix('/') else {
+            return Route::Finder;
+        };
+
+        let (section, name) = path.split_once('/').unwrap_or((path, ""));
+        let name: String = percent_decode(name.trim_end_matches('/'));
+
+        match section {
+            "champions" if !name.is_empty() => name.parse().map_or_else(Route::NotFound, Route::Champion),
+            "skinsets" if !name.is_empty() => name.parse().map_or_else(Route::NotFound, Route::Skinset),
+            _ => Route::Finder,
+        }
+    }
+
+    /// The route for the current URL fragment.
+    pub fn current() -> Self {
+        let hash: String = leptos::window().location().hash().unwrap_or_default();
+        Self::from_fragment(hash.trim_start_matches('#'))
+    }
+
+    /// The `href` of a link to this route.
+    pub fn href(&self) -> String {
+        match self {
+            Route::Champion(champ_id) => format!("#/champions/{}", slug(champ_id.champ_name())),
+            Route::Skinset(skinset_id) => format!("#/skinsets/{}", slug(skinset_id.skinset_name())),
+            Route::Finder | Route::NotFound(_) => "#/".to_owned(),
+        }
+    }
+}
+
+/// The URL path segment for a name -- its normalized form, with anything outside of ASCII percent-encoded.
+fn slug(name: &str) -> String {
+    normalize_name(name).bytes().fold(String::new(), |mut slug, byte| {
+        if byte.is_ascii() {
+            slug.push(byte as char);
+        } else {
+            let _ = write!(slug, "%{byte:02X}");
+        }
+        slug
+    })
+}
+
+/// Decode `%XX` escapes in a path segment. Invalid escapes are left as they are.
+fn percent_decode(segment: &str) -> String {
+    let bytes: &[u8] = segment.as_bytes();
+    let mut decoded: Vec<u8> = Vec::with_capacity(bytes.len());
+    let mut index: usize = 0;
+
+    while index < bytes.len() {
+        let escaped: Option<u8> = (bytes[index] == b'%')
+            .then(|| bytes.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            }
+
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Route;
+    use crate::constants::{ChampId, SkinsetId};
+
+    #[test]
+    fn routes_round_trip_through_hrefs() {
+        for route in [
+            Route::Champion(ChampId::from_name("Kai'Sa").unwrap()),
+            Route::Skinset(SkinsetId::from_name("K/DA").unwrap()),
+        ] {
+            assert_eq!(Route::from_fragment(route.href().trim_start_matches('#')), route);
+        }
+
+        let velkoz = Route::Champion(ChampId::from_name("Velkoz").unwrap());
+        assert_eq!(Route::from_fragment("/champions/Vel%27Koz"), velkoz);
+        assert_eq!(Route::from_fragment("roster=AAAA"), Route::Finder);
+        assert!(matches!(Route::from_fragment("/skinsets/not-a-skinset"), Route::NotFound(_)));
+    }
+}
@@ -0,0 +1,441 @@
+//! Compact encoding of the session state into the URL fragment, so that a whole roster can be shared as one link.
+//!
+//! The fragment is `roster=` followed by the base64url encoding of these bytes:
+//! - The format version (1 byte).
+//! - The [SKINSET_VERSION] and [SKIN_VERSION] of the data the link was made with (4 bytes each, little endian).
+//! - The settings (1 byte) -- bit 0 is set for ARAM, and bit 1 if only owned skins are used.
+//! - The number of players (1 byte, at most [MAX_PLAYERS]), then for each player:
+//!   - The length of their name in bytes (1 byte, zero if unnamed), followed by the UTF-8 name.
+//!   - The number of champs (1 byte), then for each champ its [ChampId::share_key] (4 bytes, little endian) and its
+//!     lanes (1 byte).
+//!   - Their lock-in (2 bytes) -- the position of the locked champ in their champs plus one (zero if none), then the
+//!     locked lane's flag (zero if none).
+//!   - The number of skinsets they've excluded (2 bytes, little endian), then each [SkinsetId] (2 bytes each).
+//!   - The number of skins they own (2 bytes, little endian), then each [SkinId] (2 bytes each).
+//! - The number of skinsets in the data the link was made with (2 bytes, little endian), then a bitmask of the
+//!   included skinsets (one bit per [SkinsetId], in order).
+//!
+//! Champs are encoded by their stable key, so they survive data updates. Skinsets and skins are positional, so the
+//! skinset bitmask and each player's excluded skinsets are only used when the skinset version of the link matches the
+//! current data, and owned skins only when the skin version does. Adding champs changes neither version.
+
+use crate::{
+    constants::{ChampId, Lane, SkinId, SkinsetId},
+    generated::{ALL_SKINSET_NAMES, SKINSET_VERSION, SKIN_VERSION},
+    model::{GameMode, LockIn, PlayerRecord, SolverSettings, MAX_PLAYERS},
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use derive_more::Display;
+use enumflags2::BitFlags;
+use std::collections::HashSet;
+use wasm_bindgen::JsValue;
+
+/// The version of the share link format. Bump this whenever the format changes.
+const SHARE_FORMAT_VERSION: u8 = 2;
+
+/// The prefix of URL fragments that contain a shared roster.
+const FRAGMENT_PREFIX: &str = "roster=";
+
+/// The bit of the settings byte that is set when playing ARAM.
+const ARAM_BIT: u8 = 1 << 0;
+
+/// The bit of the settings byte that is set when only owned skins are used.
+const OWNED_ONLY_BIT: u8 = 1 << 1;
+
+/// Session state decoded from a share link.
+#[derive(Debug)]
+pub struct SharedState {
+    /// The shared player records. Their excluded skinsets and owned skins are left out if the link was made with
+    /// different skinset or skin data.
+    pub players: Vec<PlayerRecord>,
+
+    /// The shared set of included skinsets, or [`None`] if the link was made with different skinset data and the set
+    /// could not be decoded.
+    pub skinsets: Option<HashSet<SkinsetId>>,
+
+    /// The shared game mode and owned skins setting.
+    pub settings: SolverSettings,
+}
+
+impl SharedState {
+    /// Split this into the players, skinsets and settings to load. The default skinsets are used if the link was made
+    /// with different skinset data, and there's always at least one player.
+    pub fn into_roster(self) -> (Vec<PlayerRecord>, HashSet<SkinsetId>, SolverSettings) {
+        let mut players: Vec<PlayerRecord> = self.players;
+
+        if players.is_empty() {
+            players.push(PlayerRecord::new());
+        }
+
+        let skinsets: HashSet<SkinsetId> = self.skinsets.unwrap_or_else(|| {
+            log::warn!("Share link was made with different skinset data -- using default skinsets");
+            SkinsetId::generate_default_included_skinsets()
+        });
+
+        (players, skinsets, self.settings)
+    }
+}
+
+/// Errors that can occur when decoding a share link.
+#[derive(Debug, Display)]
+pub enum ShareDecodeError {
+    /// The fragment was not valid base64url.
+    #[display(fmt = "share link is not valid base64")]
+    InvalidBase64,
+
+    /// The link was made with a format version this build does not understand.
+    #[display(fmt = "share link has unsupported format version {}", _0)]
+    UnsupportedVersion(u8),
+
+    /// The encoded data ended early.
+    #[display(fmt = "share link is truncated")]
+    Truncated,
+
+    /// A player name was not valid UTF-8.
+    #[display(fmt = "share link contains an invalid player name")]
+    InvalidName,
+
+    /// The link has more players than a comp can have.
+    #[display(fmt = "share link has {} players, but a comp has at most {MAX_PLAYERS}", _0)]
+    TooManyPlayers(u8),
+}
+
+/// Encode the session state into a URL fragment (without the leading `#`).
+pub fn encode(players: &[PlayerRecord], skinsets: &HashSet<SkinsetId>, settings: SolverSettings) -> String {
+    let mut bytes: Vec<u8> = vec![SHARE_FORMAT_VERSION];
+    bytes.extend_from_slice(&SKINSET_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&SKIN_VERSION.to_le_bytes());
+
+    let mut settings_bits: u8 = 0;
+    if settings.game_mode == GameMode::Aram {
+        settings_bits |= ARAM_BIT;
+    }
+    if settings.owned_only {
+        settings_bits |= OWNED_ONLY_BIT;
+    }
+    bytes.push(settings_bits);
+
+    // Players and their champs. Counts are capped to the most players in a comp and to fit in a byte.
+    let players = &players[..players.len().min(MAX_PLAYERS)];
+    bytes.push(players.len() as u8);
+
+    for player in players {
+        let name: &str = truncate_to_char_boundary(player.name.as_deref().unwrap_or_default(), u8::MAX as usize);
+        bytes.push(name.len() as u8);
+        bytes.extend_from_slice(name.as_bytes());
+
+        let champs = &player.champs[..player.champs.len().min(u8::MAX as usize)];
+        bytes.push(champs.len() as u8);
+
+        for (champ_id, lanes) in champs {
+            bytes.extend_from_slice(&champ_id.share_key().to_le_bytes());
+            bytes.push(lanes.bits());
+        }
+
+        // A locked champ that isn't one of the encoded champs can't be pointed at, so it's left out.
+        let locked_champ: Option<usize> = player
+            .lock
+            .champ
+            .and_then(|locked| champs.iter().position(|(champ_id, _)| *champ_id == locked));
+        bytes.push(locked_champ.map_or(0, |position| position as u8 + 1));
+        bytes.push(player.lock.lane.map_or(0, |lane| BitFlags::from(lane).bits()));
+
+        let mut excluded_skinsets: Vec<SkinsetId> = player.excluded_skinsets.iter().copied().collect();
+        excluded_skinsets.sort();
+        push_indices(&mut bytes, excluded_skinsets.into_iter().map(SkinsetId::inner));
+
+        let mut owned_skins: Vec<SkinId> = player.owned_skins.iter().copied().collect();
+        owned_skins.sort();
+        push_indices(&mut bytes, owned_skins.into_iter().map(SkinId::inner));
+    }
+
+    // Skinset bitmask.
+    bytes.extend_from_slice(&(ALL_SKINSET_NAMES.len() as u16).to_le_bytes());
+    let mut bitmask: Vec<u8> = vec![0; ALL_SKINSET_NAMES.len().div_ceil(8)];
+
+    for skinset_id in skinsets {
+        bitmask[skinset_id.inner() / 8] |= 1 << (skinset_id.inner() % 8);
+    }
+
+    bytes.extend_from_slice(&bitmask);
+
+    format!("{FRAGMENT_PREFIX}{}", URL_SAFE_NO_PAD.encode(bytes))
+}
+
+/// Decode session state from a URL fragment (without the leading `#`).
+///
+/// Returns `Ok(None)` if the fragment does not contain a shared roster. Champs that are not in the current data are
+/// dropped.
+pub fn decode(fragment: &str) -> Result<Option<SharedState>, ShareDecodeError> {
+    let Some(encoded) = fragment.strip_prefix(FRAGMENT_PREFIX) else {
+        return Ok(None);
+    };
+
+    let bytes: Vec<u8> = URL_SAFE_NO_PAD
+        .decode(encoded)
+        .map_err(|_| ShareDecodeError::InvalidBase64)?;
+
+    let mut reader = ByteReader { bytes: &bytes };
+
+    let format_version: u8 = reader.u8()?;
+    if format_version != SHARE_FORMAT_VERSION {
+        return Err(ShareDecodeError::UnsupportedVersion(format_version));
+    }
+
+    let same_skinsets: bool = u32::from_le_bytes(reader.array()?) == SKINSET_VERSION;
+    let same_skins: bool = u32::from_le_bytes(reader.array()?) == SKIN_VERSION;
+
+    let settings_bits: u8 = reader.u8()?;
+    let settings = SolverSettings {
+        game_mode: if settings_bits & ARAM_BIT != 0 { GameMode::Aram } else { GameMode::SummonersRift },
+        owned_only: settings_bits & OWNED_ONLY_BIT != 0,
+    };
+
+    // Players and their champs.
+    let player_count: u8 = reader.u8()?;
+    if player_count as usize > MAX_PLAYERS {
+        return Err(ShareDecodeError::TooManyPlayers(player_count));
+    }
+
+    let mut players: Vec<PlayerRecord> = Vec::with_capacity(player_count as usize);
+
+    for _ in 0..player_count {
+        let name_len: u8 = reader.u8()?;
+        let name: &str = std::str::from_utf8(reader.take(name_len as usize)?)
+            .map_err(|_| ShareDecodeError::InvalidName)?;
+
+        let mut player = PlayerRecord::new();
+        player.name = (!name.is_empty()).then(|| name.to_owned());
+
+        let champ_count: u8 = reader.u8()?;
+        // Every encoded champ, including unknown ones, so that the lock can point into them.
+        let mut encoded_champs: Vec<Option<ChampId>> = Vec::with_capacity(champ_count as usize);
+
+        for _ in 0..champ_count {
+            let champ_key: u32 = u32::from_le_bytes(reader.array()?);
+            let lanes: BitFlags<Lane> = BitFlags::from_bits_truncate(reader.u8()?);
+            let champ_id: Option<ChampId> = ChampId::from_share_key(champ_key);
+
+            match champ_id {
+                Some(champ_id) => player.champs.push((champ_id, lanes)),
+                None => log::warn!("Dropping unknown champ (key {champ_key}) from share link"),
+            }
+
+            encoded_champs.push(champ_id);
+        }
+
+        let locked_champ: Option<ChampId> = (reader.u8()? as usize)
+            .checked_sub(1)
+            .and_then(|position| encoded_champs.get(position).copied().flatten());
+        let locked_lane: Option<Lane> = BitFlags::<Lane>::from_bits(reader.u8()?).ok().and_then(BitFlags::exactly_one);
+        player.lock = LockIn { champ: locked_champ, lane: locked_lane };
+
+        let excluded_skinsets = reader.indices()?.filter_map(SkinsetId::from_inner);
+        if same_skinsets {
+            player.excluded_skinsets = excluded_skinsets.collect();
+        }
+
+        let owned_skins = reader.indices()?.filter_map(SkinId::from_inner);
+        if same_skins {
+            player.owned_skins = owned_skins.collect();
+        }
+
+        players.push(player);
+    }
+
+    // Skinset bitmask -- only meaningful if the skinset list is the same as when the link was made.
+    let skinset_count: u16 = u16::from_le_bytes(reader.array()?);
+    let bitmask: &[u8] = reader.take((skinset_count as usize).div_ceil(8))?;
+    let same_skinset_count: bool = skinset_count as usize == ALL_SKINSET_NAMES.len();
+
+    let skinsets: Option<HashSet<SkinsetId>> = (same_skinsets && same_skinset_count).then(|| {
+        SkinsetId::iter_all()
+            .filter(|skinset_id| bitmask[skinset_id.inner() / 8] & (1 << (skinset_id.inner() % 8)) != 0)
+            .collect()
+    });
+
+    if !same_skinsets {
+        log::warn!("Share link was made with different skinset data -- dropping player skinset exclusions");
+    }
+
+    if !same_skins {
+        log::warn!("Share link was made with different skin data -- dropping owned skins");
+    }
+
+    Ok(Some(SharedState { players, skinsets, settings }))
+}
+
+/// Make a full share link to this page for the given session state.
+pub fn share_url(players: &[PlayerRecord], skinsets: &HashSet<SkinsetId>, settings: SolverSettings) -> String {
+    let location = leptos::window().location();
+    let origin: String = location.origin().unwrap_or_default();
+    let path: String = location.pathname().unwrap_or_default();
+
+    format!("{origin}{path}#{}", encode(players, skinsets, settings))
+}
+
+/// Read and decode any shared roster in the current URL fragment, then remove the fragment from the URL so that later
+/// edits are not overwritten by the link on refresh.
+pub fn take_shared_state() -> Option<SharedState> {
+    let window = leptos::window();
+    let hash: String = window.location().hash().ok()?;
+
+    match decode(hash.trim_start_matches('#')) {
+        Ok(None) => None,
+
+        Ok(Some(state)) => {
+            log::info!("Loaded roster from share link");
+
+            // Strip the fragment from the URL without reloading.
+            if let (Ok(history), Ok(path)) = (window.history(), window.location().pathname()) {
+                let _ = history.replace_state_with_url(&JsValue::NULL, "", Some(&path));
+            }
+
+            Some(state)
+        }
+
+        Err(err) => {
+            log::warn!("Ignoring invalid share link: {err}");
+            None
+        }
+    }
+}
+
+/// Write a count (2 bytes, little endian) followed by that many indices (2 bytes each, little endian). Any indices
+/// that don't fit in 2 bytes, or past the most that can be counted, are left out.
+fn push_indices(bytes: &mut Vec<u8>, indices: impl Iterator<Item = usize>) {
+    let indices: Vec<u16> = indices.filter_map(|index| u16::try_from(index).ok()).take(u16::MAX as usize).collect();
+    bytes.extend_from_slice(&(indices.len() as u16).to_le_bytes());
+
+    for index in indices {
+        bytes.extend_from_slice(&index.to_le_bytes());
+    }
+}
+
+/// Truncate a string to at most `max_len` bytes without splitting a character.
+fn truncate_to_char_boundary(s: &str, max_len: usize) -> &str {
+    if s.len() <= max_len {
+        return s;
+    }
+
+    let end: usize = (0..=max_len).rev().find(|index| s.is_char_boundary(*index)).unwrap_or(0);
+    &s[..end]
+}
+
+/// Cursor over the decoded bytes of a share link.
+struct ByteReader<'a> {
+    /// The bytes not yet read.
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    /// Read the next `count` bytes.
+    fn take(&mut self, count: usize) -> Result<&'a [u8], ShareDecodeError> {
+        if self.bytes.len() < count {
+            return Err(ShareDecodeError::Truncated);
+        }
+
+        let (taken, rest) = self.bytes.split_at(count);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    /// Read a single byte.
+    fn u8(&mut self) -> Result<u8, ShareDecodeError> {
+        Ok(self.take(1)?[0])
+    }
+
+    /// Read a fixed number of bytes into an array.
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], ShareDecodeError> {
+        Ok(self.take(N)?.try_into().expect("took exactly N bytes"))
+    }
+
+    /// Read a list of indices written by [push_indices].
+    fn indices(&mut self) -> Result<impl Iterator<Item = usize> + 'a, ShareDecodeError> {
+        let count: u16 = u16::from_le_bytes(self.array()?);
+        let indices: &'a [u8] = self.take(count as usize * 2)?;
+        Ok(indices.chunks_exact(2).map(|index| u16::from_le_bytes([index[0], index[1]]) as usize))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode, ShareDecodeError, FRAGMENT_PREFIX, SHARE_FORMAT_VERSION};
+    use crate::{
+        constants::{ChampId, Lane, SkinId, SkinsetId},
+        generated::{SKINSET_VERSION, SKIN_VERSION},
+        model::{GameMode, PlayerRecord, SolverSettings},
+    };
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    use enumflags2::BitFlags;
+
+    /// Make a share link fragment out of raw bytes.
+    fn fragment(bytes: &[u8]) -> String {
+        format!("{FRAGMENT_PREFIX}{}", URL_SAFE_NO_PAD.encode(bytes))
+    }
+
+    /// The header of a link in the given format version, made with the current data and the default settings.
+    fn header(format_version: u8) -> Vec<u8> {
+        let mut bytes: Vec<u8> = vec![format_version];
+        bytes.extend_from_slice(&SKINSET_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&SKIN_VERSION.to_le_bytes());
+        bytes.push(0);
+        bytes
+    }
+
+    #[test]
+    fn sessions_round_trip_through_links() {
+        let mut player = PlayerRecord::new();
+        player.name = Some("Emma".to_owned());
+        player.champs.push((ChampId::from_champ_name("Ahri").unwrap(), BitFlags::from(Lane::Mid)));
+        player.champs.push((ChampId::from_champ_name("Vel'Koz").unwrap(), BitFlags::all()));
+        player.lock_pick(ChampId::from_champ_name("Vel'Koz").unwrap(), Some(Lane::Support));
+        player.excluded_skinsets.insert(SkinsetId::from_skinset_name("K/DA").unwrap());
+        player.excluded_skinsets.insert(SkinsetId::from_skinset_name("Legacy").unwrap());
+        player.owned_skins.insert(SkinId::from_skin_name("K/DA Ahri").unwrap());
+
+        // A player locked into a lane only.
+        let mut lane_locked = PlayerRecord::new();
+        lane_locked.champs.push((ChampId::from_champ_name("Garen").unwrap(), BitFlags::from(Lane::Top)));
+        lane_locked.lock.lane = Some(Lane::Top);
+
+        let players = vec![player, lane_locked, PlayerRecord::new()];
+        let skinsets = SkinsetId::generate_default_included_skinsets();
+        let settings = SolverSettings { game_mode: GameMode::Aram, owned_only: true };
+
+        let state = decode(&encode(&players, &skinsets, settings)).unwrap().unwrap();
+        assert_eq!(state.players, players);
+        assert_eq!(state.skinsets, Some(skinsets));
+        assert_eq!(state.settings, settings);
+        assert!(decode("something-else").unwrap().is_none());
+    }
+
+    #[test]
+    fn malformed_links_are_rejected() {
+        // Cut a real link short.
+        let skinsets = SkinsetId::generate_default_included_skinsets();
+        let encoded: String = encode(&[PlayerRecord::new()], &skinsets, SolverSettings::default());
+        let bytes: Vec<u8> = URL_SAFE_NO_PAD.decode(encoded.trim_start_matches(FRAGMENT_PREFIX)).unwrap();
+        assert!(matches!(decode(&fragment(&bytes[..bytes.len() - 1])), Err(ShareDecodeError::Truncated)));
+
+        // More players than a comp can have.
+        let mut bytes: Vec<u8> = header(SHARE_FORMAT_VERSION);
+        bytes.push(200);
+        assert!(matches!(decode(&fragment(&bytes)), Err(ShareDecodeError::TooManyPlayers(200))));
+
+        // A format version this build doesn't know.
+        let bytes: Vec<u8> = header(SHARE_FORMAT_VERSION + 1);
+        assert!(matches!(decode(&fragment(&bytes)), Err(ShareDecodeError::UnsupportedVersion(_))));
+    }
+
+    #[test]
+    fn short_bitmasks_are_handled() {
+        // A link with the current skinset version but fewer skinsets than the current data keeps its roster but not
+        // its skinsets, rather than reading past the end of the bitmask.
+        let mut bytes: Vec<u8> = header(SHARE_FORMAT_VERSION);
+        bytes.push(0);
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.push(0xFF);
+        assert_eq!(decode(&fragment(&bytes)).unwrap().unwrap().skinsets, None);
+    }
+}
@@ -0,0 +1,186 @@
+//! Persistence of the app state in the browser's local storage, so that the roster and skinset selection survive a
+//! page refresh.
+
+use crate::{constants::SkinsetId, lenient, model::{GameMode, PlayerRecord}, presets::UserPreset};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, time::Duration};
+use web_sys::Storage;
+
+/// The key that the app state is stored under in local storage.
+const STORAGE_KEY: &str = "league-skinset-finder-state";
+
+/// The key that user-saved skinset presets are stored under in local storage. These are kept apart from the app state
+/// so that resetting the app doesn't lose them.
+const PRESETS_KEY: &str = "league-skinset-finder-presets";
+
+/// Version of the stored state format. Bump this whenever [`StoredState`] changes in a non-backwards-compatible way.
+const STORAGE_VERSION: u32 = 3;
+
+/// How long to wait after the last change to the app state before writing it to local storage.
+pub const SAVE_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// The app state as it is written to local storage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredState {
+    /// The version of the format this state was stored with.
+    version: u32,
+
+    /// The game mode being played.
+    #[serde(default)]
+    pub game_mode: GameMode,
+
+    /// Whether the search only uses skins the players own.
+    #[serde(default)]
+    pub owned_only: bool,
+
+    /// The player records.
+    pub players: Vec<PlayerRecord>,
+
+    /// The set of skinsets left out of the search. The excluded skinsets are stored rather than the included ones, so
+    /// that skinsets added by a data update are included for returning users.
+    #[serde(deserialize_with = "lenient::skinset_set")]
+    excluded_skinsets: HashSet<SkinsetId>,
+}
+
+impl StoredState {
+    /// Create a new stored state for the current format.
+    pub fn new(
+        game_mode: GameMode,
+        owned_only: bool,
+        players: Vec<PlayerRecord>,
+        skinsets: &HashSet<SkinsetId>,
+    ) -> Self {
+        Self {
+            version: STORAGE_VERSION,
+            game_mode,
+            owned_only,
+            players,
+            excluded_skinsets: SkinsetId::iter_all().filter(|skinset_id| !skinsets.contains(skinset_id)).collect(),
+        }
+    }
+
+    /// Get the set of skinsets included in the search -- every skinset that wasn't excluded.
+    pub fn included_skinsets(&self) -> HashSet<SkinsetId> {
+        SkinsetId::iter_all().filter(|skinset_id| !self.excluded_skinsets.contains(skinset_id)).collect()
+    }
+
+    /// Check whether this state was stored with the current format and has at least one player. Champs, skinsets and
+    /// skins are stored by name, and any the current data no longer has are skipped as the state loads (see
+    /// [`lenient`]), so a data update never discards the rest of the roster.
+    fn is_current(&self) -> bool {
+        self.version == STORAGE_VERSION && !self.players.is_empty()
+    }
+}
+
+/// Get the browser's local storage, if it's available.
+fn local_storage() -> Option<Storage> {
+    leptos::window().local_storage().ok().flatten()
+}
+
+/// Load the app state from local storage.
+///
+/// Returns [`None`] if there is no stored state, or if the stored state is corrupted or outdated, in which case it is
+/// also removed from local storage.
+pub fn load_state() -> Option<StoredState> {
+    let storage = local_storage()?;
+    let raw: String = storage.get_item(STORAGE_KEY).ok().flatten()?;
+
+    match serde_json::from_str::<StoredState>(&raw) {
+        Ok(state) if state.is_current() => {
+            log::info!("Restored app state from local storage");
+            Some(state)
+        }
+
+        Ok(_) => {
+            log::warn!("Discarding outdated app state from local storage");
+            clear_state();
+            None
+        }
+
+        Err(err) => {
+            log::warn!("Discarding corrupted app state from local storage: {err}");
+            clear_state();
+            None
+        }
+    }
+}
+
+/// Write the app state to local storage, logging any failure.
+pub fn save_state(state: &StoredState) {
+    let Some(storage) = local_storage() else {
+        log::warn!("Local storage unavailable -- app state will not be saved");
+        return;
+    };
+
+    match serde_json::to_string(state) {
+        Ok(raw) => {
+            if storage.set_item(STORAGE_KEY, &raw).is_err() {
+                log::warn!("Failed to write app state to local storage");
+            }
+        }
+
+        Err(err) => log::error!("Failed to serialize app state: {err}"),
+    }
+}
+
+/// Remove any stored app state from local storage.
+pub fn clear_state() {
+    if let Some(storage) = local_storage() {
+        // Ignore failure here -- there's nothing more we can do.
+        let _ = storage.remove_item(STORAGE_KEY);
+    }
+}
+
+/// Load the user's saved skinset presets from local storage, discarding them if they are corrupted.
+pub fn load_presets() -> Vec<UserPreset> {
+    let Some(raw) = local_storage().and_then(|storage| storage.get_item(PRESETS_KEY).ok().flatten()) else {
+        return Vec::new();
+    };
+
+    serde_json::from_str(&raw).unwrap_or_else(|err| {
+        log::warn!("Discarding corrupted skinset presets from local storage: {err}");
+        Vec::new()
+    })
+}
+
+/// Write the user's saved skinset presets to local storage, logging any failure.
+pub fn save_presets(presets: &[UserPreset]) {
+    let Some(storage) = local_storage() else {
+        log::warn!("Local storage unavailable -- skinset presets will not be saved");
+        return;
+    };
+
+    match serde_json::to_string(presets) {
+        Ok(raw) => {
+            if storage.set_item(PRESETS_KEY, &raw).is_err() {
+                log::warn!("Failed to write skinset presets to local storage");
+            }
+        }
+
+        Err(err) => log::error!("Failed to serialize skinset presets: {err}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StoredState;
+    use crate::{constants::SkinsetId, model::GameMode};
+    use std::collections::HashSet;
+
+    #[test]
+    fn skinsets_missing_from_stored_state_are_included() {
+        let included: HashSet<SkinsetId> = SkinsetId::generate_default_included_skinsets();
+        let state = StoredState::new(GameMode::default(), false, Vec::new(), &included);
+        let mut raw: serde_json::Value = serde_json::to_value(&state).unwrap();
+
+        // Stored state round-trips the selection.
+        let restored: StoredState = serde_json::from_value(raw.clone()).unwrap();
+        assert_eq!(restored.included_skinsets(), included);
+
+        // A skinset the stored state doesn't mention, as if it were added by a data update, is included.
+        let excluded: &mut Vec<serde_json::Value> = raw["excluded_skinsets"].as_array_mut().unwrap();
+        let added: String = excluded.pop().unwrap().as_str().unwrap().to_owned();
+        let restored: StoredState = serde_json::from_value(raw).unwrap();
+        assert!(restored.included_skinsets().contains(&SkinsetId::from_name(&added).unwrap()));
+    }
+}